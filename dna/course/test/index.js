@@ -33,6 +33,39 @@ const conductorConfig = Config.gen(
   }
 );
 
+// Admins are listed in DNA properties by their agent addresses, which are only known after players start.
+// So scenarios that need admins install a copy of the DNA with the admins property into every player's conductor
+// and call it through the returned caller that works with the same helper functions as players do
+const ADMINS_INSTANCE = "course_dna_with_admins";
+
+async function withAdmins(player, admins) {
+  await player.admin("admin/dna/install_from_file", {
+    id: ADMINS_INSTANCE,
+    path: dnaPath,
+    copy: true,
+    properties: { admins },
+  });
+  await player.admin("admin/instance/add", {
+    id: ADMINS_INSTANCE,
+    dna_id: ADMINS_INSTANCE,
+    agent_id: "course_dna",
+  });
+  await player.admin("admin/interface/add_instance", {
+    interface_id: "tryorama-interface",
+    instance_id: ADMINS_INSTANCE,
+  });
+  await player.admin("admin/instance/start", { id: ADMINS_INSTANCE });
+  return {
+    call: (_instance, zome, fn, params) => player.call(ADMINS_INSTANCE, zome, fn, params),
+  };
+}
+
+// Course, Section and Content timestamps have to be close to the time when they're committed,
+// so scenarios use timestamps relative to the moment they've started
+function currentTime() {
+  return Math.floor(Date.now() / 1000);
+};
+
 // These are HOF(higher order functions) for zomeFn calls
 // that will make our codes more readable
 function createCourse(title, timestamp) {
@@ -43,6 +76,13 @@ function createCourse(title, timestamp) {
     })
 };
 
+function createCourseNow(title) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "create_course_now", {
+      title,
+    })
+};
+
 function updateCourse(title, sectionsAddresses, courseAnchorAddress, timestamp) {
   return (caller) =>
   caller.call("course_dna", "courses", "update_course", {
@@ -79,11 +119,36 @@ function enrolInCourse(courseAnchorAddress) {
     })
 };
 
+function setCourseDripRule(courseAnchorAddress, dripRule, timestamp) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "set_course_drip_rule", {
+      course_anchor_address: courseAnchorAddress,
+      drip_rule: dripRule,
+      timestamp,
+    })
+};
+
 function getMyEnrolledCourse() {
   return (caller) =>
     caller.call("course_dna", "courses", "get_my_enrolled_courses", {})
 };
 
+function getCourseTree(courseAnchorAddress) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "get_course_tree", {
+      course_anchor_address: courseAnchorAddress,
+    })
+};
+
+function applyCourseBatch(courseAnchorAddress, operations, timestamp) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "apply_course_batch", {
+      course_anchor_address: courseAnchorAddress,
+      operations,
+      timestamp,
+    })
+};
+
 function getAllStudents(courseAnchorAddress) {
   return (caller) =>
     caller.call("course_dna", "courses", "get_all_students", {
@@ -117,6 +182,49 @@ function deleteSection(sectionAnchorAddress, timestamp) {
     })
 };
 
+function createSubsection(title, parentSectionAnchorAddress, timestamp) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "create_subsection", {
+      title,
+      parent_section_anchor_address: parentSectionAnchorAddress,
+      timestamp,
+    })
+};
+
+function reorderSubsections(sectionAnchorAddress, subsections, timestamp) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "reorder_subsections", {
+      section_anchor_address: sectionAnchorAddress,
+      subsections,
+      timestamp,
+    })
+};
+
+function getSectionTree(sectionAnchorAddress) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "get_section_tree", {
+      section_anchor_address: sectionAnchorAddress,
+    })
+};
+
+function moveSectionToCourse(sectionAnchorAddress, targetCourseAnchorAddress, timestamp) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "move_section_to_course", {
+      section_anchor_address: sectionAnchorAddress,
+      target_course_anchor_address: targetCourseAnchorAddress,
+      timestamp,
+    })
+};
+
+function setSectionReleaseTime(sectionAnchorAddress, releaseAt, timestamp) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "set_section_release_time", {
+      section_anchor_address: sectionAnchorAddress,
+      release_at: releaseAt,
+      timestamp,
+    })
+};
+
 function getLatestSectionEntry(sectionAnchorAddress) {
   return (caller) =>
     caller.call("course_dna", "courses", "get_latest_section_entry", {
@@ -135,10 +243,10 @@ function createContent(name, sectionAnchorAddress, url, timestamp, description)
   })
 };
 
-function updateContent(contentAddress, name, url, description, timestamp) {
+function updateContent(contentAnchorAddress, name, url, description, timestamp) {
   return (caller) =>
   caller.call("course_dna", "courses", "update_content", {
-    content_address: contentAddress,
+    content_anchor_address: contentAnchorAddress,
     name,
     url,
     description,
@@ -146,10 +254,24 @@ function updateContent(contentAddress, name, url, description, timestamp) {
   })
 };
 
-function deleteContent(contentAddress) {
+function deleteContent(contentAnchorAddress) {
   return (caller) =>
     caller.call("course_dna", "courses", "delete_content", {
-      content_address: contentAddress,
+      content_anchor_address: contentAnchorAddress,
+    })
+};
+
+function getLatestContentEntry(contentAnchorAddress) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "get_latest_content_entry", {
+      content_anchor_address: contentAnchorAddress,
+    })
+};
+
+function migrateContents(courseAnchorAddress) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "migrate_contents", {
+      course_anchor_address: courseAnchorAddress,
     })
 };
 
@@ -160,514 +282,2101 @@ function getContents(sectionAnchorAddress) {
     })
 };
 
+function createAssignment(title, prompt, dueDate, maxScore, sectionAnchorAddress, timestamp) {
+  return (caller) =>
+  caller.call("course_dna", "courses", "create_assignment", {
+    title,
+    prompt,
+    due_date: dueDate,
+    max_score: maxScore,
+    section_anchor_address: sectionAnchorAddress,
+    timestamp,
+  })
+};
 
-/*******  CREATE_COURSE & GET_LATEST_COURSE_ENTRY *********/
-// here we are registering the first test scenario through orchestrator object.
-// registerScenario accepts two arguments. The first arg is the name of your test scenario.
-// The second argument is the actual test that is in a form of async fnction.
-// `s` is an instance of the Scenario API
-// `t` is the tape assertion API
-// In this scenario, we are testing the create_course and get_latest_course_entry function
-orchestrator.registerScenario("Scenario1: Create new course and get latest", async (s, t) => {
-
-  // here, we are saying that there are 2 agents in this test scenario
-  // both of which we are giving the same conductor config we defined above in L26.
-  const { alice, bob } = await s.players(
-    { alice: conductorConfig, bob: conductorConfig },
-    true
-  );
+function submitAssignment(assignmentAddress, answer, timestamp) {
+  return (caller) =>
+  caller.call("course_dna", "courses", "submit_assignment", {
+    assignment_address: assignmentAddress,
+    answer,
+    timestamp,
+  })
+};
 
-  // so first we are making alice to create a course 
-  const course_addr = await createCourse("course test 1", 123)(alice);
-  console.log(course_addr);
+function gradeSubmission(submissionAddress, score, feedback, timestamp) {
+  return (caller) =>
+  caller.call("course_dna", "courses", "grade_submission", {
+    submission_address: submissionAddress,
+    score,
+    feedback,
+    timestamp,
+  })
+};
 
-  // here we are checking if we are asserting 
-  // that value is truthy
-  t.ok(course_addr.Ok);
+function getMySubmissions() {
+  return (caller) =>
+    caller.call("course_dna", "courses", "get_my_submissions", {})
+};
 
-  // another use of the Scenario API is to automagically wait
-  // for the network to reach a consistent state
-  // before continuing the test
-  await s.consistency();
+function getSubmissions(assignmentAddress) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "get_submissions", {
+      assignment_address: assignmentAddress,
+    })
+};
 
-  // Now, bob is trying to get the course entry that was committed by alice
-  const course = await getLatestCouseEntry(course_addr.Ok)(bob);
-  console.log("course");
-  console.log(course);
+function getUngradedSubmissions(assignmentAddress) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "get_ungraded_submissions", {
+      assignment_address: assignmentAddress,
+    })
+};
 
-  // this is a strict comparison (===) between
-  // course.Ok and the object in second argument
-  t.deepEqual(course.Ok, {
-    title: "course test 1",
-    timestamp: 123,
-    teacher_address: alice.instance("course_dna").agentAddress,
-    sections: [],
-    anchor_address: course_addr.Ok,
-  });
-  // Wait for all network activity to settle
-  await s.consistency();
-});
+function markContentDone(contentAnchorAddress, timestamp) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "mark_content_done", {
+      content_anchor_address: contentAnchorAddress,
+      timestamp,
+    })
+};
 
-/*********** UPDATE_COURSE ********/
-orchestrator.registerScenario("Scenario2: Update course title", async (s, t) => {
-  const { alice, bob } = await s.players(
-    { alice: conductorConfig, bob: conductorConfig },
-    true
-  );
-  const course_addr = await createCourse("new course test for update test", 123)(alice);
-  const course_update_addrss = await updateCourse("course title updated", [], course_addr.Ok, 1234)(alice);
-  await s.consistency();
-  const course = await getLatestCouseEntry(course_addr.Ok)(bob);
-  t.deepEqual(course.Ok, {
-    title: "course title updated",
-    timestamp: 1234,
-    teacher_address: alice.instance("course_dna").agentAddress,
-    sections: [],
-    anchor_address: course_update_addrss.Ok
-  });
-  const course_update_addrss_2 = await updateCourse("new course test for update test", [], course_addr.Ok, 12345)(alice);
-  await s.consistency();
-  const course2 = await getLatestCouseEntry(course_update_addrss_2.Ok)(bob);
-  t.deepEqual(course2.Ok, {
-    title: "new course test for update test",
-    timestamp: 12345,
-    teacher_address: alice.instance("course_dna").agentAddress,
-    sections: [],
-    anchor_address: course_update_addrss.Ok
-  });
-});
+function getMyProgress(courseAnchorAddress) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "get_my_progress", {
+      course_anchor_address: courseAnchorAddress,
+    })
+};
 
-/*********** DELETE_COURSE ********/
-orchestrator.registerScenario("Scenario3: Delete course", async (s, t) => {
-  const { alice, bob } = await s.players(
-    { alice: conductorConfig, bob: conductorConfig },
-    true
-  );
-  const course_addr = await createCourse("new course test for delete scenario", 123)(alice);
-  await s.consistency();
+function issueCertificate(studentAddress, courseAnchorAddress, date) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "issue_certificate", {
+      student_address: studentAddress,
+      course_anchor_address: courseAnchorAddress,
+      date,
+    })
+};
 
-  const delete_result = await deleteCourse(course_addr.Ok)(alice);
-  await s.consistency();
-  
-  console.log("deleted");
-  console.log(delete_result);
-  t.ok(delete_result.Ok);
+function verifyCertificate(certificateAddress) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "verify_certificate", {
+      certificate_address: certificateAddress,
+    })
+};
 
-  const course = await getLatestCouseEntry(course_addr.Ok)(bob);
-  console.log("course");
-  console.log(course);
-  t.deepEqual(course.Ok, null);
-  await s.consistency();
+function revokeCertificate(certificateAddress) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "revoke_certificate", {
+      certificate_address: certificateAddress,
+    })
+};
 
-});
+function getCertificates(studentAddress) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "get_certificates", {
+      student_address: studentAddress,
+    })
+};
 
-/********* GET_MY_COURSES *********/
-orchestrator.registerScenario("Scenario4: Get All My Courses", async (s, t) => {
-  const { alice, bob } = await s.players(
-    { alice: conductorConfig, bob: conductorConfig },
-    true
-  );
-  const course_addr_1 = await createCourse("course for scenario 5-1", 123)(alice);
-  console.log(course_addr_1);
-  t.ok(course_addr_1.Ok);
+function createReview(courseAnchorAddress, rating, text, timestamp) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "create_review", {
+      course_anchor_address: courseAnchorAddress,
+      rating,
+      text,
+      timestamp,
+    })
+};
 
-  await s.consistency();
+function updateReview(reviewAnchorAddress, rating, text, timestamp) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "update_review", {
+      review_anchor_address: reviewAnchorAddress,
+      rating,
+      text,
+      timestamp,
+    })
+};
 
-  const course_addr_2 = await createCourse("course for scenario 5-2", 1234)(alice);
-  console.log(course_addr_2);
-  t.ok(course_addr_2.Ok);
+function getCourseRatingSummary(courseAnchorAddress) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "get_course_rating_summary", {
+      course_anchor_address: courseAnchorAddress,
+    })
+};
 
-  await s.consistency();
+function createComment(targetAddress, text, timestamp) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "create_comment", {
+      target_address: targetAddress,
+      text,
+      timestamp,
+    })
+};
 
+function replyToComment(commentAnchorAddress, text, timestamp) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "reply_to_comment", {
+      comment_anchor_address: commentAnchorAddress,
+      text,
+      timestamp,
+    })
+};
 
-  const all_courses_alice = await getMyCourses()(alice);
-  t.true(all_courses_alice.Ok[0] != null);
-  t.true(all_courses_alice.Ok[1] != null);
+function updateComment(commentAnchorAddress, text, timestamp) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "update_comment", {
+      comment_anchor_address: commentAnchorAddress,
+      text,
+      timestamp,
+    })
+};
 
-  const all_courses_bob = await getMyCourses()(bob);
-  t.true(all_courses_bob.Ok[0] == null);
+function hideComment(commentAnchorAddress, timestamp) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "hide_comment", {
+      comment_anchor_address: commentAnchorAddress,
+      timestamp,
+    })
+};
 
-  await s.consistency();
-});
+function deleteComment(commentAnchorAddress) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "delete_comment", {
+      comment_anchor_address: commentAnchorAddress,
+    })
+};
 
-/********** ENROL_IN_COURSE & GET_ALL_STUDENTS & GET_MY_ENROLLED_COURSES **********/
-orchestrator.registerScenario("Scenario5: Create new course and enrol", async (s, t) => {
-  const { alice, bob } = await s.players(
-    { alice: conductorConfig, bob: conductorConfig },
-    true
-  );
-  const course_addr = await createCourse("course test 1", 123)(alice);
-  console.log(course_addr);
-  t.ok(course_addr.Ok);
+function getComments(targetAddress) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "get_comments", {
+      target_address: targetAddress,
+    })
+};
 
-  await s.consistency();
+function getReplies(commentAnchorAddress) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "get_replies", {
+      comment_anchor_address: commentAnchorAddress,
+    })
+};
 
-  const enrolled_addr = await enrolInCourse(course_addr.Ok)(bob);
+function getLatestCommentEntry(commentAnchorAddress) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "get_latest_comment_entry", {
+      comment_anchor_address: commentAnchorAddress,
+    })
+};
+
+function askQuestion(courseAnchorAddress, title, text, timestamp) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "ask_question", {
+      course_anchor_address: courseAnchorAddress,
+      title,
+      text,
+      timestamp,
+    })
+};
+
+function getQuestions(courseAnchorAddress, sortBy) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "get_questions", {
+      course_anchor_address: courseAnchorAddress,
+      sort_by: sortBy,
+    })
+};
+
+function answerQuestion(questionAddress, text, timestamp) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "answer_question", {
+      question_address: questionAddress,
+      text,
+      timestamp,
+    })
+};
+
+function getAnswers(questionAddress) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "get_answers", {
+      question_address: questionAddress,
+    })
+};
+
+function acceptAnswer(questionAddress, answerAddress) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "accept_answer", {
+      question_address: questionAddress,
+      answer_address: answerAddress,
+    })
+};
+
+function voteForAnswer(answerAddress) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "vote_for_answer", {
+      answer_address: answerAddress,
+    })
+};
+
+function createAnnouncement(courseAnchorAddress, title, text, timestamp) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "create_announcement", {
+      course_anchor_address: courseAnchorAddress,
+      title,
+      text,
+      timestamp,
+    })
+};
+
+function deliverAnnouncement(announcementAddress) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "deliver_announcement", {
+      announcement_address: announcementAddress,
+    })
+};
+
+function notifyCourseMembers(notice) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "notify_course_members", {
+      notice,
+    })
+};
+
+function getAnnouncements(courseAnchorAddress) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "get_announcements", {
+      course_anchor_address: courseAnchorAddress,
+    })
+};
+
+function getAnnouncementInbox() {
+  return (caller) =>
+    caller.call("course_dna", "courses", "get_announcement_inbox", {})
+};
+
+function sendMessage(toAgent, courseAnchorAddress, body, timestamp) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "send_message", {
+      to_agent: toAgent,
+      course_anchor_address: courseAnchorAddress,
+      body,
+      timestamp,
+    })
+};
+
+function getConversations() {
+  return (caller) =>
+    caller.call("course_dna", "courses", "get_conversations", {})
+};
+
+function getMessages(withAgent) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "get_messages", {
+      with_agent: withAgent,
+    })
+};
+
+function createProfile(username, displayName, bio, avatar, timestamp) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "create_profile", {
+      username,
+      display_name: displayName,
+      bio,
+      avatar,
+      timestamp,
+    })
+};
+
+function updateProfile(displayName, bio, avatar, timestamp) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "update_profile", {
+      display_name: displayName,
+      bio,
+      avatar,
+      timestamp,
+    })
+};
+
+function getProfile(agentAddress) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "get_profile", {
+      agent_address: agentAddress,
+    })
+};
+
+function getProfiles(agentAddresses) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "get_profiles", {
+      agent_addresses: agentAddresses,
+    })
+};
+
+function grantRole(agentAddress, role, timestamp) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "grant_role", {
+      agent_address: agentAddress,
+      role,
+      timestamp,
+    })
+};
+
+function getMyRoles() {
+  return (caller) =>
+    caller.call("course_dna", "courses", "get_my_roles", {})
+};
+
+function revokeRole(agentAddress, role) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "revoke_role", {
+      agent_address: agentAddress,
+      role,
+    })
+};
+
+function listTeachers() {
+  return (caller) =>
+    caller.call("course_dna", "courses", "list_teachers", {})
+};
+
+function forkCourse(courseAnchorAddress, newTitle, timestamp) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "fork_course", {
+      course_anchor_address: courseAnchorAddress,
+      new_title: newTitle,
+      timestamp,
+    })
+};
+
+function getForks(courseAnchorAddress) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "get_forks", {
+      course_anchor_address: courseAnchorAddress,
+    })
+};
+
+function getForkOrigin(courseAnchorAddress) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "get_fork_origin", {
+      course_anchor_address: courseAnchorAddress,
+    })
+};
+
+function exportCourse(courseAnchorAddress) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "export_course", {
+      course_anchor_address: courseAnchorAddress,
+    })
+};
+
+function importCourse(bundle, timestamp) {
+  return (caller) =>
+    caller.call("course_dna", "courses", "import_course", {
+      bundle,
+      timestamp,
+    })
+};
+
+
+/*******  CREATE_COURSE & GET_LATEST_COURSE_ENTRY *********/
+// here we are registering the first test scenario through orchestrator object.
+// registerScenario accepts two arguments. The first arg is the name of your test scenario.
+// The second argument is the actual test that is in a form of async fnction.
+// `s` is an instance of the Scenario API
+// `t` is the tape assertion API
+// In this scenario, we are testing the create_course and get_latest_course_entry function
+orchestrator.registerScenario("Scenario1: Create new course and get latest", async (s, t) => {
+
+  // here, we are saying that there are 2 agents in this test scenario
+  // both of which we are giving the same conductor config we defined above in L26.
+  const { alice, bob } = await s.players(
+    { alice: conductorConfig, bob: conductorConfig },
+    true
+  );
+  const t0 = currentTime();
+
+  // so first we are making alice to create a course 
+  const course_addr = await createCourse("course test 1", t0 + 123)(alice);
+  console.log(course_addr);
+
+  // here we are checking if we are asserting 
+  // that value is truthy
+  t.ok(course_addr.Ok);
+
+  // another use of the Scenario API is to automagically wait
+  // for the network to reach a consistent state
+  // before continuing the test
+  await s.consistency();
+
+  // Now, bob is trying to get the course entry that was committed by alice
+  const course = await getLatestCouseEntry(course_addr.Ok)(bob);
+  console.log("course");
+  console.log(course);
+
+  // this is a strict comparison (===) between
+  // course.Ok and the object in second argument
+  t.deepEqual(course.Ok, {
+    title: "course test 1",
+    timestamp: t0 + 123,
+    teacher_address: alice.instance("course_dna").agentAddress,
+    sections: [],
+    anchor_address: course_addr.Ok,
+  });
+  // Wait for all network activity to settle
+  await s.consistency();
+});
+
+/*********** UPDATE_COURSE ********/
+orchestrator.registerScenario("Scenario2: Update course title", async (s, t) => {
+  const { alice, bob } = await s.players(
+    { alice: conductorConfig, bob: conductorConfig },
+    true
+  );
+  const t0 = currentTime();
+  const course_addr = await createCourse("new course test for update test", t0 + 123)(alice);
+  const course_update_addrss = await updateCourse("course title updated", [], course_addr.Ok, t0 + 420)(alice);
+  await s.consistency();
+  const course = await getLatestCouseEntry(course_addr.Ok)(bob);
+  t.deepEqual(course.Ok, {
+    title: "course title updated",
+    timestamp: t0 + 420,
+    teacher_address: alice.instance("course_dna").agentAddress,
+    sections: [],
+    anchor_address: course_update_addrss.Ok
+  });
+  const course_update_addrss_2 = await updateCourse("new course test for update test", [], course_addr.Ok, t0 + 450)(alice);
+  await s.consistency();
+  const course2 = await getLatestCouseEntry(course_update_addrss_2.Ok)(bob);
+  t.deepEqual(course2.Ok, {
+    title: "new course test for update test",
+    timestamp: t0 + 450,
+    teacher_address: alice.instance("course_dna").agentAddress,
+    sections: [],
+    anchor_address: course_update_addrss.Ok
+  });
+});
+
+/*********** DELETE_COURSE ********/
+orchestrator.registerScenario("Scenario3: Delete course", async (s, t) => {
+  const { alice, bob } = await s.players(
+    { alice: conductorConfig, bob: conductorConfig },
+    true
+  );
+  const t0 = currentTime();
+  const course_addr = await createCourse("new course test for delete scenario", t0 + 123)(alice);
+  await s.consistency();
+
+  const delete_result = await deleteCourse(course_addr.Ok)(alice);
+  await s.consistency();
+  
+  console.log("deleted");
+  console.log(delete_result);
+  t.ok(delete_result.Ok);
+
+  const course = await getLatestCouseEntry(course_addr.Ok)(bob);
+  console.log("course");
+  console.log(course);
+  t.deepEqual(course.Ok, null);
+  await s.consistency();
+
+});
+
+/********* GET_MY_COURSES *********/
+orchestrator.registerScenario("Scenario4: Get All My Courses", async (s, t) => {
+  const { alice, bob } = await s.players(
+    { alice: conductorConfig, bob: conductorConfig },
+    true
+  );
+  const t0 = currentTime();
+  const course_addr_1 = await createCourse("course for scenario 5-1", t0 + 123)(alice);
+  console.log(course_addr_1);
+  t.ok(course_addr_1.Ok);
+
+  await s.consistency();
+
+  const course_addr_2 = await createCourse("course for scenario 5-2", t0 + 420)(alice);
+  console.log(course_addr_2);
+  t.ok(course_addr_2.Ok);
+
+  await s.consistency();
+
+
+  const all_courses_alice = await getMyCourses()(alice);
+  t.true(all_courses_alice.Ok[0] != null);
+  t.true(all_courses_alice.Ok[1] != null);
+
+  const all_courses_bob = await getMyCourses()(bob);
+  t.true(all_courses_bob.Ok[0] == null);
+
+  await s.consistency();
+});
+
+/********** ENROL_IN_COURSE & GET_ALL_STUDENTS & GET_MY_ENROLLED_COURSES **********/
+orchestrator.registerScenario("Scenario5: Create new course and enrol", async (s, t) => {
+  const { alice, bob } = await s.players(
+    { alice: conductorConfig, bob: conductorConfig },
+    true
+  );
+  const t0 = currentTime();
+  const course_addr = await createCourse("course test 1", t0 + 123)(alice);
+  console.log(course_addr);
+  t.ok(course_addr.Ok);
+
+  await s.consistency();
+
+  const enrolled_addr = await enrolInCourse(course_addr.Ok)(bob);
   console.log("enrolled");
   console.log(enrolled_addr);
   t.ok(enrolled_addr.Ok);
 
   await s.consistency();
 
-  const all_enrolled_courses = await getMyEnrolledCourse()(bob);
-  console.log("my_enrolled_courses");
-  console.log(all_enrolled_courses);
-  t.true(all_enrolled_courses.Ok[0] != null);
+  const all_enrolled_courses = await getMyEnrolledCourse()(bob);
+  console.log("my_enrolled_courses");
+  console.log(all_enrolled_courses);
+  t.true(all_enrolled_courses.Ok[0] != null);
+
+  const all_students = await getAllStudents(course_addr.Ok)(alice);
+  console.log("all_students");
+  console.log(all_students);
+  t.true(all_students.Ok[0] != null);
+
+
+  // Wait for all network activity to settle
+  await s.consistency();
+});
+
+// HOMEWORK
+/******** CREATE_SECTION & GET_LATEST_SECTION_ENTRY **********/
+orchestrator.registerScenario("Scenario6: Create new Section for a Course", async (s, t) => {
+  const { alice, bob } = await s.players(
+    { alice: conductorConfig, bob: conductorConfig },
+    true
+  );
+  const t0 = currentTime();
+
+  const course_addr = await createCourse("course for scenario 4", t0 + 123)(alice);
+  console.log(course_addr);
+  t.ok(course_addr.Ok);
+
+  await s.consistency();
+  // Alice can create a module for course because she is the owner
+  const new_section_addr = await createSection("section 1 for course 1", course_addr.Ok, t0 + 400)(alice);
+
+  console.log(new_section_addr);
+  t.ok(new_section_addr.Ok);
+  await s.consistency();
+
+  // Bob can not create a module for course, because he is not the owner of course
+  const fail_add_module_addr = await createSection("section 1 for course 1 by bob", course_addr.Ok, t0 + 400)(bob);
+
+  console.log(fail_add_module_addr);
+  t.error(fail_add_module_addr.Ok);
+  await s.consistency();
+
+  const sectionResult = await getLatestSectionEntry(new_section_addr.Ok)(alice);
+  console.log(sectionResult);
+  t.deepEqual(sectionResult.Ok, {
+    title: "section 1 for course 1",
+    course_address: course_addr.Ok,
+    parent_address: null,
+    subsections: [],
+    release_at: null,
+    timestamp: t0 + 400,
+    anchor_address: new_section_addr.Ok,
+    teacher_address: alice.instance("course_dna").agentAddress,
+    lock: null
+  });
+  await s.consistency();
+});
+
+// HOMEWORK
+/********* CREATE_CONTENT ********/
+orchestrator.registerScenario("Scenario7: Create new Content for a Section", async (s, t) => {
+  const { alice, bob } = await s.players(
+    { alice: conductorConfig, bob: conductorConfig },
+    true
+  );
+  const t0 = currentTime();
+
+  const course_addr = await createCourse("course for scenario 6", t0 + 123)(alice);
+  console.log(course_addr);
+  t.ok(course_addr.Ok);
+
+  await s.consistency();
+  const section_addr = await createSection("section 1 for course 1", course_addr.Ok, t0 + 400)(alice);
+
+  console.log(section_addr);
+  t.ok(section_addr.Ok);
+  await s.consistency();
+
+  const content_addr = await createContent(
+    "content 1 for module 1",
+    section_addr.Ok,
+     "https://youtube.com",
+     t0 + 410,
+    "Holochain Intro"
+  )(alice);
+
+  console.log(content_addr);
+  t.ok(content_addr.Ok);
+  await s.consistency();
+});
+
+// HOMEWORK
+/********* GET_CONTENTS *******/
+orchestrator.registerScenario("Scenario8: Get all contents of a module", async (s, t) => {
+  const { alice, bob } = await s.players(
+    { alice: conductorConfig, bob: conductorConfig },
+    true
+  );
+  const t0 = currentTime();
+  const course_addr = await createCourse("course for scenario 7", t0 + 123)(alice);
+  console.log(course_addr);
+  t.ok(course_addr.Ok);
+
+  await s.consistency();
+  // Alice can create a section for course because she is the owner
+  const section_addr = await createSection("section 1 for course 1", course_addr.Ok, t0 + 400)(alice);
+
+  console.log(section_addr);
+  t.ok(section_addr.Ok);
+  await s.consistency();
+
+  const content_addr_1 = await createContent(
+    "content 1 for module 1",
+    section_addr.Ok,
+    "https://youtube.com",
+    t0 + 410,
+    "Holochain Intro"
+  )(alice);
+
+  console.log(content_addr_1);
+  t.ok(content_addr_1.Ok);
+  await s.consistency();
+
+  const content_addr_2 = await createContent(
+    "content 2 for module 1",
+    section_addr.Ok,
+    "https://youtube.com",
+    t0 + 441,
+    "Holochain Intro-2"
+  )(alice);
+
+  console.log(content_addr_2);
+  t.ok(content_addr_2.Ok);
+  await s.consistency();
+
+
+  const all_contents_of_section_1 = await getContents(section_addr.Ok)(alice);
+
+  t.true(all_contents_of_section_1.Ok[0] != null);
+  t.true(all_contents_of_section_1.Ok[1] != null);
+
+  await s.consistency();
+});
+
+// HOMEWORK
+/********* DELETE_CONTENT & GET_CONTENTS **********/
+orchestrator.registerScenario("Scenario9: delete content from module", async (s, t) => {
+  const { alice, bob } = await s.players(
+    { alice: conductorConfig, bob: conductorConfig },
+    true
+  );
+  const t0 = currentTime();
+  const course_addr = await createCourse("course for scenario 8", t0 + 123)(alice);
+  console.log(course_addr);
+  t.ok(course_addr.Ok);
+
+  await s.consistency();
+  // Alice can create a module for course because she is the owner
+  const section_addr = await createSection("section 1 for course 1", course_addr.Ok, t0 + 400)(alice);
+
+  console.log(section_addr);
+  t.ok(section_addr.Ok);
+  await s.consistency();
+
+  const content_addr_1 = await createContent(
+    "content 1 for module 1",
+    section_addr.Ok,
+    "https://youtube.com",
+    t0 + 410,
+    "Holochain Intro"
+  )(alice);
+
+  console.log(content_addr_1);
+  t.ok(content_addr_1.Ok);
+  await s.consistency();
+
+  const content_addr_2 = await createContent(
+    "content 2 for module 1",
+    section_addr.Ok,
+    "https://youtube.com",
+    t0 + 441,
+    "Holochain Intro-2"
+  )(alice);
+
+  console.log(content_addr_2);
+  t.ok(content_addr_2.Ok);
+  await s.consistency();
+
+  const all_contents_of_section_1 = await getContents(section_addr.Ok)(alice);
+
+  t.true(all_contents_of_section_1.Ok[0] != null);
+  t.true(all_contents_of_section_1.Ok[1] != null);
+  await s.consistency();
+
+  const delete_content = await deleteContent(content_addr_1.Ok)(alice)
+  
+  console.log("delete_content");
+  console.log(delete_content);
+  t.ok(delete_content.Ok);
+
+  await s.consistency();
+
+  const all_contents_of_section_1_again = await getContents(section_addr.Ok)(alice);
+
+  t.true(all_contents_of_section_1_again.Ok[0] != null);
+  t.true(all_contents_of_section_1_again.Ok[1] == null);
+
+  await s.consistency();
+});
+
+// HOMEWORK
+/***** DELETE_SECTION ***********/
+orchestrator.registerScenario(
+  "Scenario10: delete module from course",
+  async (s, t) => {
+    const { alice, bob } = await s.players(
+      { alice: conductorConfig, bob: conductorConfig },
+      true
+    );
+    const t0 = currentTime();
+
+    const course_addr = await createCourse("course for scenario 9: debugging purpose", t0 + 123)(alice);
+    console.log("course_addr");
+    console.log(course_addr);
+    t.ok(course_addr.Ok);
+
+    await s.consistency();
+    // Alice can create a module for course because she is the owner
+    const section_addr = await createSection("section 1 for course 1", course_addr.Ok, t0 + 400)(alice);
+
+    console.log(section_addr);
+    t.ok(section_addr.Ok);
+    await s.consistency();
+
+    const courseResult = await getMyCourses()(alice);
+    console.log("get_my_courses");
+    console.log(courseResult.Ok);
+    t.true(courseResult.Ok[0] != null);
+    await s.consistency();
+
+    console.log("get_latest_course_entry");
+    const course_again = await getLatestCouseEntry(course_addr.Ok)(alice)
+    console.log(course_again.Ok);
+    await s.consistency();
+
+    const content_addr_1 = await createContent(
+      "content 1 for module 1",
+      section_addr.Ok,
+      "https://youtube.com",
+      t0 + 440,
+      "Holochain Intro-Video"
+      )(alice);
+    console.log(content_addr_1);
+    t.ok(content_addr_1.Ok);
+    await s.consistency();
+
+    const content_addr_2 = await createContent(
+      "content 2 for module 1",
+      section_addr.Ok,
+      "https://soundclould.com",
+      t0 + 441,
+      "Holochain Intro-Sound"
+      )(alice);
+
+    console.log(content_addr_2);
+    t.ok(content_addr_2.Ok);
+    await s.consistency();
+
+    const all_contents_of_module_1 = await getContents(section_addr.Ok)(alice);
+
+    t.true(all_contents_of_module_1.Ok[0] != null);
+    t.true(all_contents_of_module_1.Ok[1] != null);
+    await s.consistency();
+
+    const delete_content = await deleteContent(content_addr_1.Ok)(alice)
+    console.log("delete_content");
+    console.log(delete_content);
+    t.ok(delete_content.Ok);
+
+    await s.consistency();
+
+    const all_contents_of_section_1_again = await getContents(section_addr.Ok)(alice);
+
+    t.true(all_contents_of_section_1_again.Ok[0] != null);
+    t.true(all_contents_of_section_1_again.Ok[1] == null);
+
+    await s.consistency();
+
+    const delete_section = await deleteSection(section_addr.Ok, t0 + 430)(alice)
+    console.log(delete_section);
+    t.ok(delete_section.Ok);
+    await s.consistency();
+  }
+);
+
+// HOMEWORK
+/********** UPDATE_SECTION & UPDATE_CONTENT ********/
+orchestrator.registerScenario("Scenario11: update section and content", async (s, t) => {
+  const { alice, bob } = await s.players(
+    { alice: conductorConfig, bob: conductorConfig },
+    true
+  );
+  const t0 = currentTime();
+  const course_addr = await createCourse("course test 1", t0 + 123)(alice);
+  console.log(course_addr);
+  t.ok(course_addr.Ok);
+
+  await s.consistency();
+  
+  const section_addr = await createSection("section 1 for course 1", course_addr.Ok, t0 + 234)(alice);
+  t.ok(section_addr.Ok);
+  
+  await s.consistency();
+
+  const content_addr = await createContent(
+    "content 1 for section 1",
+    section_addr.Ok,
+    "https://youtube.com",
+    t0 + 440,
+    "Holochain Intro-Video"
+    )(alice);
+  console.log(content_addr);
+  t.ok(content_addr.Ok);
+
+  await s.consistency();
+  
+  const updated_section_addr = await updateSection("updated section title", section_addr.Ok, t0 + 450)(alice);
+  console.log("update_section")
+  console.log(updated_section_addr.Ok)
+  t.ok(updated_section_addr.Ok);
+  
+  await s.consistency();
+  
+  // this will fail since bob is not the teacher
+  const updated_section_addr_fail = await updateSection("updated section title", section_addr.Ok, t0 + 460)(bob);
+  console.log("update_section failed");
+  console.log(updated_section_addr_fail);
+  t.error(updated_section_addr_fail.Ok);
+  
+  await s.consistency();
+  
+  const update_content_addr = await updateContent(
+    content_addr.Ok,
+    "updated content for section 1",
+    "https://youtube.com",
+    "Holochain Intro-Video",
+    t0 + 460
+    )(alice);
+    console.log("updated_content");
+    console.log(update_content_addr.Ok);
+    t.ok(update_content_addr.Ok)
+    
+  await s.consistency();
+
+  // this will also fail since bob is not the teacher of this course
+  const update_content_addr_fail = await updateContent(
+    update_content_addr.Ok,
+    "updated content for section 1",
+    "https://youtube.com",
+    "Holochain Intro-Video",
+    t0 + 461
+  )(bob);
+  console.log("update_content failed");
+  console.log(update_content_addr_fail);
+  t.error(update_content_addr_fail.Ok);
+
+  // Wait for all network activity to settle
+  await s.consistency();
+});
+
+orchestrator.run();
+
+/********** ASSIGNMENTS & SUBMISSIONS & GRADES ********/
+orchestrator.registerScenario("Scenario12: submit and grade an assignment", async (s, t) => {
+  const { alice, bob, carol } = await s.players(
+    { alice: conductorConfig, bob: conductorConfig, carol: conductorConfig },
+    true
+  );
+  const t0 = currentTime();
+  const course_addr = await createCourse("course for scenario 12", t0 + 123)(alice);
+  t.ok(course_addr.Ok);
+  await s.consistency();
+
+  const section_addr = await createSection("section 1 for course 1", course_addr.Ok, t0 + 234)(alice);
+  t.ok(section_addr.Ok);
+  await s.consistency();
+
+  const assignment_addr = await createAssignment(
+    "homework 1",
+    "Write your first zome function",
+    100000,
+    10,
+    section_addr.Ok,
+    345
+  )(alice);
+  console.log(assignment_addr);
+  t.ok(assignment_addr.Ok);
+  await s.consistency();
+
+  // bob can't create assignments because he isn't the teacher of this course
+  const assignment_addr_fail = await createAssignment(
+    "homework by bob",
+    "Write your first zome function",
+    100000,
+    10,
+    section_addr.Ok,
+    345
+  )(bob);
+  t.error(assignment_addr_fail.Ok);
+
+  const enrolled_addr = await enrolInCourse(course_addr.Ok)(bob);
+  t.ok(enrolled_addr.Ok);
+  await s.consistency();
+
+  const submission_addr = await submitAssignment(assignment_addr.Ok, "my zome function", 456)(bob);
+  console.log(submission_addr);
+  t.ok(submission_addr.Ok);
+  await s.consistency();
+
+  // carol can't submit her work because she isn't enrolled in this course
+  const submission_addr_fail = await submitAssignment(assignment_addr.Ok, "carol's answer", 456)(carol);
+  t.error(submission_addr_fail.Ok);
+  await s.consistency();
+
+  const my_submissions = await getMySubmissions()(bob);
+  t.deepEqual(my_submissions.Ok, [submission_addr.Ok]);
+
+  const ungraded_submissions = await getUngradedSubmissions(assignment_addr.Ok)(alice);
+  t.deepEqual(ungraded_submissions.Ok, [submission_addr.Ok]);
+
+  // bob can't grade his own work because he isn't the teacher of this course
+  const grade_addr_fail = await gradeSubmission(submission_addr.Ok, 10, "great job", 567)(bob);
+  t.error(grade_addr_fail.Ok);
+
+  const grade_addr = await gradeSubmission(submission_addr.Ok, 9, "great job", 567)(alice);
+  console.log(grade_addr);
+  t.ok(grade_addr.Ok);
+  await s.consistency();
+
+  const all_submissions = await getSubmissions(assignment_addr.Ok)(alice);
+  t.deepEqual(all_submissions.Ok, [submission_addr.Ok]);
+
+  const ungraded_submissions_again = await getUngradedSubmissions(assignment_addr.Ok)(alice);
+  t.deepEqual(ungraded_submissions_again.Ok, []);
+
+  await s.consistency();
+});
+
+/********** MARK_CONTENT_DONE & GET_MY_PROGRESS ********/
+orchestrator.registerScenario("Scenario13: track student progress through a course", async (s, t) => {
+  const { alice, bob } = await s.players(
+    { alice: conductorConfig, bob: conductorConfig },
+    true
+  );
+  const t0 = currentTime();
+  const course_addr = await createCourse("course for scenario 13", t0 + 123)(alice);
+  t.ok(course_addr.Ok);
+  await s.consistency();
+
+  const section_addr = await createSection("section 1 for course 1", course_addr.Ok, t0 + 234)(alice);
+  t.ok(section_addr.Ok);
+  await s.consistency();
+
+  const content_addr_1 = await createContent(
+    "content 1 for section 1",
+    section_addr.Ok,
+    "https://youtube.com",
+    t0 + 345,
+    "Holochain Intro"
+  )(alice);
+  t.ok(content_addr_1.Ok);
+  const content_addr_2 = await createContent(
+    "content 2 for section 1",
+    section_addr.Ok,
+    "https://youtube.com",
+    t0 + 346,
+    "Holochain Intro-2"
+  )(alice);
+  t.ok(content_addr_2.Ok);
+  await s.consistency();
+
+  const enrolled_addr = await enrolInCourse(course_addr.Ok)(bob);
+  t.ok(enrolled_addr.Ok);
+  await s.consistency();
+
+  const done_addr = await markContentDone(content_addr_1.Ok, 456)(bob);
+  t.ok(done_addr.Ok);
+  // marking the same content twice doesn't count it twice
+  const done_addr_again = await markContentDone(content_addr_1.Ok, 457)(bob);
+  t.equal(done_addr_again.Ok, done_addr.Ok);
+
+  const progress = await getMyProgress(course_addr.Ok)(bob);
+  console.log(progress);
+  t.deepEqual(progress.Ok, {
+    course_anchor_address: course_addr.Ok,
+    sections: [
+      {
+        section_anchor_address: section_addr.Ok,
+        completed: 1,
+        total: 2,
+      }
+    ],
+    completed: 1,
+    total: 2,
+    percentage: 50.0,
+  });
+
+  // progress is private so alice doesn't see bob's completed content
+  const alice_progress = await getMyProgress(course_addr.Ok)(alice);
+  t.equal(alice_progress.Ok.completed, 0);
+
+  await s.consistency();
+});
+
+/********** ISSUE_CERTIFICATE & VERIFY_CERTIFICATE & REVOKE_CERTIFICATE ********/
+orchestrator.registerScenario("Scenario14: issue, verify and revoke a certificate", async (s, t) => {
+  const { alice, bob, carol } = await s.players(
+    { alice: conductorConfig, bob: conductorConfig, carol: conductorConfig },
+    true
+  );
+  const t0 = currentTime();
+  const bob_address = bob.instance("course_dna").agentAddress;
+  const carol_address = carol.instance("course_dna").agentAddress;
+
+  const course_addr = await createCourse("course for scenario 14", t0 + 123)(alice);
+  t.ok(course_addr.Ok);
+  await s.consistency();
+
+  const enrolled_addr = await enrolInCourse(course_addr.Ok)(bob);
+  t.ok(enrolled_addr.Ok);
+  await s.consistency();
+
+  // carol isn't enrolled so she can't get a certificate
+  const certificate_addr_fail = await issueCertificate(carol_address, course_addr.Ok, 456)(alice);
+  t.error(certificate_addr_fail.Ok);
+
+  // bob can't issue a certificate to himself because he isn't the teacher
+  const certificate_addr_fail_2 = await issueCertificate(bob_address, course_addr.Ok, 456)(bob);
+  t.error(certificate_addr_fail_2.Ok);
+
+  const certificate_addr = await issueCertificate(bob_address, course_addr.Ok, 456)(alice);
+  console.log(certificate_addr);
+  t.ok(certificate_addr.Ok);
+  await s.consistency();
+
+  const bob_certificates = await getCertificates(bob_address)(carol);
+  t.deepEqual(bob_certificates.Ok, [certificate_addr.Ok]);
 
-  const all_students = await getAllStudents(course_addr.Ok)(alice);
-  console.log("all_students");
-  console.log(all_students);
-  t.true(all_students.Ok[0] != null);
+  const verification = await verifyCertificate(certificate_addr.Ok)(carol);
+  console.log(verification);
+  t.true(verification.Ok.valid);
+  t.true(verification.Ok.signature_valid);
+  t.false(verification.Ok.revoked);
+
+  const revoke_result = await revokeCertificate(certificate_addr.Ok)(alice);
+  t.ok(revoke_result.Ok);
+  await s.consistency();
 
+  const verification_after_revoke = await verifyCertificate(certificate_addr.Ok)(carol);
+  t.false(verification_after_revoke.Ok.valid);
+  t.true(verification_after_revoke.Ok.revoked);
+
+  const bob_certificates_after_revoke = await getCertificates(bob_address)(carol);
+  t.deepEqual(bob_certificates_after_revoke.Ok, []);
 
-  // Wait for all network activity to settle
   await s.consistency();
 });
 
-// HOMEWORK
-/******** CREATE_SECTION & GET_LATEST_SECTION_ENTRY **********/
-orchestrator.registerScenario("Scenario6: Create new Section for a Course", async (s, t) => {
+/********** CREATE_REVIEW & UPDATE_REVIEW & GET_COURSE_RATING_SUMMARY ********/
+orchestrator.registerScenario("Scenario15: review a course and get its rating", async (s, t) => {
+  const { alice, bob, carol } = await s.players(
+    { alice: conductorConfig, bob: conductorConfig, carol: conductorConfig },
+    true
+  );
+  const t0 = currentTime();
+  const course_addr = await createCourse("course for scenario 15", t0 + 123)(alice);
+  t.ok(course_addr.Ok);
+  await s.consistency();
+
+  const enrolled_addr = await enrolInCourse(course_addr.Ok)(bob);
+  t.ok(enrolled_addr.Ok);
+  await s.consistency();
+
+  // teacher can't review their own course
+  const review_addr_teacher = await createReview(course_addr.Ok, 5, "best course ever", 234)(alice);
+  t.error(review_addr_teacher.Ok);
+
+  // carol isn't enrolled in this course so she can't review it
+  const review_addr_carol = await createReview(course_addr.Ok, 1, "didn't take it", 234)(carol);
+  t.error(review_addr_carol.Ok);
+
+  // rating has to be from 1 to 5 stars
+  const review_addr_invalid = await createReview(course_addr.Ok, 6, "too good", 234)(bob);
+  t.error(review_addr_invalid.Ok);
+
+  const review_addr = await createReview(course_addr.Ok, 3, "good course", 234)(bob);
+  console.log(review_addr);
+  t.ok(review_addr.Ok);
+  await s.consistency();
+
+  // bob can only review this course once
+  const review_addr_again = await createReview(course_addr.Ok, 4, "still good", 345)(bob);
+  t.error(review_addr_again.Ok);
+
+  const updated_review_addr = await updateReview(review_addr.Ok, 4, "even better after update", 456)(bob);
+  t.equal(updated_review_addr.Ok, review_addr.Ok);
+  await s.consistency();
+
+  const summary = await getCourseRatingSummary(course_addr.Ok)(carol);
+  console.log(summary);
+  t.deepEqual(summary.Ok, {
+    average: 4.0,
+    count: 1,
+    histogram: [0, 0, 0, 1, 0],
+  });
+
+  await s.consistency();
+});
+
+/********** COMMENTS & REPLIES & MODERATION ********/
+orchestrator.registerScenario("Scenario16: discuss a section and moderate comments", async (s, t) => {
+  const { alice, bob, carol } = await s.players(
+    { alice: conductorConfig, bob: conductorConfig, carol: conductorConfig },
+    true
+  );
+  const t0 = currentTime();
+  const course_addr = await createCourse("course for scenario 16", t0 + 123)(alice);
+  t.ok(course_addr.Ok);
+  await s.consistency();
+
+  const section_addr = await createSection("section 1 for course 1", course_addr.Ok, t0 + 234)(alice);
+  t.ok(section_addr.Ok);
+  await s.consistency();
+
+  const enrolled_addr = await enrolInCourse(course_addr.Ok)(bob);
+  t.ok(enrolled_addr.Ok);
+  await s.consistency();
+
+  // carol isn't enrolled so she can't take part in the discussion
+  const comment_addr_fail = await createComment(section_addr.Ok, "hi from carol", 345)(carol);
+  t.error(comment_addr_fail.Ok);
+
+  const comment_addr = await createComment(section_addr.Ok, "what is a DHT?", 345)(bob);
+  console.log(comment_addr);
+  t.ok(comment_addr.Ok);
+  await s.consistency();
+
+  const reply_addr = await replyToComment(comment_addr.Ok, "see the next lesson", 456)(alice);
+  t.ok(reply_addr.Ok);
+  await s.consistency();
+
+  const comments = await getComments(section_addr.Ok)(bob);
+  t.deepEqual(comments.Ok, [comment_addr.Ok]);
+  const replies = await getReplies(comment_addr.Ok)(bob);
+  t.deepEqual(replies.Ok, [reply_addr.Ok]);
+
+  // only the author can edit the comment
+  const update_fail = await updateComment(comment_addr.Ok, "edited by teacher", 567)(alice);
+  t.error(update_fail.Ok);
+  const update_result = await updateComment(comment_addr.Ok, "what is a DHT exactly?", 567)(bob);
+  t.ok(update_result.Ok);
+  await s.consistency();
+
+  // only the teacher can hide the comment
+  const hide_fail = await hideComment(reply_addr.Ok, 678)(bob);
+  t.error(hide_fail.Ok);
+  const hide_result = await hideComment(comment_addr.Ok, 678)(alice);
+  t.ok(hide_result.Ok);
+  await s.consistency();
+
+  const hidden_comment = await getLatestCommentEntry(comment_addr.Ok)(alice);
+  t.true(hidden_comment.Ok.hidden);
+  t.equal(hidden_comment.Ok.text, "what is a DHT exactly?");
+
+  const delete_result = await deleteComment(reply_addr.Ok)(alice);
+  t.ok(delete_result.Ok);
+  await s.consistency();
+
+  const replies_after_delete = await getReplies(comment_addr.Ok)(bob);
+  t.deepEqual(replies_after_delete.Ok, []);
+
+  // comments on a deleted section can still be deleted
+  await deleteSection(section_addr.Ok, t0 + 345)(alice);
+  await s.consistency();
+  const orphan_delete_result = await deleteComment(comment_addr.Ok)(bob);
+  t.ok(orphan_delete_result.Ok);
+
+  await s.consistency();
+});
+
+/********** QUESTIONS & ANSWERS & VOTES ********/
+orchestrator.registerScenario("Scenario17: ask, answer, vote and accept on the Q&A board", async (s, t) => {
+  const { alice, bob, carol } = await s.players(
+    { alice: conductorConfig, bob: conductorConfig, carol: conductorConfig },
+    true
+  );
+  const t0 = currentTime();
+  const course_addr = await createCourse("course for scenario 17", t0 + 123)(alice);
+  t.ok(course_addr.Ok);
+  await s.consistency();
+
+  await enrolInCourse(course_addr.Ok)(bob);
+  await enrolInCourse(course_addr.Ok)(carol);
+  await s.consistency();
+
+  // teacher doesn't ask questions on the Q&A board of their course
+  const question_addr_fail = await askQuestion(course_addr.Ok, "question", "by teacher", 234)(alice);
+  t.error(question_addr_fail.Ok);
+
+  const question_addr_1 = await askQuestion(course_addr.Ok, "what is a zome?", "please explain", 234)(bob);
+  t.ok(question_addr_1.Ok);
+  const question_addr_2 = await askQuestion(course_addr.Ok, "what is a DNA?", "please explain", 235)(bob);
+  t.ok(question_addr_2.Ok);
+  await s.consistency();
+
+  const answer_addr_1 = await answerQuestion(question_addr_1.Ok, "a module of a DNA", 345)(carol);
+  t.ok(answer_addr_1.Ok);
+  const answer_addr_2 = await answerQuestion(question_addr_1.Ok, "a piece of wasm code", 346)(alice);
+  t.ok(answer_addr_2.Ok);
+  await s.consistency();
+
+  const vote_addr = await voteForAnswer(answer_addr_2.Ok)(bob);
+  t.ok(vote_addr.Ok);
+  await s.consistency();
+  // bob can vote only once for the same answer
+  const vote_addr_again = await voteForAnswer(answer_addr_2.Ok)(bob);
+  t.error(vote_addr_again.Ok);
+  // carol can't vote for her own answer
+  const vote_addr_own = await voteForAnswer(answer_addr_1.Ok)(carol);
+  t.error(vote_addr_own.Ok);
+
+  const answers = await getAnswers(question_addr_1.Ok)(carol);
+  t.deepEqual(answers.Ok, [
+    { answer_address: answer_addr_2.Ok, votes: 1, accepted: false },
+    { answer_address: answer_addr_1.Ok, votes: 0, accepted: false },
+  ]);
+
+  // only the asker or the teacher can accept an answer
+  const accept_fail = await acceptAnswer(question_addr_1.Ok, answer_addr_1.Ok)(carol);
+  t.error(accept_fail.Ok);
+  const accept_result = await acceptAnswer(question_addr_1.Ok, answer_addr_1.Ok)(bob);
+  t.ok(accept_result.Ok);
+  await s.consistency();
+
+  const answers_after_accept = await getAnswers(question_addr_1.Ok)(carol);
+  t.equal(answers_after_accept.Ok[0].answer_address, answer_addr_1.Ok);
+  t.true(answers_after_accept.Ok[0].accepted);
+
+  const unanswered = await getQuestions(course_addr.Ok, "Unanswered")(alice);
+  t.deepEqual(unanswered.Ok.map((question) => question.question_address), [question_addr_2.Ok, question_addr_1.Ok]);
+
+  const most_voted = await getQuestions(course_addr.Ok, "MostVoted")(alice);
+  t.deepEqual(most_voted.Ok.map((question) => question.question_address), [question_addr_1.Ok, question_addr_2.Ok]);
+
+  await s.consistency();
+});
+
+/********** CREATE_ANNOUNCEMENT & GET_ANNOUNCEMENTS & GET_ANNOUNCEMENT_INBOX ********/
+orchestrator.registerScenario("Scenario18: post announcements and deliver them to students", async (s, t) => {
+  const { alice, bob, carol } = await s.players(
+    { alice: conductorConfig, bob: conductorConfig, carol: conductorConfig },
+    true
+  );
+  const t0 = currentTime();
+  const course_addr = await createCourse("course for scenario 18", t0 + 123)(alice);
+  t.ok(course_addr.Ok);
+  await s.consistency();
+
+  await enrolInCourse(course_addr.Ok)(bob);
+  await s.consistency();
+
+  // only the teacher can make announcements
+  const announcement_addr_fail = await createAnnouncement(course_addr.Ok, "by student", "text", 234)(bob);
+  t.error(announcement_addr_fail.Ok);
+
+  const announcement_addr = await createAnnouncement(course_addr.Ok, "welcome", "first lesson is tomorrow", 234)(alice);
+  t.ok(announcement_addr.Ok);
+  await s.consistency();
+
+  const announcements = await getAnnouncements(course_addr.Ok)(carol);
+  t.deepEqual(announcements.Ok, [announcement_addr.Ok]);
+
+  // only the teacher can deliver announcements
+  const delivered_fail = await deliverAnnouncement(announcement_addr.Ok)(bob);
+  t.error(delivered_fail.Ok);
+  const delivered = await deliverAnnouncement(announcement_addr.Ok)(alice);
+  t.deepEqual(delivered.Ok, [bob.instance("course_dna").agentAddress]);
+
+  // enrolled student got the announcement delivered into the inbox
+  const bob_inbox = await getAnnouncementInbox()(bob);
+  t.equal(bob_inbox.Ok.length, 1);
+  t.equal(bob_inbox.Ok[0].announcement_address, announcement_addr.Ok);
+  t.equal(bob_inbox.Ok[0].announcement.title, "welcome");
+
+  // carol isn't enrolled in the course so nothing was delivered to her
+  const carol_inbox = await getAnnouncementInbox()(carol);
+  t.deepEqual(carol_inbox.Ok, []);
+
+  await s.consistency();
+});
+
+/********** SEND_MESSAGE & GET_CONVERSATIONS & GET_MESSAGES ********/
+orchestrator.registerScenario("Scenario19: direct messages between teacher and students", async (s, t) => {
+  const { alice, bob, carol } = await s.players(
+    { alice: conductorConfig, bob: conductorConfig, carol: conductorConfig },
+    true
+  );
+  const t0 = currentTime();
+  const course_addr = await createCourse("course for scenario 19", t0 + 123)(alice);
+  t.ok(course_addr.Ok);
+  await s.consistency();
+
+  await enrolInCourse(course_addr.Ok)(bob);
+  await s.consistency();
+
+  const message_addr_1 = await sendMessage(alice.instance("course_dna").agentAddress, course_addr.Ok, "hi, I have a question", 234)(bob);
+  t.ok(message_addr_1.Ok);
+  const message_addr_2 = await sendMessage(bob.instance("course_dna").agentAddress, course_addr.Ok, "sure, ask away", 235)(alice);
+  t.ok(message_addr_2.Ok);
+
+  // carol isn't enrolled in the course so she can't message it's teacher
+  const message_addr_fail = await sendMessage(alice.instance("course_dna").agentAddress, course_addr.Ok, "hello", 236)(carol);
+  t.error(message_addr_fail.Ok);
+
+  const bob_messages = await getMessages(alice.instance("course_dna").agentAddress)(bob);
+  t.deepEqual(bob_messages.Ok.map((message) => message.body), ["hi, I have a question", "sure, ask away"]);
+
+  const alice_conversations = await getConversations()(alice);
+  t.equal(alice_conversations.Ok.length, 1);
+  t.equal(alice_conversations.Ok[0].with_agent, bob.instance("course_dna").agentAddress);
+  t.equal(alice_conversations.Ok[0].message_count, 2);
+  t.equal(alice_conversations.Ok[0].last_message.body, "sure, ask away");
+
+  await s.consistency();
+});
+
+/********** CREATE_PROFILE & UPDATE_PROFILE & GET_PROFILES ********/
+orchestrator.registerScenario("Scenario20: profiles with unique usernames", async (s, t) => {
+  const { alice, bob, carol } = await s.players(
+    { alice: conductorConfig, bob: conductorConfig, carol: conductorConfig },
+    true
+  );
+  const alice_address = alice.instance("course_dna").agentAddress;
+  const bob_address = bob.instance("course_dna").agentAddress;
+  const carol_address = carol.instance("course_dna").agentAddress;
+
+  const alice_profile_addr = await createProfile("alice", "Alice", "teaches holochain", "", 123)(alice);
+  t.ok(alice_profile_addr.Ok);
+  await s.consistency();
+
+  // username is already taken by alice
+  const bob_profile_fail = await createProfile("alice", "Bob", "", "", 124)(bob);
+  t.error(bob_profile_fail.Ok);
+  // usernames can only have lowercase letters, digits and underscores
+  const bob_profile_invalid = await createProfile("Bob!", "Bob", "", "", 124)(bob);
+  t.error(bob_profile_invalid.Ok);
+  const bob_profile_addr = await createProfile("bob", "Bob", "", "", 125)(bob);
+  t.ok(bob_profile_addr.Ok);
+  await s.consistency();
+
+  const update_result = await updateProfile("Alice A.", "teaches holochain and rust", "https://example.com/alice.png", 234)(alice);
+  t.ok(update_result.Ok);
+  await s.consistency();
+
+  const alice_profile = await getProfile(alice_address)(carol);
+  t.equal(alice_profile.Ok.username, "alice");
+  t.equal(alice_profile.Ok.display_name, "Alice A.");
+  t.equal(alice_profile.Ok.avatar, "https://example.com/alice.png");
+
+  const profiles = await getProfiles([alice_address, bob_address, carol_address])(carol);
+  t.deepEqual(profiles.Ok.map((profile) => profile.profile ? profile.profile.username : null), ["alice", "bob", null]);
+
+  await s.consistency();
+});
+
+/********** GRANT_ROLE & GET_MY_ROLES & LIST_TEACHERS ********/
+orchestrator.registerScenario("Scenario21: roles aren't enforced in a DNA without admins", async (s, t) => {
   const { alice, bob } = await s.players(
     { alice: conductorConfig, bob: conductorConfig },
     true
   );
+  const t0 = currentTime();
+  // test DNA doesn't list any admins so nobody can grant roles
+  const grant_result = await grantRole(bob.instance("course_dna").agentAddress, "teacher", 123)(alice);
+  t.error(grant_result.Ok);
+  await s.consistency();
 
-  const course_addr = await createCourse("course for scenario 4", 123)(alice);
-  console.log(course_addr);
+  const bob_roles = await getMyRoles()(bob);
+  t.deepEqual(bob_roles.Ok, []);
+  const teachers = await listTeachers()(alice);
+  t.deepEqual(teachers.Ok, []);
+
+  // but everyone can still create courses
+  const course_addr = await createCourse("course for scenario 21", t0 + 234)(bob);
   t.ok(course_addr.Ok);
 
   await s.consistency();
-  // Alice can create a module for course because she is the owner
-  const new_section_addr = await createSection("section 1 for course 1", course_addr.Ok, 456)(alice);
+});
 
-  console.log(new_section_addr);
-  t.ok(new_section_addr.Ok);
+/********** FORK_COURSE & GET_FORKS & GET_FORK_ORIGIN ********/
+orchestrator.registerScenario("Scenario22: fork a course with all it's sections and contents", async (s, t) => {
+  const { alice, bob } = await s.players(
+    { alice: conductorConfig, bob: conductorConfig },
+    true
+  );
+  const t0 = currentTime();
+  const course_addr = await createCourse("course for scenario 22", t0 + 123)(alice);
+  t.ok(course_addr.Ok);
+  await s.consistency();
+  const section_addr = await createSection("section 1", course_addr.Ok, t0 + 124)(alice);
+  t.ok(section_addr.Ok);
+  await s.consistency();
+  const content_addr = await createContent("content 1", section_addr.Ok, "https://example.com", t0 + 125, "description")(alice);
+  t.ok(content_addr.Ok);
   await s.consistency();
 
-  // Bob can not create a module for course, because he is not the owner of course
-  const fail_add_module_addr = await createSection("section 1 for course 1 by bob", course_addr.Ok, 456)(bob);
+  const fork_addr = await forkCourse(course_addr.Ok, "bob's version", t0 + 234)(bob);
+  t.ok(fork_addr.Ok);
+  await s.consistency();
+
+  const fork = await getLatestCouseEntry(fork_addr.Ok)(bob);
+  t.equal(fork.Ok.title, "bob's version");
+  t.equal(fork.Ok.teacher_address, bob.instance("course_dna").agentAddress);
+  t.equal(fork.Ok.sections.length, 1);
+
+  const fork_section = await getLatestSectionEntry(fork.Ok.sections[0])(bob);
+  t.equal(fork_section.Ok.title, "section 1");
+  t.equal(fork_section.Ok.teacher_address, bob.instance("course_dna").agentAddress);
+  const fork_contents = await getContents(fork.Ok.sections[0])(bob);
+  t.equal(fork_contents.Ok.length, 1);
+
+  const forks = await getForks(course_addr.Ok)(alice);
+  t.deepEqual(forks.Ok, [fork_addr.Ok]);
+  const origin = await getForkOrigin(fork_addr.Ok)(alice);
+  t.equal(origin.Ok, course_addr.Ok);
+  const no_origin = await getForkOrigin(course_addr.Ok)(alice);
+  t.equal(no_origin.Ok, null);
 
-  console.log(fail_add_module_addr);
-  t.error(fail_add_module_addr.Ok);
   await s.consistency();
+});
+
+/********** EXPORT_COURSE ********/
+orchestrator.registerScenario("Scenario23: export a course as a JSON bundle", async (s, t) => {
+  const { alice } = await s.players({ alice: conductorConfig }, true);
+  const t0 = currentTime();
+  const course_addr = await createCourse("course for scenario 23", t0 + 123)(alice);
+  t.ok(course_addr.Ok);
+  await s.consistency();
+  const section_addr_1 = await createSection("section 1", course_addr.Ok, t0 + 124)(alice);
+  await s.consistency();
+  const section_addr_2 = await createSection("section 2", course_addr.Ok, t0 + 125)(alice);
+  await s.consistency();
+  await createContent("content 2", section_addr_1.Ok, "https://example.com/2", t0 + 127, "second")(alice);
+  await createContent("content 1", section_addr_1.Ok, "https://example.com/1", t0 + 126, "first")(alice);
+  await s.consistency();
+
+  const bundle = await exportCourse(course_addr.Ok)(alice);
+  t.equal(bundle.Ok.format_version, 1);
+  t.equal(bundle.Ok.course.title, "course for scenario 23");
+  t.deepEqual(bundle.Ok.sections.map((section) => section.title), ["section 1", "section 2"]);
+  t.deepEqual(bundle.Ok.sections[0].contents.map((content) => content.name), ["content 1", "content 2"]);
+  t.deepEqual(bundle.Ok.sections[1].contents, []);
 
-  const sectionResult = await getLatestSectionEntry(new_section_addr.Ok)(alice);
-  console.log(sectionResult);
-  t.deepEqual(sectionResult.Ok, {
-    title: "section 1 for course 1",
-    course_address: course_addr.Ok,
-    timestamp: 456,
-    anchor_address: new_section_addr.Ok,
-    teacher_address: alice.instance("course_dna").agentAddress
-  });
   await s.consistency();
 });
 
-// HOMEWORK
-/********* CREATE_CONTENT ********/
-orchestrator.registerScenario("Scenario7: Create new Content for a Section", async (s, t) => {
+/********** IMPORT_COURSE ********/
+orchestrator.registerScenario("Scenario24: import a course from a JSON bundle", async (s, t) => {
+  const { alice, bob } = await s.players(
+    { alice: conductorConfig, bob: conductorConfig },
+    true
+  );
+  const t0 = currentTime();
+  const course_addr = await createCourse("course for scenario 24", t0 + 123)(alice);
+  t.ok(course_addr.Ok);
+  await s.consistency();
+  const section_addr = await createSection("section 1", course_addr.Ok, t0 + 124)(alice);
+  await s.consistency();
+  await createContent("content 1", section_addr.Ok, "https://example.com/1", t0 + 125, "first")(alice);
+  await s.consistency();
+
+  const bundle = await exportCourse(course_addr.Ok)(alice);
+  t.ok(bundle.Ok);
+
+  // invalid bundle is rejected as a whole with an error for every invalid item
+  const invalid_bundle = JSON.parse(JSON.stringify(bundle.Ok));
+  invalid_bundle.course.title = "x".repeat(51);
+  invalid_bundle.sections[0].contents[0].name = "";
+  const invalid_report = await importCourse(invalid_bundle, t0 + 234)(bob);
+  t.equal(invalid_report.Ok.course_anchor_address, null);
+  t.deepEqual(invalid_report.Ok.errors.map((error) => error.item), ["course", "sections[0].contents[0]"]);
+
+  const unsupported_bundle = Object.assign({}, bundle.Ok, { format_version: 999 });
+  const unsupported_report = await importCourse(unsupported_bundle, t0 + 234)(bob);
+  t.equal(unsupported_report.Ok.course_anchor_address, null);
+  t.equal(unsupported_report.Ok.errors[0].item, "format_version");
+
+  const report = await importCourse(bundle.Ok, t0 + 235)(bob);
+  t.deepEqual(report.Ok.errors, []);
+  t.ok(report.Ok.course_anchor_address);
+  await s.consistency();
+
+  const imported_course = await getLatestCouseEntry(report.Ok.course_anchor_address)(bob);
+  t.equal(imported_course.Ok.title, "course for scenario 24");
+  t.equal(imported_course.Ok.teacher_address, bob.instance("course_dna").agentAddress);
+  const imported_contents = await getContents(imported_course.Ok.sections[0])(bob);
+  t.equal(imported_contents.Ok.length, 1);
+
+  await s.consistency();
+});
+
+/********** GET_COURSE_TREE ********/
+orchestrator.registerScenario("Scenario25: get the whole course tree in a single call", async (s, t) => {
   const { alice, bob } = await s.players(
     { alice: conductorConfig, bob: conductorConfig },
     true
   );
+  const t0 = currentTime();
+  const course_addr = await createCourse("course for scenario 25", t0 + 123)(alice);
+  t.ok(course_addr.Ok);
+  await s.consistency();
+  const section_addr_1 = await createSection("section 1", course_addr.Ok, t0 + 124)(alice);
+  await s.consistency();
+  const section_addr_2 = await createSection("section 2", course_addr.Ok, t0 + 125)(alice);
+  await s.consistency();
+  const content_addr = await createContent("content 1", section_addr_1.Ok, "https://example.com/1", t0 + 126, "first")(alice);
+  await s.consistency();
 
-  const course_addr = await createCourse("course for scenario 6", 123)(alice);
-  console.log(course_addr);
+  const tree = await getCourseTree(course_addr.Ok)(bob);
+  t.equal(tree.Ok.course.title, "course for scenario 25");
+  t.deepEqual(tree.Ok.sections.map((section) => section.section_anchor_address), [section_addr_1.Ok, section_addr_2.Ok]);
+  t.equal(tree.Ok.sections[0].contents[0].content_anchor_address, content_addr.Ok);
+  t.equal(tree.Ok.sections[0].contents[0].content.name, "content 1");
+  t.deepEqual(tree.Ok.sections[1].contents, []);
+
+  await deleteCourse(course_addr.Ok)(alice);
+  await s.consistency();
+  const deleted_tree = await getCourseTree(course_addr.Ok)(bob);
+  t.equal(deleted_tree.Ok, null);
+
+  await s.consistency();
+});
+
+/********** APPLY_COURSE_BATCH ********/
+orchestrator.registerScenario("Scenario26: build a course with a single batch call", async (s, t) => {
+  const { alice, bob } = await s.players(
+    { alice: conductorConfig, bob: conductorConfig },
+    true
+  );
+  const t0 = currentTime();
+  const course_addr = await createCourse("course for scenario 26", t0 + 123)(alice);
+  t.ok(course_addr.Ok);
+  await s.consistency();
+  const old_section_addr = await createSection("old section", course_addr.Ok, t0 + 124)(alice);
+  await s.consistency();
+
+  const addresses = await applyCourseBatch(course_addr.Ok, [
+    { type: "CreateSection", title: "section 1" },
+    { type: "CreateSection", title: "section 2" },
+    { type: "CreateContent", section: { Created: 0 }, name: "content 1", url: "https://example.com/1", description: "" },
+    { type: "CreateContent", section: { Created: 1 }, name: "content 2", url: "https://example.com/2", description: "" },
+    { type: "DeleteSection", section_anchor_address: old_section_addr.Ok },
+  ], t0 + 234)(alice);
+  t.equal(addresses.Ok.length, 5);
+  t.equal(addresses.Ok[4], old_section_addr.Ok);
+  await s.consistency();
+
+  const tree = await getCourseTree(course_addr.Ok)(alice);
+  t.deepEqual(tree.Ok.sections.map((section) => section.section_anchor_address), [addresses.Ok[0], addresses.Ok[1]]);
+  t.equal(tree.Ok.sections[0].contents[0].content_anchor_address, addresses.Ok[2]);
+  t.equal(tree.Ok.sections[1].contents[0].content_anchor_address, addresses.Ok[3]);
+
+  // operation can't refer to a section that isn't created earlier in the batch
+  const invalid_batch = await applyCourseBatch(course_addr.Ok, [
+    { type: "CreateContent", section: { Created: 0 }, name: "content", url: "", description: "" },
+  ], t0 + 235)(alice);
+  t.error(invalid_batch.Ok);
+
+  // contents of one batch keep their order and can't repeat their names in one section
+  const ordered_batch = await applyCourseBatch(course_addr.Ok, [
+    { type: "CreateContent", section: { Existing: addresses.Ok[0] }, name: "content 3", url: "https://example.com/3", description: "" },
+    { type: "CreateContent", section: { Existing: addresses.Ok[0] }, name: "content 4", url: "https://example.com/4", description: "" },
+  ], t0 + 235)(alice);
+  await s.consistency();
+  const ordered_tree = await getCourseTree(course_addr.Ok)(alice);
+  t.deepEqual(
+    ordered_tree.Ok.sections[0].contents.map((content) => content.content_anchor_address),
+    [addresses.Ok[2], ordered_batch.Ok[0], ordered_batch.Ok[1]]
+  );
+  const duplicate_batch = await applyCourseBatch(course_addr.Ok, [
+    { type: "CreateContent", section: { Existing: addresses.Ok[1] }, name: "content 5", url: "https://example.com/5", description: "" },
+    { type: "CreateContent", section: { Existing: addresses.Ok[1] }, name: "content 5", url: "https://example.com/6", description: "" },
+  ], t0 + 236)(alice);
+  t.equal(JSON.parse(duplicate_batch.Err.Internal).code, "InvalidInput");
+
+  // only the teacher can change the course
+  const batch_by_student = await applyCourseBatch(course_addr.Ok, [
+    { type: "CreateSection", title: "section 3" },
+  ], t0 + 236)(bob);
+  t.error(batch_by_student.Ok);
+
+  await s.consistency();
+});
+
+/********** STRUCTURED ERRORS ********/
+orchestrator.registerScenario("Scenario27: zome errors have machine-readable codes", async (s, t) => {
+  const { alice } = await s.players(
+    { alice: conductorConfig },
+    true
+  );
+  const t0 = currentTime();
+  const course_addr = await createCourse("course for scenario 27", t0 + 123)(alice);
+  t.ok(course_addr.Ok);
+  await s.consistency();
+  await deleteCourse(course_addr.Ok)(alice);
+  await s.consistency();
+
+  const update_result = await updateCourse("new title", [], course_addr.Ok, t0 + 124)(alice);
+  const error = JSON.parse(update_result.Err.Internal);
+  t.equal(error.code, "Deleted");
+  t.equal(error.entity, "course");
+  t.equal(error.action, "update");
+  t.ok(error.message);
+
+  await s.consistency();
+});
+
+/********** TIMESTAMP VALIDATION ********/
+orchestrator.registerScenario("Scenario28: timestamps have to be close to the commit time and can't go back", async (s, t) => {
+  const { alice } = await s.players(
+    { alice: conductorConfig },
+    true
+  );
+  const t0 = currentTime();
+  // "created" in 1970
+  const old_course_addr = await createCourse("course from the past", 123)(alice);
+  t.error(old_course_addr.Ok);
+  // "created" in 2099
+  const future_course_addr = await createCourse("course from the future", 4070908800)(alice);
+  t.error(future_course_addr.Ok);
+
+  const course_addr = await createCourse("course for scenario 28", t0 + 123)(alice);
   t.ok(course_addr.Ok);
+  await s.consistency();
+  const older_update_addr = await updateCourse("older version", [], course_addr.Ok, t0 + 100)(alice);
+  t.error(older_update_addr.Ok);
+
+  // zome takes the timestamp from the conductor when UI doesn't provide it
+  const now_course_addr = await createCourseNow("course created now")(alice);
+  t.ok(now_course_addr.Ok);
+  await s.consistency();
+  const now_course = await getLatestCouseEntry(now_course_addr.Ok)(alice);
+  t.true(Math.abs(now_course.Ok.timestamp - currentTime()) < 600);
+
+  await s.consistency();
+});
 
+/********** CONTENT OF DELETED SECTIONS AND COURSES ********/
+orchestrator.registerScenario("Scenario29: content of deleted sections and courses can't be changed", async (s, t) => {
+  const { alice } = await s.players(
+    { alice: conductorConfig },
+    true
+  );
+  const t0 = currentTime();
+  const course_addr = await createCourse("course for scenario 29", t0 + 123)(alice);
+  await s.consistency();
+  const section_addr_1 = await createSection("section 1", course_addr.Ok, t0 + 124)(alice);
+  const section_addr_2 = await createSection("section 2", course_addr.Ok, t0 + 125)(alice);
+  await s.consistency();
+  const content_addr_1 = await createContent("content 1", section_addr_1.Ok, "https://example.com/1", t0 + 126, "first")(alice);
+  const content_addr_2 = await createContent("content 2", section_addr_2.Ok, "https://example.com/2", t0 + 127, "second")(alice);
   await s.consistency();
-  const section_addr = await createSection("section 1 for course 1", course_addr.Ok, 456)(alice);
 
-  console.log(section_addr);
-  t.ok(section_addr.Ok);
+  await deleteSection(section_addr_1.Ok, t0 + 128)(alice);
   await s.consistency();
+  const update_in_deleted_section = await updateContent(content_addr_1.Ok, "new name", "https://example.com/1", "first", t0 + 129)(alice);
+  t.equal(JSON.parse(update_in_deleted_section.Err.Internal).code, "Deleted");
+  const delete_in_deleted_section = await deleteContent(content_addr_1.Ok)(alice);
+  t.equal(JSON.parse(delete_in_deleted_section.Err.Internal).code, "Deleted");
 
-  const content_addr = await createContent(
-    "content 1 for module 1",
-    section_addr.Ok,
-     "https://youtube.com",
-     789,
-    "Holochain Intro"
-  )(alice);
+  await deleteCourse(course_addr.Ok)(alice);
+  await s.consistency();
+  const update_in_deleted_course = await updateContent(content_addr_2.Ok, "new name", "https://example.com/2", "second", t0 + 130)(alice);
+  t.equal(JSON.parse(update_in_deleted_course.Err.Internal).code, "Deleted");
 
-  console.log(content_addr);
-  t.ok(content_addr.Ok);
   await s.consistency();
 });
 
-// HOMEWORK
-/********* GET_CONTENTS *******/
-orchestrator.registerScenario("Scenario8: Get all contents of a module", async (s, t) => {
+/********** CONTENT ANCHORS ********/
+orchestrator.registerScenario("Scenario30: content keeps it's address across updates", async (s, t) => {
   const { alice, bob } = await s.players(
     { alice: conductorConfig, bob: conductorConfig },
     true
   );
-  const course_addr = await createCourse("course for scenario 7", 123)(alice);
-  console.log(course_addr);
-  t.ok(course_addr.Ok);
-
+  const t0 = currentTime();
+  const course_addr = await createCourse("course for scenario 30", t0 + 123)(alice);
   await s.consistency();
-  // Alice can create a section for course because she is the owner
-  const section_addr = await createSection("section 1 for course 1", course_addr.Ok, 456)(alice);
-
-  console.log(section_addr);
-  t.ok(section_addr.Ok);
+  const section_addr = await createSection("section 1", course_addr.Ok, t0 + 124)(alice);
   await s.consistency();
-
-  const content_addr_1 = await createContent(
-    "content 1 for module 1",
-    section_addr.Ok,
-    "https://youtube.com",
-    789,
-    "Holochain Intro"
-  )(alice);
-
-  console.log(content_addr_1);
-  t.ok(content_addr_1.Ok);
+  const content_addr = await createContent("content 1", section_addr.Ok, "https://example.com/1", t0 + 125, "first")(alice);
+  t.ok(content_addr.Ok);
   await s.consistency();
 
-  const content_addr_2 = await createContent(
-    "content 2 for module 1",
-    section_addr.Ok,
-    "https://youtube.com",
-    7892,
-    "Holochain Intro-2"
-  )(alice);
-
-  console.log(content_addr_2);
-  t.ok(content_addr_2.Ok);
+  await enrolInCourse(course_addr.Ok)(bob);
   await s.consistency();
+  await markContentDone(content_addr.Ok, t0 + 126)(bob);
 
+  const updated_content_addr = await updateContent(content_addr.Ok, "updated content 1", "https://example.com/1", "first", t0 + 127)(alice);
+  t.equal(updated_content_addr.Ok, content_addr.Ok);
+  await s.consistency();
 
-  const all_contents_of_section_1 = await getContents(section_addr.Ok)(alice);
+  const latest_content = await getLatestContentEntry(content_addr.Ok)(bob);
+  t.equal(latest_content.Ok.name, "updated content 1");
+  const contents = await getContents(section_addr.Ok)(bob);
+  t.deepEqual(contents.Ok, [content_addr.Ok]);
+  // progress that was recorded before the update still counts
+  const progress = await getMyProgress(course_addr.Ok)(bob);
+  t.equal(progress.Ok.completed, 1);
+
+  // only teacher can migrate contents and there's nothing to migrate in the course created with anchors
+  const bob_migration = await migrateContents(course_addr.Ok)(bob);
+  t.equal(JSON.parse(bob_migration.Err.Internal).code, "NotTeacher");
+  const alice_migration = await migrateContents(course_addr.Ok)(alice);
+  t.deepEqual(alice_migration.Ok, []);
+
+  await deleteContent(content_addr.Ok)(alice);
+  await s.consistency();
+  const deleted_content = await getLatestContentEntry(content_addr.Ok)(bob);
+  t.equal(deleted_content.Ok, null);
 
-  t.true(all_contents_of_section_1.Ok[0] != null);
-  t.true(all_contents_of_section_1.Ok[1] != null);
+  // contents with the same name created in the same second still get their own anchors
+  const same_name_addr_1 = await createContent("same name", section_addr.Ok, "https://example.com/a", t0 + 128, "a")(alice);
+  await s.consistency();
+  const same_name_addr_2 = await createContent("same name", section_addr.Ok, "https://example.com/b", t0 + 128, "b")(alice);
+  await s.consistency();
+  t.notEqual(same_name_addr_1.Ok, same_name_addr_2.Ok);
+  const same_name_2 = await getLatestContentEntry(same_name_addr_2.Ok)(bob);
+  t.equal(same_name_2.Ok.url, "https://example.com/b");
 
   await s.consistency();
 });
 
-// HOMEWORK
-/********* DELETE_CONTENT & GET_CONTENTS **********/
-orchestrator.registerScenario("Scenario9: delete content from module", async (s, t) => {
+/********** MOVE SECTION ********/
+orchestrator.registerScenario("Scenario31: teacher moves a section with it's content to another course", async (s, t) => {
   const { alice, bob } = await s.players(
     { alice: conductorConfig, bob: conductorConfig },
     true
   );
-  const course_addr = await createCourse("course for scenario 8", 123)(alice);
-  console.log(course_addr);
-  t.ok(course_addr.Ok);
-
+  const t0 = currentTime();
+  const course_addr_1 = await createCourse("big course", t0 + 123)(alice);
+  const course_addr_2 = await createCourse("split course", t0 + 124)(alice);
+  const bob_course_addr = await createCourse("bob's course", t0 + 125)(bob);
   await s.consistency();
-  // Alice can create a module for course because she is the owner
-  const section_addr = await createSection("section 1 for course 1", course_addr.Ok, 456)(alice);
-
-  console.log(section_addr);
-  t.ok(section_addr.Ok);
+  const section_addr = await createSection("section to move", course_addr_1.Ok, t0 + 126)(alice);
+  await s.consistency();
+  await createContent("content 1", section_addr.Ok, "https://example.com/1", t0 + 127, "first")(alice);
+  await createContent("content 2", section_addr.Ok, "https://example.com/2", t0 + 128, "second")(alice);
+  const release_at = t0 + 1000000;
+  await setSectionReleaseTime(section_addr.Ok, release_at, t0 + 128)(alice);
   await s.consistency();
 
-  const content_addr_1 = await createContent(
-    "content 1 for module 1",
-    section_addr.Ok,
-    "https://youtube.com",
-    789,
-    "Holochain Intro"
-  )(alice);
-
-  console.log(content_addr_1);
-  t.ok(content_addr_1.Ok);
+  // alice doesn't teach bob's course
+  const move_to_bob = await moveSectionToCourse(section_addr.Ok, bob_course_addr.Ok, t0 + 129)(alice);
+  t.equal(JSON.parse(move_to_bob.Err.Internal).code, "NotTeacher");
+  const move_to_same = await moveSectionToCourse(section_addr.Ok, course_addr_1.Ok, t0 + 129)(alice);
+  t.equal(JSON.parse(move_to_same.Err.Internal).code, "InvalidValue");
+
+  const old_contents = await getContents(section_addr.Ok)(alice);
+  const moved_section = await moveSectionToCourse(section_addr.Ok, course_addr_2.Ok, t0 + 130)(alice);
+  const moved_section_addr = moved_section.Ok.section_anchor_address;
+  t.ok(moved_section_addr);
+  // every content gets a new anchor and caller learns which one replaced which
+  t.deepEqual(
+    moved_section.Ok.contents.map((content) => content.previous_content_anchor_address).sort(),
+    old_contents.Ok.sort()
+  );
   await s.consistency();
 
-  const content_addr_2 = await createContent(
-    "content 2 for module 1",
-    section_addr.Ok,
-    "https://youtube.com",
-    7892,
-    "Holochain Intro-2"
-  )(alice);
+  const source_course = await getLatestCouseEntry(course_addr_1.Ok)(bob);
+  t.deepEqual(source_course.Ok.sections, []);
+  const old_section = await getLatestSectionEntry(section_addr.Ok)(bob);
+  t.equal(old_section.Ok, null);
+  // moved section is released at the same time as before
+  const new_section = await getLatestSectionEntry(moved_section_addr)(alice);
+  t.equal(new_section.Ok.release_at, release_at);
+
+  const target_tree = await getCourseTree(course_addr_2.Ok)(bob);
+  t.deepEqual(target_tree.Ok.sections.map((section) => section.section_anchor_address), [moved_section_addr]);
+  t.equal(target_tree.Ok.sections[0].section.title, "section to move");
+  t.deepEqual(target_tree.Ok.sections[0].contents.map((content) => content.content.name), ["content 1", "content 2"]);
+  t.deepEqual(
+    target_tree.Ok.sections[0].contents.map((content) => content.content_anchor_address).sort(),
+    moved_section.Ok.contents.map((content) => content.content_anchor_address).sort()
+  );
 
-  console.log(content_addr_2);
-  t.ok(content_addr_2.Ok);
   await s.consistency();
+});
 
-  const all_contents_of_section_1 = await getContents(section_addr.Ok)(alice);
-
-  t.true(all_contents_of_section_1.Ok[0] != null);
-  t.true(all_contents_of_section_1.Ok[1] != null);
+/********** SUBSECTIONS ********/
+orchestrator.registerScenario("Scenario32: sections can have nested subsections", async (s, t) => {
+  const { alice, bob } = await s.players(
+    { alice: conductorConfig, bob: conductorConfig },
+    true
+  );
+  const t0 = currentTime();
+  const course_addr = await createCourse("course for scenario 32", t0 + 123)(alice);
+  await s.consistency();
+  const chapter_addr = await createSection("chapter 1", course_addr.Ok, t0 + 124)(alice);
+  await s.consistency();
+  const lesson_addr_1 = await createSubsection("lesson 1", chapter_addr.Ok, t0 + 125)(alice);
+  await s.consistency();
+  const lesson_addr_2 = await createSubsection("lesson 2", chapter_addr.Ok, t0 + 126)(alice);
+  await s.consistency();
+  const topic_addr = await createSubsection("topic 1", lesson_addr_1.Ok, t0 + 127)(alice);
+  await s.consistency();
+  await createContent("content 1", topic_addr.Ok, "https://example.com/1", t0 + 128, "first")(alice);
   await s.consistency();
 
-  const delete_content = await deleteContent(content_addr_1.Ok)(alice)
-  
-  console.log("delete_content");
-  console.log(delete_content);
-  t.ok(delete_content.Ok);
+  // only the teacher can add subsections
+  const bob_subsection = await createSubsection("bob's lesson", chapter_addr.Ok, t0 + 129)(bob);
+  t.error(bob_subsection.Ok);
+  // sections can't be nested deeper than 3 levels
+  const too_deep = await createSubsection("subtopic", topic_addr.Ok, t0 + 129)(alice);
+  t.equal(JSON.parse(too_deep.Err.Internal).code, "InvalidValue");
+
+  // only top-level sections are listed in the course
+  const course = await getLatestCouseEntry(course_addr.Ok)(bob);
+  t.deepEqual(course.Ok.sections, [chapter_addr.Ok]);
 
+  const reordered = await reorderSubsections(chapter_addr.Ok, [lesson_addr_2.Ok, lesson_addr_1.Ok], t0 + 130)(alice);
+  t.ok(reordered.Ok);
+  const wrong_order = await reorderSubsections(chapter_addr.Ok, [lesson_addr_2.Ok], t0 + 131)(alice);
+  t.equal(JSON.parse(wrong_order.Err.Internal).code, "InvalidInput");
   await s.consistency();
 
-  const all_contents_of_section_1_again = await getContents(section_addr.Ok)(alice);
+  const tree = await getCourseTree(course_addr.Ok)(bob);
+  const chapter = tree.Ok.sections[0];
+  t.deepEqual(chapter.subsections.map((section) => section.section.title), ["lesson 2", "lesson 1"]);
+  t.equal(chapter.subsections[1].subsections[0].contents[0].content.name, "content 1");
+  const lesson_tree = await getSectionTree(lesson_addr_1.Ok)(bob);
+  t.equal(lesson_tree.Ok.subsections[0].section_anchor_address, topic_addr.Ok);
 
-  t.true(all_contents_of_section_1_again.Ok[0] != null);
-  t.true(all_contents_of_section_1_again.Ok[1] == null);
+  // deleted subsection is removed from it's parent
+  await deleteSection(lesson_addr_2.Ok, t0 + 132)(alice);
+  await s.consistency();
+  const chapter_entry = await getLatestSectionEntry(chapter_addr.Ok)(bob);
+  t.deepEqual(chapter_entry.Ok.subsections, [lesson_addr_1.Ok]);
 
   await s.consistency();
 });
 
-// HOMEWORK
-/***** DELETE_SECTION ***********/
-orchestrator.registerScenario(
-  "Scenario10: delete module from course",
-  async (s, t) => {
-    const { alice, bob } = await s.players(
-      { alice: conductorConfig, bob: conductorConfig },
-      true
-    );
-
-    const course_addr = await createCourse("course for scenario 9: debugging purpose", 123)(alice);
-    console.log("course_addr");
-    console.log(course_addr);
-    t.ok(course_addr.Ok);
+/********** SECTION RELEASE ********/
+orchestrator.registerScenario("Scenario33: sections can be released on schedule or one after another", async (s, t) => {
+  const { alice, bob } = await s.players(
+    { alice: conductorConfig, bob: conductorConfig },
+    true
+  );
+  const t0 = currentTime();
+  const course_addr = await createCourse("course for scenario 33", t0 + 123)(alice);
+  await s.consistency();
+  const section_addr_1 = await createSection("section 1", course_addr.Ok, t0 + 124)(alice);
+  await s.consistency();
+  const section_addr_2 = await createSection("section 2", course_addr.Ok, t0 + 125)(alice);
+  await s.consistency();
+  const content_addr = await createContent("content 1", section_addr_1.Ok, "https://example.com/1", t0 + 126, "first")(alice);
+  await s.consistency();
+  await createContent("content 2", section_addr_2.Ok, "https://example.com/2", t0 + 127, "second")(alice);
+  await s.consistency();
+  await enrolInCourse(course_addr.Ok)(bob);
+  await s.consistency();
 
-    await s.consistency();
-    // Alice can create a module for course because she is the owner
-    const section_addr = await createSection("section 1 for course 1", course_addr.Ok, 456)(alice);
+  // section released in the future is locked for students
+  const release_at = t0 + 1000000;
+  const released = await setSectionReleaseTime(section_addr_1.Ok, release_at, t0 + 128)(alice);
+  t.ok(released.Ok);
+  const bob_release = await setSectionReleaseTime(section_addr_1.Ok, null, t0 + 129)(bob);
+  t.error(bob_release.Ok);
+  await s.consistency();
 
-    console.log(section_addr);
-    t.ok(section_addr.Ok);
-    await s.consistency();
+  const locked_contents = await getContents(section_addr_1.Ok)(bob);
+  const locked_error = JSON.parse(locked_contents.Err.Internal);
+  t.equal(locked_error.code, "Locked");
+  t.equal(locked_error.unlocks_at, release_at);
+  const locked_section = await getLatestSectionEntry(section_addr_1.Ok)(bob);
+  t.deepEqual(locked_section.Ok.lock, { reason: "UntilTime", unlocks_at: release_at });
+  const locked_tree = await getCourseTree(course_addr.Ok)(bob);
+  t.deepEqual(locked_tree.Ok.sections[0].contents, []);
+
+  // teacher can always see all sections
+  const teacher_contents = await getContents(section_addr_1.Ok)(alice);
+  t.deepEqual(teacher_contents.Ok, [content_addr.Ok]);
+  const teacher_section = await getLatestSectionEntry(section_addr_1.Ok)(alice);
+  t.equal(teacher_section.Ok.lock, null);
+
+  // with drip rule, next section is locked until the previous one is completed
+  await setSectionReleaseTime(section_addr_1.Ok, null, t0 + 130)(alice);
+  const invalid_rule = await setCourseDripRule(course_addr.Ok, { type: "DaysAfterEnrolment", days: 0 }, t0 + 131)(alice);
+  t.error(invalid_rule.Ok);
+  const drip_rule = await setCourseDripRule(course_addr.Ok, { type: "AfterPreviousSection" }, t0 + 131)(alice);
+  t.ok(drip_rule.Ok);
+  await s.consistency();
 
-    const courseResult = await getMyCourses()(alice);
-    console.log("get_my_courses");
-    console.log(courseResult.Ok);
-    t.true(courseResult.Ok[0] != null);
-    await s.consistency();
+  const first_contents = await getContents(section_addr_1.Ok)(bob);
+  t.deepEqual(first_contents.Ok, [content_addr.Ok]);
+  const second_locked = await getContents(section_addr_2.Ok)(bob);
+  t.equal(JSON.parse(second_locked.Err.Internal).code, "Locked");
 
-    console.log("get_latest_course_entry");
-    const course_again = await getLatestCouseEntry(course_addr.Ok)(alice)
-    console.log(course_again.Ok);
-    await s.consistency();
+  await markContentDone(content_addr.Ok, t0 + 132)(bob);
+  await s.consistency();
+  const second_contents = await getContents(section_addr_2.Ok)(bob);
+  t.equal(second_contents.Ok.length, 1);
 
-    const content_addr_1 = await createContent(
-      "content 1 for module 1",
-      section_addr.Ok,
-      "https://youtube.com",
-      7891,
-      "Holochain Intro-Video"
-      )(alice);
-    console.log(content_addr_1);
-    t.ok(content_addr_1.Ok);
-    await s.consistency();
+  await s.consistency();
+});
 
-    const content_addr_2 = await createContent(
-      "content 2 for module 1",
-      section_addr.Ok,
-      "https://soundclould.com",
-      7892,
-      "Holochain Intro-Sound"
-      )(alice);
+/********** NOTIFY_COURSE_MEMBERS ********/
+orchestrator.registerScenario("Scenario34: teacher shares a change with course members", async (s, t) => {
+  const { alice, bob, carol } = await s.players(
+    { alice: conductorConfig, bob: conductorConfig, carol: conductorConfig },
+    true
+  );
+  const t0 = currentTime();
+  const course_addr = await createCourse("course for scenario 34", t0 + 123)(alice);
+  await s.consistency();
+  await enrolInCourse(course_addr.Ok)(bob);
+  await s.consistency();
+  await updateCourse("new title", [], course_addr.Ok, t0 + 124)(alice);
+  await s.consistency();
 
-    console.log(content_addr_2);
-    t.ok(content_addr_2.Ok);
-    await s.consistency();
+  const notice = {
+    entity: "Course",
+    change: {
+      kind: "Updated",
+      course_anchor_address: course_addr.Ok,
+      author_address: alice.instance("course_dna").agentAddress,
+    },
+  };
+  // only the agent who made the change can share it
+  const carol_notified = await notifyCourseMembers(notice)(carol);
+  t.equal(JSON.parse(carol_notified.Err.Internal).code, "Forbidden");
+  // teacher isn't sending the notice to themselves
+  const notified = await notifyCourseMembers(notice)(alice);
+  t.deepEqual(notified.Ok, [bob.instance("course_dna").agentAddress]);
 
-    const all_contents_of_module_1 = await getContents(section_addr.Ok)(alice);
+  await s.consistency();
+});
 
-    t.true(all_contents_of_module_1.Ok[0] != null);
-    t.true(all_contents_of_module_1.Ok[1] != null);
-    await s.consistency();
+/********** GRANT_ROLE & REVOKE_ROLE WITH ADMINS ********/
+orchestrator.registerScenario("Scenario35: admins grant and revoke the teacher role", async (s, t) => {
+  const { alice, bob, carol } = await s.players(
+    { alice: conductorConfig, bob: conductorConfig, carol: conductorConfig },
+    true
+  );
+  const t0 = currentTime();
+  const alice_address = alice.instance("course_dna").agentAddress;
+  const bob_address = bob.instance("course_dna").agentAddress;
+  const admin = await withAdmins(alice, [alice_address]);
+  const teacher = await withAdmins(bob, [alice_address]);
+  const student = await withAdmins(carol, [alice_address]);
+  await s.consistency();
 
-    const delete_content = await deleteContent(content_addr_1.Ok)(alice)
-    console.log("delete_content");
-    console.log(delete_content);
-    t.ok(delete_content.Ok);
+  // only admins can grant roles
+  const carol_grant = await grantRole(bob_address, "teacher", t0 + 123)(student);
+  t.error(carol_grant.Ok);
+  // agents without the teacher role can't create courses
+  const course_fail = await createCourse("course before grant", t0 + 123)(teacher);
+  t.error(course_fail.Ok);
 
-    await s.consistency();
+  const grant_result = await grantRole(bob_address, "teacher", t0 + 124)(admin);
+  t.ok(grant_result.Ok);
+  await s.consistency();
 
-    const all_contents_of_section_1_again = await getContents(section_addr.Ok)(alice);
+  const bob_roles = await getMyRoles()(teacher);
+  t.deepEqual(bob_roles.Ok, ["teacher"]);
+  const teachers = await listTeachers()(student);
+  t.deepEqual(teachers.Ok, [bob_address]);
+  const course_addr = await createCourse("course for scenario 35", t0 + 125)(teacher);
+  t.ok(course_addr.Ok);
+  await s.consistency();
 
-    t.true(all_contents_of_section_1_again.Ok[0] != null);
-    t.true(all_contents_of_section_1_again.Ok[1] == null);
+  // after the role is revoked, agent can't create courses anymore
+  const revoke_result = await revokeRole(bob_address, "teacher")(admin);
+  t.deepEqual(revoke_result.Ok, [grant_result.Ok]);
+  await s.consistency();
 
-    await s.consistency();
+  const teachers_after_revoke = await listTeachers()(student);
+  t.deepEqual(teachers_after_revoke.Ok, []);
+  const course_after_revoke = await createCourse("course after revoke", t0 + 126)(teacher);
+  t.error(course_after_revoke.Ok);
 
-    const delete_section = await deleteSection(section_addr.Ok, 2345)(alice)
-    console.log(delete_section);
-    t.ok(delete_section.Ok);
-    await s.consistency();
-  }
-);
+  await s.consistency();
+});
 
-// HOMEWORK
-/********** UPDATE_SECTION & UPDATE_CONTENT ********/
-orchestrator.registerScenario("Scenario11: update section and content", async (s, t) => {
-  const { alice, bob } = await s.players(
-    { alice: conductorConfig, bob: conductorConfig },
+/********** DAYS AFTER ENROLMENT ********/
+orchestrator.registerScenario("Scenario36: sections unlock days after the student enrolled", async (s, t) => {
+  const { alice, bob, carol } = await s.players(
+    { alice: conductorConfig, bob: conductorConfig, carol: conductorConfig },
     true
   );
-  const course_addr = await createCourse("course test 1", 123)(alice);
-  console.log(course_addr);
-  t.ok(course_addr.Ok);
-
-  await s.consistency();
-  
-  const section_addr = await createSection("section 1 for course 1", course_addr.Ok, 234)(alice);
-  t.ok(section_addr.Ok);
-  
+  const t0 = currentTime();
+  const secs_per_day = 24 * 60 * 60;
+  const course_addr = await createCourse("course for scenario 36", t0 + 123)(alice);
   await s.consistency();
-
-  const content_addr = await createContent(
-    "content 1 for section 1",
-    section_addr.Ok,
-    "https://youtube.com",
-    7891,
-    "Holochain Intro-Video"
-    )(alice);
-  console.log(content_addr);
-  t.ok(content_addr.Ok);
-
+  const section_addr_1 = await createSection("section 1", course_addr.Ok, t0 + 124)(alice);
   await s.consistency();
-  
-  const updated_section_addr = await updateSection("updated section title", section_addr.Ok, 12345)(alice);
-  console.log("update_section")
-  console.log(updated_section_addr.Ok)
-  t.ok(updated_section_addr.Ok);
-  
+  const section_addr_2 = await createSection("section 2", course_addr.Ok, t0 + 125)(alice);
   await s.consistency();
-  
-  // this will fail since bob is not the teacher
-  const updated_section_addr_fail = await updateSection("updated section title", section_addr.Ok, 123456)(bob);
-  console.log("update_section failed");
-  console.log(updated_section_addr_fail);
-  t.error(updated_section_addr_fail.Ok);
-  
+  const content_addr = await createContent("content 1", section_addr_1.Ok, "https://example.com/1", t0 + 126, "first")(alice);
   await s.consistency();
-  
-  const update_content_addr = await updateContent(
-    content_addr.Ok,
-    "updated content for section 1",
-    "https://youtube.com",
-    "Holochain Intro-Video",
-    123456
-    )(alice);
-    console.log("updated_content");
-    console.log(update_content_addr.Ok);
-    t.ok(update_content_addr.Ok)
-    
+  const drip_rule = await setCourseDripRule(course_addr.Ok, { type: "DaysAfterEnrolment", days: 2 }, t0 + 127)(alice);
+  t.ok(drip_rule.Ok);
+  const release_at = t0 + 1000000;
+  await setSectionReleaseTime(section_addr_2.Ok, release_at, t0 + 128)(alice);
   await s.consistency();
 
-  // this will also fail since bob is not the teacher of this course
-  const update_content_addr_fail = await updateContent(
-    update_content_addr.Ok,
-    "updated content for section 1",
-    "https://youtube.com",
-    "Holochain Intro-Video",
-    123457
-  )(bob);
-  console.log("update_content failed");
-  console.log(update_content_addr_fail);
-  t.error(update_content_addr_fail.Ok);
+  // agents that aren't enrolled have to enrol first
+  const carol_section = await getLatestSectionEntry(section_addr_1.Ok)(carol);
+  t.deepEqual(carol_section.Ok.lock, { reason: "UntilEnrolled" });
 
-  // Wait for all network activity to settle
+  await enrolInCourse(course_addr.Ok)(bob);
   await s.consistency();
-});
+  const enrolled_at = currentTime();
+
+  // first section unlocks 2 days after the time of enrolment that bob's conductor has recorded and the next one 2 days later
+  const locked_section_1 = await getLatestSectionEntry(section_addr_1.Ok)(bob);
+  t.equal(locked_section_1.Ok.lock.reason, "UntilTime");
+  const unlocks_at_1 = locked_section_1.Ok.lock.unlocks_at;
+  t.ok(unlocks_at_1 >= t0 + 2 * secs_per_day && unlocks_at_1 <= enrolled_at + 2 * secs_per_day);
+  const locked_section_2 = await getLatestSectionEntry(section_addr_2.Ok)(bob);
+  t.deepEqual(locked_section_2.Ok.lock, { reason: "UntilTime", unlocks_at: unlocks_at_1 + 2 * secs_per_day });
+  const locked_contents = await getContents(section_addr_1.Ok)(bob);
+  const locked_error = JSON.parse(locked_contents.Err.Internal);
+  t.equal(locked_error.code, "Locked");
+  t.equal(locked_error.unlocks_at, unlocks_at_1);
+  const locked_tree = await getCourseTree(course_addr.Ok)(bob);
+  t.deepEqual(locked_tree.Ok.sections[0].contents, []);
+
+  // teacher isn't enrolled but can always see all sections
+  const teacher_contents = await getContents(section_addr_1.Ok)(alice);
+  t.deepEqual(teacher_contents.Ok, [content_addr.Ok]);
+
+  // fork keeps the drip rule of the course and release times of it's sections
+  const fork_addr = await forkCourse(course_addr.Ok, "alice's copy", t0 + 129)(alice);
+  await s.consistency();
+  const fork = await getLatestCouseEntry(fork_addr.Ok)(alice);
+  t.deepEqual(fork.Ok.drip_rule, { type: "DaysAfterEnrolment", days: 2 });
+  const fork_tree = await getCourseTree(fork_addr.Ok)(alice);
+  t.deepEqual(fork_tree.Ok.sections.map((section) => section.section.release_at), [null, release_at]);
 
-orchestrator.run();
\ No newline at end of file
+  await s.consistency();
+});
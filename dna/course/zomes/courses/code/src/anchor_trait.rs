@@ -13,4 +13,14 @@ pub trait AnchorTrait: TryFrom<JsonString> + Into<JsonString> + Clone {
     fn address(&self) -> ZomeApiResult<Address> {
         hdk::entry_address(&self.clone().entry())
     }
+
+    // converts an Entry to the anchor or returns None if the Entry isn't an anchor of this type
+    fn from_entry(entry: &Entry) -> Option<Self> {
+        match entry {
+            Entry::App(entry_type, entry_json) if entry_type.to_string() == Self::entry_type() => {
+                Self::try_from(entry_json.clone()).ok()
+            }
+            _ => None,
+        }
+    }
 }
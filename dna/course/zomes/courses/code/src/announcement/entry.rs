@@ -0,0 +1,80 @@
+use hdk::prelude::*;
+use holochain_entry_utils::HolochainEntry;
+
+use super::validation;
+use crate::anchor_trait::AnchorTrait;
+use crate::course::anchor::CourseAnchor;
+
+pub const MAX_TITLE_LEN: usize = 200;
+pub const COURSE_ANCHOR_TO_ANNOUNCEMENT_LINK: &str = "course_anchor->announcement";
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct Announcement {
+    pub title: String,
+    pub text: String,
+    pub course_anchor_address: Address,
+    pub teacher_address: Address,
+    pub timestamp: u64,
+}
+
+impl Announcement {
+    pub fn new(
+        title: String,
+        text: String,
+        course_anchor_address: Address,
+        teacher_address: Address,
+        timestamp: u64,
+    ) -> Self {
+        Announcement {
+            title,
+            text,
+            course_anchor_address,
+            teacher_address,
+            timestamp,
+        }
+    }
+}
+
+impl HolochainEntry for Announcement {
+    fn entry_type() -> String {
+        String::from("announcement")
+    }
+}
+
+pub fn announcement_entry_def() -> ValidatingEntryType {
+    entry!(
+        name: Announcement::entry_type(),
+        description: "this is an announcement that the teacher made for all students of the course",
+        sharing: Sharing::Public,
+        validation_package: || {
+            hdk::ValidationPackageDefinition::Entry
+        },
+        validation: | validation_data: hdk::EntryValidationData<Announcement>| {
+            match validation_data {
+                EntryValidationData::Create { entry, validation_data } => {
+                    validation::create(entry, validation_data)
+                },
+                EntryValidationData::Modify { new_entry, old_entry, old_entry_header, validation_data } => {
+                    validation::modify(new_entry, old_entry, old_entry_header, validation_data)
+                },
+                EntryValidationData::Delete { old_entry, old_entry_header, validation_data } => {
+                    validation::delete(old_entry, old_entry_header, validation_data)
+                }
+            }
+        },
+        links: [
+            // link from the course this announcement was made for
+            // This is for students who were offline when the announcement was delivered
+            from!(
+                CourseAnchor::entry_type(),
+                link_type: COURSE_ANCHOR_TO_ANNOUNCEMENT_LINK,
+                validation_package: || {
+                    hdk::ValidationPackageDefinition::Entry
+                },
+                validation: | validation_data: hdk::LinkValidationData | {
+                    validation::course_anchor_to_announcement_link(validation_data)
+                }
+            )
+        ]
+    )
+}
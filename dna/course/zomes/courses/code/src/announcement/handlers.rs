@@ -0,0 +1,107 @@
+use hdk::prelude::*;
+use hdk::AGENT_ADDRESS;
+use holochain_entry_utils::HolochainEntry;
+
+use super::entry::{Announcement, COURSE_ANCHOR_TO_ANNOUNCEMENT_LINK};
+use super::inbox::ReceivedAnnouncement;
+use crate::course;
+use crate::direct_message::{self, DirectMessage};
+use crate::error::ZomeError;
+use crate::helper;
+
+// name of the signal that is emitted to the UI when a new announcement lands in the inbox
+pub const ANNOUNCEMENT_RECEIVED_SIGNAL: &str = "announcement_received";
+
+pub fn create(
+    course_anchor_address: Address,
+    title: String,
+    text: String,
+    timestamp: u64,
+) -> ZomeApiResult<Address> {
+    let latest_course_result = course::handlers::get_latest_course(&course_anchor_address)?;
+    match latest_course_result {
+        Some((course, _course_address)) => {
+            let announcement = Announcement::new(
+                title,
+                text,
+                course_anchor_address.clone(),
+                course.teacher_address,
+                timestamp,
+            );
+            let announcement_address = hdk::commit_entry(&announcement.entry())?;
+            hdk::link_entries(
+                &course_anchor_address,
+                &announcement_address,
+                COURSE_ANCHOR_TO_ANNOUNCEMENT_LINK,
+                "",
+            )?;
+
+            Ok(announcement_address)
+        }
+        None => Err(ZomeError::deleted("course", "make an announcement in").into()),
+    }
+}
+
+// delivers announcement to the inbox of every student of the course who is online right now and returns
+// addresses of students who received it. Students who are offline can always find it with get_announcements.
+// NOTE: this isn't done in create because waiting for every student to respond would make posting an announcement slow
+pub fn deliver(announcement_address: Address) -> ZomeApiResult<Vec<Address>> {
+    let announcement: Announcement = hdk::utils::get_as_type(announcement_address.clone())?;
+    if &announcement.teacher_address != *AGENT_ADDRESS {
+        return Err(ZomeError::forbidden(
+            "Only the teacher of the course can deliver it's announcements",
+        )
+        .into());
+    }
+    let students = course::handlers::get_students(announcement.course_anchor_address.clone())?;
+    Ok(direct_message::broadcast(
+        students,
+        DirectMessage::Announcement {
+            announcement_address,
+            announcement,
+        },
+    ))
+}
+
+pub fn get_announcements(course_anchor_address: &Address) -> ZomeApiResult<Vec<Address>> {
+    let links = hdk::get_links(
+        course_anchor_address,
+        LinkMatch::Exactly(COURSE_ANCHOR_TO_ANNOUNCEMENT_LINK),
+        LinkMatch::Any,
+    )?;
+
+    Ok(links.addresses())
+}
+
+// lists announcements that were delivered directly to this agent, in order they were received
+pub fn get_inbox() -> ZomeApiResult<Vec<ReceivedAnnouncement>> {
+    Ok(helper::get_my_entries::<ReceivedAnnouncement>()?
+        .into_iter()
+        .map(|(received_announcement, _address)| received_announcement)
+        .collect())
+}
+
+// called from the zome's receive callback when teacher delivers a new announcement
+pub fn receive(
+    from_address: Address,
+    announcement_address: Address,
+    announcement: Announcement,
+) -> ZomeApiResult<()> {
+    if from_address != announcement.teacher_address {
+        return Err(ZomeError::forbidden(
+            "Only the teacher of the course can deliver it's announcements",
+        )
+        .into());
+    }
+    if !course::handlers::get_my_enrolled_courses()?.contains(&announcement.course_anchor_address) {
+        return Err(ZomeError::forbidden(
+            "Can't receive announcements for a course I'm not enrolled in",
+        )
+        .into());
+    }
+
+    let received_announcement = ReceivedAnnouncement::new(announcement_address, announcement);
+    hdk::commit_entry(&received_announcement.clone().entry())?;
+    hdk::emit_signal(ANNOUNCEMENT_RECEIVED_SIGNAL, received_announcement)?;
+    Ok(())
+}
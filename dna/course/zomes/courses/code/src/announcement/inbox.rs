@@ -0,0 +1,57 @@
+use hdk::prelude::*;
+use holochain_entry_utils::HolochainEntry;
+
+use super::entry::Announcement;
+use super::validation;
+
+// Announcement that was delivered to the student directly by the teacher.
+// NOTE: this entry is private so it's only stored on the student's own source chain and serves as their inbox
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct ReceivedAnnouncement {
+    pub announcement_address: Address,
+    pub announcement: Announcement,
+}
+
+impl ReceivedAnnouncement {
+    pub fn new(announcement_address: Address, announcement: Announcement) -> Self {
+        ReceivedAnnouncement {
+            announcement_address,
+            announcement,
+        }
+    }
+}
+
+impl HolochainEntry for ReceivedAnnouncement {
+    fn entry_type() -> String {
+        String::from("received_announcement")
+    }
+}
+
+pub fn received_announcement_entry_def() -> ValidatingEntryType {
+    entry!(
+        name: ReceivedAnnouncement::entry_type(),
+        description: "this is a private copy of the announcement in the student's inbox",
+        sharing: Sharing::Private,
+        validation_package: || {
+            hdk::ValidationPackageDefinition::Entry
+        },
+        validation: | validation_data: hdk::EntryValidationData<ReceivedAnnouncement>| {
+            match validation_data {
+                EntryValidationData::Create { entry, .. } => {
+                    validation::received_create(entry)
+                },
+                // NOTE: the symbol .. means that we're skipping unpacking parameters that we receive here
+                // because we won't need them
+                EntryValidationData::Modify { .. } => {
+                    validation::received_modify()
+                },
+                // student can clean up their inbox however they want
+                EntryValidationData::Delete { .. } => {
+                    Ok(())
+                }
+            }
+        },
+        // ReceivedAnnouncement is private so nothing on the DHT can link to it
+        links: []
+    )
+}
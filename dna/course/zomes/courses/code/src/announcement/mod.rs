@@ -0,0 +1,4 @@
+pub mod entry;
+pub mod handlers;
+pub mod inbox;
+mod validation;
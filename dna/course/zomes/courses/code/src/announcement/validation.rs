@@ -0,0 +1,111 @@
+use hdk::holochain_core_types::chain_header::ChainHeader;
+use hdk::{LinkValidationData, ValidationData};
+use holochain_entry_utils::HolochainEntry;
+
+use super::{
+    entry::{Announcement, MAX_TITLE_LEN},
+    inbox::ReceivedAnnouncement,
+};
+use crate::course::anchor::CourseAnchor;
+use crate::error::ZomeError;
+use crate::helper;
+
+pub fn create(entry: Announcement, validation_data: ValidationData) -> Result<(), String> {
+    helper::validate_only_teacher_can_do(
+        &entry.teacher_address,
+        validation_data.sources(),
+        "make announcements in their courses",
+    )?;
+    helper::validate_entity_title(&entry.title, &Announcement::entry_type(), MAX_TITLE_LEN)?;
+    let course_anchor: CourseAnchor = hdk::utils::get_as_type(entry.course_anchor_address.clone())?;
+    if course_anchor.teacher_address != entry.teacher_address {
+        return Err(ZomeError::forbidden(
+            "Only the teacher of the course can make announcements in it",
+        )
+        .into());
+    }
+    Ok(())
+}
+
+pub fn modify(
+    new_entry: Announcement,
+    old_entry: Announcement,
+    _old_entry_header: ChainHeader,
+    validation_data: ValidationData,
+) -> Result<(), String> {
+    helper::validate_only_teacher_can_do(
+        &old_entry.teacher_address,
+        validation_data.sources(),
+        "modify announcements in their courses",
+    )?;
+    if new_entry.course_anchor_address != old_entry.course_anchor_address {
+        return Err(ZomeError::immutable_field("announcement", "course").into());
+    }
+    helper::validate_entity_title(&new_entry.title, &Announcement::entry_type(), MAX_TITLE_LEN)?;
+    helper::validate_no_teacher_change(
+        old_entry.teacher_address,
+        new_entry.teacher_address,
+        &Announcement::entry_type(),
+    )
+}
+
+pub fn delete(
+    entry: Announcement,
+    _entry_header: ChainHeader,
+    validation_data: ValidationData,
+) -> Result<(), String> {
+    helper::validate_only_teacher_can_do(
+        &entry.teacher_address,
+        validation_data.sources(),
+        "delete announcements in their courses",
+    )
+}
+
+// =========================== ReceivedAnnouncement validation
+pub fn received_create(entry: ReceivedAnnouncement) -> Result<(), String> {
+    // make sure that the copy in the inbox is exactly the announcement that was made by the teacher
+    let announcement_address = hdk::entry_address(&entry.announcement.entry())?;
+    if announcement_address != entry.announcement_address {
+        return Err(
+            ZomeError::invalid_input("Received announcement doesn't match it's address").into(),
+        );
+    }
+    Ok(())
+}
+
+// NOTE: we don't accept any parameters here because we don't need them to always return an error
+// because received announcement is a copy that shouldn't be changed
+pub fn received_modify() -> Result<(), String> {
+    Err(ZomeError::immutable_entry("received_announcement").into())
+}
+
+//  =========================== Announcement links validation
+pub fn course_anchor_to_announcement_link(
+    validation_data: LinkValidationData,
+) -> Result<(), String> {
+    let (link, validation_data) = match validation_data {
+        hdk::LinkValidationData::LinkAdd {
+            link,
+            validation_data,
+        } => (link, validation_data),
+        hdk::LinkValidationData::LinkRemove {
+            link,
+            validation_data,
+        } => (link, validation_data),
+    };
+    // get author of this entry
+    let author = validation_data.package.chain_header.provenances()[0].source();
+    // get link target: entry to which the link goes
+    let target: Announcement = hdk::utils::get_as_type(link.link.target().clone())?;
+    if author != target.teacher_address {
+        return Err(ZomeError::invalid_link("course_anchor->announcement", "Can't link CourseAnchor to Announcement because your address isn't specified as teacher address for this course").into());
+    }
+    if link.link.base() != &target.course_anchor_address {
+        return Err(ZomeError::invalid_link(
+            "course_anchor->announcement",
+            "Can't link CourseAnchor to Announcement that was made for a different course",
+        )
+        .into());
+    }
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use hdk::prelude::*;
+use holochain_entry_utils::HolochainEntry;
+
+use super::validation;
+use crate::anchor_trait::AnchorTrait;
+use crate::section::anchor::SectionAnchor;
+
+pub const MAX_TITLE_LEN: usize = 200;
+pub const SECTION_ANCHOR_TO_ASSIGNMENT_LINK: &str = "section_anchor->assignment";
+
+// NOTE: using self::DefaultJson to disambiguate usage of DefaultJson from this module (hdk::prelude imports it)
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct Assignment {
+    pub title: String,
+    pub prompt: String,
+    // time after which students can't submit their work anymore
+    pub due_date: u64,
+    pub max_score: u32,
+    pub timestamp: u64,
+    pub section_anchor_address: Address,
+    // NOTE: course_address and teacher_address are copied from the section so that validation of Submission
+    // and Grade entries doesn't have to walk SectionAnchor -> Course to find out who's enrolled and who's teaching
+    pub course_address: Address,
+    pub teacher_address: Address,
+}
+
+impl Assignment {
+    pub fn new(
+        title: String,
+        prompt: String,
+        due_date: u64,
+        max_score: u32,
+        timestamp: u64,
+        section_anchor_address: Address,
+        course_address: Address,
+        teacher_address: Address,
+    ) -> Self {
+        Assignment {
+            title,
+            prompt,
+            due_date,
+            max_score,
+            timestamp,
+            section_anchor_address,
+            course_address,
+            teacher_address,
+        }
+    }
+}
+
+impl HolochainEntry for Assignment {
+    fn entry_type() -> String {
+        String::from("assignment")
+    }
+}
+
+pub fn assignment_entry_def() -> ValidatingEntryType {
+    entry!(
+        name: Assignment::entry_type(),
+        description: "this is the definition of an assignment that students submit their work for",
+        sharing: Sharing::Public,
+        validation_package: || {
+            hdk::ValidationPackageDefinition::Entry
+        },
+        validation: | validation_data: hdk::EntryValidationData<Assignment>| {
+            match validation_data {
+                EntryValidationData::Create { entry, validation_data } => {
+                    validation::create(entry, validation_data)
+                },
+                EntryValidationData::Modify { new_entry, old_entry, old_entry_header, validation_data } => {
+                    validation::modify(new_entry, old_entry, old_entry_header, validation_data)
+                },
+                EntryValidationData::Delete { old_entry, old_entry_header, validation_data } => {
+                    validation::delete(old_entry, old_entry_header, validation_data)
+                }
+            }
+        },
+        links: [
+            // link from the section this assignment belongs to
+            // This is for the section to list all of it's assignments
+            from!(
+                SectionAnchor::entry_type(),
+                link_type: SECTION_ANCHOR_TO_ASSIGNMENT_LINK,
+                validation_package: || {
+                    hdk::ValidationPackageDefinition::Entry
+                },
+                validation: | validation_data: hdk::LinkValidationData | {
+                    validation::section_anchor_to_assignment_link(validation_data)
+                }
+            )
+        ]
+    )
+}
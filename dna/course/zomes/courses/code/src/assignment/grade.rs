@@ -0,0 +1,78 @@
+use hdk::prelude::*;
+use holochain_entry_utils::HolochainEntry;
+
+use super::submission::Submission;
+use super::validation;
+
+pub const SUBMISSION_TO_GRADE_LINK: &str = "submission->grade";
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct Grade {
+    pub submission_address: Address,
+    pub score: u32,
+    pub feedback: String,
+    pub teacher_address: Address,
+    pub timestamp: u64,
+}
+
+impl Grade {
+    pub fn new(
+        submission_address: Address,
+        score: u32,
+        feedback: String,
+        teacher_address: Address,
+        timestamp: u64,
+    ) -> Self {
+        Grade {
+            submission_address,
+            score,
+            feedback,
+            teacher_address,
+            timestamp,
+        }
+    }
+}
+
+impl HolochainEntry for Grade {
+    fn entry_type() -> String {
+        String::from("grade")
+    }
+}
+
+pub fn grade_entry_def() -> ValidatingEntryType {
+    entry!(
+        name: Grade::entry_type(),
+        description: "this is the teacher's grade for a student's submission",
+        sharing: Sharing::Public,
+        validation_package: || {
+            hdk::ValidationPackageDefinition::Entry
+        },
+        validation: | validation_data: hdk::EntryValidationData<Grade>| {
+            match validation_data {
+                EntryValidationData::Create { entry, validation_data } => {
+                    validation::grade_create(entry, validation_data)
+                },
+                EntryValidationData::Modify { new_entry, old_entry, old_entry_header, validation_data } => {
+                    validation::grade_modify(new_entry, old_entry, old_entry_header, validation_data)
+                },
+                EntryValidationData::Delete { old_entry, old_entry_header, validation_data } => {
+                    validation::grade_delete(old_entry, old_entry_header, validation_data)
+                }
+            }
+        },
+        links: [
+            // link from the submission this grade was given for
+            // There's at most one such link: regrading replaces it with a link to the updated Grade entry
+            from!(
+                Submission::entry_type(),
+                link_type: SUBMISSION_TO_GRADE_LINK,
+                validation_package: || {
+                    hdk::ValidationPackageDefinition::Entry
+                },
+                validation: | validation_data: hdk::LinkValidationData | {
+                    validation::submission_to_grade_link(validation_data)
+                }
+            )
+        ]
+    )
+}
@@ -0,0 +1,206 @@
+use hdk::prelude::*;
+use hdk::AGENT_ADDRESS;
+use holochain_entry_utils::HolochainEntry;
+
+use super::entry::{Assignment, SECTION_ANCHOR_TO_ASSIGNMENT_LINK};
+use super::grade::{Grade, SUBMISSION_TO_GRADE_LINK};
+use super::submission::{Submission, ASSIGNMENT_TO_SUBMISSION_LINK, STUDENT_TO_SUBMISSION_LINK};
+use crate::error::ZomeError;
+use crate::section;
+
+pub fn create(
+    title: String,
+    prompt: String,
+    due_date: u64,
+    max_score: u32,
+    section_anchor_address: Address,
+    timestamp: u64,
+) -> ZomeApiResult<Address> {
+    let latest_section_result = section::handlers::get_latest_section(&section_anchor_address)?;
+    match latest_section_result {
+        Some((current_section, _current_section_address)) => {
+            let new_assignment = Assignment::new(
+                title,
+                prompt,
+                due_date,
+                max_score,
+                timestamp,
+                section_anchor_address.clone(),
+                current_section.course_address,
+                current_section.teacher_address,
+            );
+            let new_assignment_address = hdk::commit_entry(&new_assignment.entry())?;
+            hdk::link_entries(
+                &section_anchor_address,
+                &new_assignment_address,
+                SECTION_ANCHOR_TO_ASSIGNMENT_LINK,
+                "",
+            )?;
+
+            Ok(new_assignment_address)
+        }
+        None => Err(ZomeError::deleted("section", "create an assignment in").into()),
+    }
+}
+
+pub fn get_assignments(section_anchor_address: &Address) -> ZomeApiResult<Vec<Address>> {
+    let links = hdk::get_links(
+        section_anchor_address,
+        LinkMatch::Exactly(SECTION_ANCHOR_TO_ASSIGNMENT_LINK),
+        LinkMatch::Any,
+    )?;
+
+    Ok(links.addresses())
+}
+
+pub fn delete(assignment_address: Address) -> ZomeApiResult<Address> {
+    let assignment: Assignment = hdk::utils::get_as_type(assignment_address.clone())?;
+
+    hdk::remove_link(
+        &assignment.section_anchor_address,
+        &assignment_address,
+        SECTION_ANCHOR_TO_ASSIGNMENT_LINK,
+        "",
+    )?;
+
+    // NOTE: submissions and grades are left as is: they belong to students and they still
+    // should be able to see their work in get_my_submissions
+    hdk::remove_entry(&assignment_address)
+}
+
+pub fn submit(
+    assignment_address: Address,
+    answer: String,
+    timestamp: u64,
+) -> ZomeApiResult<Address> {
+    // retrieve assignment entry. If it doesn't exist, we'll fail with error here so we're also validating input
+    let _assignment: Assignment = hdk::utils::get_as_type(assignment_address.clone())?;
+
+    let new_submission = Submission::new(
+        assignment_address.clone(),
+        answer,
+        AGENT_ADDRESS.clone(),
+        timestamp,
+    );
+    let new_submission_address = hdk::commit_entry(&new_submission.entry())?;
+
+    // link assignment to the submission for the teacher to be able to find it
+    hdk::link_entries(
+        &assignment_address,
+        &new_submission_address,
+        ASSIGNMENT_TO_SUBMISSION_LINK,
+        "",
+    )?;
+    // link student to the submission for them to be able to list their work
+    hdk::link_entries(
+        &AGENT_ADDRESS,
+        &new_submission_address,
+        STUDENT_TO_SUBMISSION_LINK,
+        "",
+    )?;
+
+    Ok(new_submission_address)
+}
+
+pub fn get_my_submissions() -> ZomeApiResult<Vec<Address>> {
+    let links = hdk::get_links(
+        &AGENT_ADDRESS,
+        LinkMatch::Exactly(STUDENT_TO_SUBMISSION_LINK),
+        LinkMatch::Any,
+    )?;
+
+    Ok(links.addresses())
+}
+
+pub fn get_submissions(assignment_address: &Address) -> ZomeApiResult<Vec<Address>> {
+    let links = hdk::get_links(
+        assignment_address,
+        LinkMatch::Exactly(ASSIGNMENT_TO_SUBMISSION_LINK),
+        LinkMatch::Any,
+    )?;
+
+    Ok(links.addresses())
+}
+
+// returns addresses of submissions for the assignment that don't have a grade yet
+pub fn get_ungraded_submissions(assignment_address: &Address) -> ZomeApiResult<Vec<Address>> {
+    let mut ungraded_submissions = Vec::new();
+    for submission_address in get_submissions(assignment_address)? {
+        if get_grade(&submission_address)?.is_none() {
+            ungraded_submissions.push(submission_address);
+        }
+    }
+
+    Ok(ungraded_submissions)
+}
+
+// returns the grade for the submission with it's address or None if it wasn't graded yet
+pub fn get_grade(submission_address: &Address) -> ZomeApiResult<Option<(Grade, Address)>> {
+    let grade_addresses = hdk::get_links(
+        submission_address,
+        LinkMatch::Exactly(SUBMISSION_TO_GRADE_LINK),
+        LinkMatch::Any,
+    )?
+    .addresses();
+
+    match grade_addresses.len() {
+        0 => Ok(None),
+        1 => {
+            let grade_address = grade_addresses[0].clone();
+            let grade: Grade = hdk::utils::get_as_type(grade_address.clone())?;
+            Ok(Some((grade, grade_address)))
+        }
+        _ => Err(ZomeError::inconsistent_links("from Submission to Grade").into()),
+    }
+}
+
+pub fn get_grade_entry(submission_address: Address) -> ZomeApiResult<Option<Grade>> {
+    match get_grade(&submission_address)? {
+        Some((grade_entry, _grade_entry_address)) => Ok(Some(grade_entry)),
+        None => Ok(None),
+    }
+}
+
+// grades the submission or updates it's grade if it was already graded
+pub fn grade(
+    submission_address: Address,
+    score: u32,
+    feedback: String,
+    timestamp: u64,
+) -> ZomeApiResult<Address> {
+    let submission: Submission = hdk::utils::get_as_type(submission_address.clone())?;
+    let assignment: Assignment = hdk::utils::get_as_type(submission.assignment_address)?;
+
+    let new_grade = Grade::new(
+        submission_address.clone(),
+        score,
+        feedback,
+        assignment.teacher_address,
+        timestamp,
+    );
+
+    let new_grade_address = match get_grade(&submission_address)? {
+        Some((_previous_grade, previous_grade_address)) => {
+            let new_grade_address = hdk::update_entry(new_grade.entry(), &previous_grade_address)?;
+            // remove link to previous version of grade
+            hdk::remove_link(
+                &submission_address,
+                &previous_grade_address,
+                SUBMISSION_TO_GRADE_LINK,
+                "",
+            )?;
+            new_grade_address
+        }
+        None => hdk::commit_entry(&new_grade.entry())?,
+    };
+
+    // create link to the latest version of grade
+    hdk::link_entries(
+        &submission_address,
+        &new_grade_address,
+        SUBMISSION_TO_GRADE_LINK,
+        "",
+    )?;
+
+    Ok(new_grade_address)
+}
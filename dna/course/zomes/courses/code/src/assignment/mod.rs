@@ -0,0 +1,5 @@
+pub mod entry;
+pub mod grade;
+pub mod handlers;
+pub mod submission;
+mod validation;
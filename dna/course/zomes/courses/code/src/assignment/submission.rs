@@ -0,0 +1,90 @@
+use hdk::prelude::*;
+use holochain_entry_utils::HolochainEntry;
+
+use super::entry::Assignment;
+use super::validation;
+
+pub const ASSIGNMENT_TO_SUBMISSION_LINK: &str = "assignment->submission";
+pub const STUDENT_TO_SUBMISSION_LINK: &str = "student->submission";
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct Submission {
+    pub assignment_address: Address,
+    pub answer: String,
+    pub student_address: Address,
+    pub timestamp: u64,
+}
+
+impl Submission {
+    pub fn new(
+        assignment_address: Address,
+        answer: String,
+        student_address: Address,
+        timestamp: u64,
+    ) -> Self {
+        Submission {
+            assignment_address,
+            answer,
+            student_address,
+            timestamp,
+        }
+    }
+}
+
+impl HolochainEntry for Submission {
+    fn entry_type() -> String {
+        String::from("submission")
+    }
+}
+
+pub fn submission_entry_def() -> ValidatingEntryType {
+    entry!(
+        name: Submission::entry_type(),
+        description: "this is the work that an enrolled student submitted for an assignment",
+        sharing: Sharing::Public,
+        validation_package: || {
+            hdk::ValidationPackageDefinition::Entry
+        },
+        validation: | validation_data: hdk::EntryValidationData<Submission>| {
+            match validation_data {
+                EntryValidationData::Create { entry, validation_data } => {
+                    validation::submission_create(entry, validation_data)
+                },
+                // NOTE: the symbol .. means that we're skipping unpacking parameters that we receive here
+                // because we won't need them
+                EntryValidationData::Modify { .. } => {
+                    validation::submission_modify()
+                },
+                EntryValidationData::Delete { old_entry, old_entry_header, validation_data } => {
+                    validation::submission_delete(old_entry, old_entry_header, validation_data)
+                }
+            }
+        },
+        links: [
+            // link from the assignment this work was submitted for
+            // This is for the teacher to find all submissions they need to grade
+            from!(
+                Assignment::entry_type(),
+                link_type: ASSIGNMENT_TO_SUBMISSION_LINK,
+                validation_package: || {
+                    hdk::ValidationPackageDefinition::Entry
+                },
+                validation: | validation_data: hdk::LinkValidationData | {
+                    validation::submission_link(validation_data)
+                }
+            ),
+            // link from agent that is a student who submitted this work
+            // This is for student to keep track of all their submissions
+            from!(
+                "%agent_id", // this is a special string that would automatically expand to the hdk::AGENT_ADDRESS
+                link_type: STUDENT_TO_SUBMISSION_LINK,
+                validation_package: || {
+                    hdk::ValidationPackageDefinition::Entry
+                },
+                validation: | validation_data: hdk::LinkValidationData | {
+                    validation::submission_link(validation_data)
+                }
+            )
+        ]
+    )
+}
@@ -0,0 +1,261 @@
+use hdk::holochain_core_types::chain_header::ChainHeader;
+use hdk::{LinkValidationData, ValidationData};
+use holochain_entry_utils::HolochainEntry;
+
+use super::{
+    entry::{Assignment, MAX_TITLE_LEN},
+    grade::Grade,
+    submission::Submission,
+};
+use crate::course;
+use crate::error::ZomeError;
+use crate::helper;
+use crate::section::anchor::SectionAnchor;
+
+pub fn create(entry: Assignment, validation_data: ValidationData) -> Result<(), String> {
+    helper::validate_only_teacher_can_do(
+        &entry.teacher_address,
+        validation_data.sources(),
+        "create assignments in this course",
+    )?;
+    helper::validate_entity_title(&entry.title, &Assignment::entry_type(), MAX_TITLE_LEN)?;
+    if entry.max_score == 0 {
+        return Err(ZomeError::invalid_value(
+            "assignment",
+            "max_score",
+            "has to be greater than 0",
+        )
+        .into());
+    }
+    // make sure that teacher_address and course_address copied from the section are the real ones
+    let section_anchor: SectionAnchor =
+        hdk::utils::get_as_type(entry.section_anchor_address.clone())?;
+    if section_anchor.teacher_address != entry.teacher_address {
+        return Err(ZomeError::forbidden(
+            "Only the teacher of the course can create assignments in it's sections",
+        )
+        .into());
+    }
+    if section_anchor.course_address != entry.course_address {
+        return Err(ZomeError::invalid_input(
+            "Assignment has to belong to the same course as it's section",
+        )
+        .into());
+    }
+    Ok(())
+}
+
+pub fn modify(
+    new_entry: Assignment,
+    old_entry: Assignment,
+    _old_entry_header: ChainHeader,
+    validation_data: ValidationData,
+) -> Result<(), String> {
+    helper::validate_only_teacher_can_do(
+        &old_entry.teacher_address,
+        validation_data.sources(),
+        "modify assignments in this course",
+    )?;
+    if new_entry.section_anchor_address != old_entry.section_anchor_address
+        || new_entry.course_address != old_entry.course_address
+    {
+        return Err(ZomeError::immutable_field("assignment", "section").into());
+    }
+    if new_entry.max_score == 0 {
+        return Err(ZomeError::invalid_value(
+            "assignment",
+            "max_score",
+            "has to be greater than 0",
+        )
+        .into());
+    }
+    helper::validate_entity_title(&new_entry.title, &Assignment::entry_type(), MAX_TITLE_LEN)?;
+    helper::validate_no_teacher_change(
+        old_entry.teacher_address,
+        new_entry.teacher_address,
+        &Assignment::entry_type(),
+    )
+}
+
+pub fn delete(
+    entry: Assignment,
+    _entry_header: ChainHeader,
+    validation_data: ValidationData,
+) -> Result<(), String> {
+    helper::validate_only_teacher_can_do(
+        &entry.teacher_address,
+        validation_data.sources(),
+        "delete assignments in this course",
+    )
+}
+
+// =========================== Submission validation
+pub fn submission_create(entry: Submission, validation_data: ValidationData) -> Result<(), String> {
+    if !validation_data.sources().contains(&entry.student_address) {
+        return Err(ZomeError::forbidden("Students can only submit their own work").into());
+    }
+    let assignment: Assignment = hdk::utils::get_as_type(entry.assignment_address.clone())?;
+    let students = course::handlers::get_students(assignment.course_address)?;
+    if !students.contains(&entry.student_address) {
+        return Err(ZomeError::forbidden(
+            "Only students enrolled in the course can submit their work",
+        )
+        .into());
+    }
+    if entry.timestamp > assignment.due_date {
+        return Err(
+            ZomeError::forbidden("Can't submit work after the due date of the assignment").into(),
+        );
+    }
+    Ok(())
+}
+
+// NOTE: we don't accept any parameters here because we don't need them to always return an error
+// because submitted work can never be modified: student can submit it once more instead
+pub fn submission_modify() -> Result<(), String> {
+    Err(ZomeError::immutable_entry("submission").into())
+}
+
+pub fn submission_delete(
+    entry: Submission,
+    _entry_header: ChainHeader,
+    validation_data: ValidationData,
+) -> Result<(), String> {
+    if !validation_data.sources().contains(&entry.student_address) {
+        return Err(ZomeError::forbidden("Students can only delete their own work").into());
+    }
+    Ok(())
+}
+
+// =========================== Grade validation
+// validates that teacher_address is the teacher of the assignment that submission was made for
+// and that score fits into the assignment's limits
+fn validate_grade(grade: &Grade) -> Result<(), String> {
+    let submission: Submission = hdk::utils::get_as_type(grade.submission_address.clone())?;
+    let assignment: Assignment = hdk::utils::get_as_type(submission.assignment_address)?;
+    if assignment.teacher_address != grade.teacher_address {
+        return Err(
+            ZomeError::forbidden("Only the teacher of the course can grade submissions").into(),
+        );
+    }
+    if grade.score > assignment.max_score {
+        return Err(ZomeError::invalid_value(
+            "grade",
+            "score",
+            &format!(
+                "can't be greater than assignment's max score of {}",
+                assignment.max_score
+            ),
+        )
+        .into());
+    }
+    Ok(())
+}
+
+pub fn grade_create(entry: Grade, validation_data: ValidationData) -> Result<(), String> {
+    helper::validate_only_teacher_can_do(
+        &entry.teacher_address,
+        validation_data.sources(),
+        "grade submissions",
+    )?;
+    validate_grade(&entry)
+}
+
+pub fn grade_modify(
+    new_entry: Grade,
+    old_entry: Grade,
+    _old_entry_header: ChainHeader,
+    validation_data: ValidationData,
+) -> Result<(), String> {
+    helper::validate_only_teacher_can_do(
+        &old_entry.teacher_address,
+        validation_data.sources(),
+        "modify grades",
+    )?;
+    if new_entry.submission_address != old_entry.submission_address {
+        return Err(ZomeError::immutable_field("grade", "submission").into());
+    }
+    helper::validate_no_teacher_change(
+        old_entry.teacher_address,
+        new_entry.teacher_address.clone(),
+        &Grade::entry_type(),
+    )?;
+    validate_grade(&new_entry)
+}
+
+pub fn grade_delete(
+    entry: Grade,
+    _entry_header: ChainHeader,
+    validation_data: ValidationData,
+) -> Result<(), String> {
+    helper::validate_only_teacher_can_do(
+        &entry.teacher_address,
+        validation_data.sources(),
+        "delete grades",
+    )
+}
+
+//  =========================== Assignment links validation
+pub fn section_anchor_to_assignment_link(
+    validation_data: LinkValidationData,
+) -> Result<(), String> {
+    // both adding and removing this link is allowed only for the teacher of the course
+    let (link, validation_data) = match validation_data {
+        hdk::LinkValidationData::LinkAdd {
+            link,
+            validation_data,
+        } => (link, validation_data),
+        hdk::LinkValidationData::LinkRemove {
+            link,
+            validation_data,
+        } => (link, validation_data),
+    };
+    // get author of this entry
+    let author = validation_data.package.chain_header.provenances()[0].source();
+    // get link base: entry from which the link goes
+    let base: SectionAnchor = hdk::utils::get_as_type(link.link.base().clone())?;
+    if author != base.teacher_address {
+        return Err(ZomeError::invalid_link("section_anchor->assignment", "Can't link SectionAnchor to Assignment because your address isn't specified as teacher address for this course").into());
+    }
+    Ok(())
+}
+
+// used for both links that point to a Submission: only the student who submitted the work can manage them
+pub fn submission_link(validation_data: LinkValidationData) -> Result<(), String> {
+    let (link, validation_data) = match validation_data {
+        hdk::LinkValidationData::LinkAdd {
+            link,
+            validation_data,
+        } => (link, validation_data),
+        hdk::LinkValidationData::LinkRemove {
+            link,
+            validation_data,
+        } => (link, validation_data),
+    };
+    let author = validation_data.package.chain_header.provenances()[0].source();
+    // get link target: entry to which the link goes
+    let target: Submission = hdk::utils::get_as_type(link.link.target().clone())?;
+    if author != target.student_address {
+        return Err(ZomeError::invalid_link("*->submission", "Can't link to Submission because your address isn't specified as student address for it").into());
+    }
+    Ok(())
+}
+
+pub fn submission_to_grade_link(validation_data: LinkValidationData) -> Result<(), String> {
+    let (link, validation_data) = match validation_data {
+        hdk::LinkValidationData::LinkAdd {
+            link,
+            validation_data,
+        } => (link, validation_data),
+        hdk::LinkValidationData::LinkRemove {
+            link,
+            validation_data,
+        } => (link, validation_data),
+    };
+    let author = validation_data.package.chain_header.provenances()[0].source();
+    let target: Grade = hdk::utils::get_as_type(link.link.target().clone())?;
+    if author != target.teacher_address {
+        return Err(ZomeError::invalid_link("submission->grade", "Can't link Submission to Grade because your address isn't specified as teacher address for this course").into());
+    }
+    Ok(())
+}
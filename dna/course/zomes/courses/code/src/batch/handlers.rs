@@ -0,0 +1,285 @@
+use hdk::prelude::*;
+use hdk::AGENT_ADDRESS;
+use holochain_entry_utils::HolochainEntry;
+use std::collections::HashMap;
+
+use super::operation::{BatchOperation, SectionRef};
+use crate::content;
+use crate::content::entry::Content;
+use crate::course;
+use crate::course::entry::Course;
+use crate::error::ZomeError;
+use crate::helper;
+use crate::section;
+use crate::section::entry::{Section, MAX_TITLE_LEN};
+use crate::signal::{self, ChangeKind, ChangeNotice};
+
+fn operation_error(index: usize, message: &str) -> ZomeApiError {
+    ZomeError::invalid_input(&format!("Operation {}: {}", index, message)).into()
+}
+
+// adds index of the operation to the error that was returned by a handler while checking it
+fn wrap_operation_error(index: usize, error: ZomeApiError) -> ZomeApiError {
+    // zome errors are JSON with a human-readable message, other errors are plain text
+    let message = match &error {
+        ZomeApiError::Internal(error_json) => serde_json::from_str::<serde_json::Value>(error_json)
+            .ok()
+            .and_then(|error_object| error_object["message"].as_str().map(String::from))
+            .unwrap_or_else(|| error_json.clone()),
+        _ => error.to_string(),
+    };
+    operation_error(index, &message)
+}
+
+// checks that all operations refer to sections and contents of this course before anything is committed,
+// so invalid batch doesn't leave course half-changed. Returns latest versions of contents that are changed by the batch
+// together with their addresses, keyed by ContentAnchor address
+fn check_operations(
+    course: &Course,
+    operations: &[BatchOperation],
+) -> ZomeApiResult<HashMap<Address, (Content, Address)>> {
+    let mut sections = course.sections.clone();
+    let mut created_section_titles: Vec<&String> = vec![];
+    let mut created_content_names: Vec<(&SectionRef, &String)> = vec![];
+    let mut contents: HashMap<Address, (Content, Address)> = HashMap::new();
+
+    for (index, operation) in operations.iter().enumerate() {
+        match operation {
+            BatchOperation::CreateSection { title } => {
+                helper::validate_entity_title(title, &Section::entry_type(), MAX_TITLE_LEN)
+                    .map_err(|e| operation_error(index, &e))?;
+                // NOTE: all sections in the batch get the same timestamp and SectionAnchor address depends
+                // only on title and timestamp, so sections with the same title would end up being the same section
+                if created_section_titles.contains(&title) {
+                    return Err(operation_error(
+                        index,
+                        "can't create two sections with the same title in one batch",
+                    ));
+                }
+                created_section_titles.push(title);
+            }
+            BatchOperation::UpdateSection {
+                section_anchor_address,
+                title,
+            } => {
+                if !sections.contains(section_anchor_address) {
+                    return Err(operation_error(index, "section isn't in this course"));
+                }
+                content::handlers::validate_section_not_deleted(section_anchor_address, "update")
+                    .map_err(|e| wrap_operation_error(index, e))?;
+                helper::validate_entity_title(title, &Section::entry_type(), MAX_TITLE_LEN)
+                    .map_err(|e| operation_error(index, &e))?;
+            }
+            BatchOperation::DeleteSection {
+                section_anchor_address,
+            } => {
+                if !sections.contains(section_anchor_address) {
+                    return Err(operation_error(index, "section isn't in this course"));
+                }
+                sections.retain(|address| address != section_anchor_address);
+            }
+            BatchOperation::CreateContent { section, name, .. } => {
+                match section {
+                    SectionRef::Existing(section_anchor_address) => {
+                        if !sections.contains(section_anchor_address) {
+                            return Err(operation_error(index, "section isn't in this course"));
+                        }
+                        content::handlers::validate_section_not_deleted(
+                            section_anchor_address,
+                            "create a content in",
+                        )
+                        .map_err(|e| wrap_operation_error(index, e))?;
+                    }
+                    SectionRef::Created(section_index) => {
+                        match operations[..index].get(*section_index) {
+                            Some(BatchOperation::CreateSection { .. }) => {}
+                            _ => return Err(operation_error(
+                                index,
+                                "section has to be created by an earlier operation of this batch",
+                            )),
+                        }
+                    }
+                }
+                // NOTE: same as with sections, names make it easier to tell contents apart in the UI
+                // and can't repeat in one section of the batch
+                if created_content_names.contains(&(section, name)) {
+                    return Err(operation_error(
+                        index,
+                        "can't create two contents with the same name in one section in one batch",
+                    ));
+                }
+                created_content_names.push((section, name));
+            }
+            BatchOperation::UpdateContent {
+                content_anchor_address,
+                ..
+            }
+            | BatchOperation::DeleteContent {
+                content_anchor_address,
+            } => {
+                let (content, content_address) =
+                    content::handlers::get_latest_content(content_anchor_address)
+                        .map_err(|e| wrap_operation_error(index, e))?
+                        .ok_or_else(|| operation_error(index, "content doesn't exist"))?;
+                if !sections.contains(&content.section_anchor_address) {
+                    return Err(operation_error(
+                        index,
+                        "content doesn't belong to a section of this course",
+                    ));
+                }
+                // NOTE: deleted sections are still listed in the course, so this isn't covered by the check above
+                content::handlers::validate_section_not_deleted(
+                    &content.section_anchor_address,
+                    "change content in",
+                )
+                .map_err(|e| wrap_operation_error(index, e))?;
+                if contents
+                    .insert(content_anchor_address.clone(), (content, content_address))
+                    .is_some()
+                {
+                    return Err(operation_error(
+                        index,
+                        "content can only be changed once in a batch",
+                    ));
+                }
+            }
+        }
+    }
+    Ok(contents)
+}
+
+// applies all operations to the course and updates Course entry only once at the end.
+// Returns address for each operation in the same order: section anchor address for section operations,
+// and content anchor address for content operations
+pub fn apply(
+    course_anchor_address: Address,
+    operations: Vec<BatchOperation>,
+    timestamp: u64,
+) -> ZomeApiResult<Vec<Address>> {
+    let (mut course, course_address) =
+        match course::handlers::get_latest_course(&course_anchor_address)? {
+            Some(latest_course) => latest_course,
+            None => return Err(ZomeError::deleted("course", "change").into()),
+        };
+    if &course.teacher_address != *AGENT_ADDRESS {
+        return Err(ZomeError::forbidden("Only the teacher of the course can change it").into());
+    }
+    let mut contents = check_operations(&course, &operations)?;
+
+    let mut addresses: Vec<Address> = vec![];
+    for operation in operations {
+        let address = match operation {
+            BatchOperation::CreateSection { title } => {
+                let section_anchor_address = section::handlers::commit_new_section(
+                    title,
+                    &course_anchor_address,
+                    None,
+                    timestamp,
+                    addresses.len() as u64,
+                    course.teacher_address.clone(),
+                )?;
+                course.sections.push(section_anchor_address.clone());
+                section_anchor_address
+            }
+            BatchOperation::UpdateSection {
+                section_anchor_address,
+                title,
+            } => match section::handlers::get_latest_section(&section_anchor_address)? {
+                Some((mut section, section_address)) => {
+                    section.title = title;
+                    section.timestamp = timestamp;
+                    section::handlers::commit_update(
+                        section,
+                        &section_address,
+                        &section_anchor_address,
+                    )?;
+                    section_anchor_address
+                }
+                None => return Err(ZomeError::deleted("section", "update").into()),
+            },
+            BatchOperation::DeleteSection {
+                section_anchor_address,
+            } => {
+                // NOTE: same as in section::handlers::delete, we're only deleting the anchor
+                hdk::remove_entry(&section_anchor_address)?;
+                course
+                    .sections
+                    .retain(|address| address != &section_anchor_address);
+                section_anchor_address
+            }
+            BatchOperation::CreateContent {
+                section,
+                name,
+                url,
+                description,
+            } => {
+                let section_anchor_address = match section {
+                    SectionRef::Existing(section_anchor_address) => section_anchor_address,
+                    // check_operations made sure that this index points to an earlier CreateSection
+                    SectionRef::Created(section_index) => addresses[section_index].clone(),
+                };
+                // all contents of the batch have the same timestamp, so index of the operation orders them
+                content::handlers::commit_new_content(
+                    Content::new(
+                        name,
+                        section_anchor_address,
+                        url,
+                        timestamp,
+                        description,
+                        course.teacher_address.clone(),
+                    ),
+                    addresses.len() as u64,
+                )?
+            }
+            BatchOperation::UpdateContent {
+                content_anchor_address,
+                name,
+                url,
+                description,
+            } => {
+                // check_operations has already retrieved all contents that are changed
+                let (mut content, content_address) =
+                    contents.remove(&content_anchor_address).ok_or_else(|| {
+                        ZomeApiError::from(ZomeError::invalid_input(
+                            "Content wasn't checked before update",
+                        ))
+                    })?;
+                content.name = name;
+                content.url = url;
+                content.description = description;
+                content.timestamp = timestamp;
+                content::handlers::commit_update(
+                    content,
+                    &content_address,
+                    &content_anchor_address,
+                )?;
+                content_anchor_address
+            }
+            BatchOperation::DeleteContent {
+                content_anchor_address,
+            } => {
+                let (content, _content_address) =
+                    contents.remove(&content_anchor_address).ok_or_else(|| {
+                        ZomeApiError::from(ZomeError::invalid_input(
+                            "Content wasn't checked before delete",
+                        ))
+                    })?;
+                content::handlers::remove(&content, &content_anchor_address)?;
+                content_anchor_address
+            }
+        };
+        addresses.push(address);
+    }
+
+    // update the course only once for the whole batch
+    course.timestamp = timestamp;
+    course::handlers::commit_update(course, &course_address, &course_anchor_address)?;
+
+    // and notify the UI only once too
+    signal::notify(ChangeNotice::course(
+        ChangeKind::Updated,
+        course_anchor_address,
+    ))?;
+
+    Ok(addresses)
+}
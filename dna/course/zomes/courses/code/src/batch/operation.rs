@@ -0,0 +1,41 @@
+use hdk::prelude::*;
+
+// Reference to the section that content should be created in
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum SectionRef {
+    // section that already exists in the course
+    Existing(Address),
+    // section that is created by the CreateSection operation with this index earlier in the same batch
+    Created(usize),
+}
+
+// Single change to the sections or contents of the course that can be applied as a part of a batch
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum BatchOperation {
+    CreateSection {
+        title: String,
+    },
+    UpdateSection {
+        section_anchor_address: Address,
+        title: String,
+    },
+    DeleteSection {
+        section_anchor_address: Address,
+    },
+    CreateContent {
+        section: SectionRef,
+        name: String,
+        url: String,
+        description: String,
+    },
+    UpdateContent {
+        content_anchor_address: Address,
+        name: String,
+        url: String,
+        description: String,
+    },
+    DeleteContent {
+        content_anchor_address: Address,
+    },
+}
@@ -0,0 +1,59 @@
+use hdk::prelude::*;
+
+// Version of the course bundle format. Increase it every time the structs below change
+// in a way that older versions of this DNA wouldn't be able to read
+pub const BUNDLE_FORMAT_VERSION: u32 = 1;
+
+// Self-contained copy of the course that can be stored outside of the DHT
+// and moved between networks
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct CourseBundle {
+    pub format_version: u32,
+    pub course: CourseBundleMetadata,
+    // sections in the same order as they are listed in the course
+    pub sections: Vec<SectionBundle>,
+}
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct CourseBundleMetadata {
+    pub title: String,
+    // NOTE: these fields are only here for reference: addresses are meaningless in another network
+    // and course would be owned by whoever imports it
+    pub course_anchor_address: Address,
+    pub teacher_address: Address,
+    pub timestamp: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct SectionBundle {
+    pub title: String,
+    pub timestamp: u64,
+    // contents in the order they were created
+    pub contents: Vec<ContentBundle>,
+    // NOTE: bundles that were exported before subsections were introduced don't have this field
+    #[serde(default)]
+    pub subsections: Vec<SectionBundle>,
+}
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct ContentBundle {
+    pub name: String,
+    pub url: String,
+    pub description: String,
+    pub timestamp: u64,
+}
+
+// Error with a single item of the bundle that was imported.
+// item is the path to the item in the bundle, e.g. "sections[1].contents[0]"
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct ImportItemError {
+    pub item: String,
+    pub error: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct ImportReport {
+    // None if course wasn't created
+    pub course_anchor_address: Option<Address>,
+    pub errors: Vec<ImportItemError>,
+}
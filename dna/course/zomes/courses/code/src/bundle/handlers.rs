@@ -0,0 +1,240 @@
+use hdk::prelude::*;
+use holochain_entry_utils::HolochainEntry;
+
+use super::format::{
+    ContentBundle, CourseBundle, CourseBundleMetadata, ImportItemError, ImportReport,
+    SectionBundle, BUNDLE_FORMAT_VERSION,
+};
+use crate::content;
+use crate::course;
+use crate::course::entry::Course;
+use crate::error::ZomeError;
+use crate::helper;
+use crate::section;
+use crate::section::entry::Section;
+
+fn export_section(section_anchor_address: &Address) -> ZomeApiResult<Option<SectionBundle>> {
+    match section::handlers::get_latest_section(section_anchor_address)? {
+        Some((section, _section_address)) => {
+            let contents = content::handlers::get_ordered_contents(section_anchor_address)?
+                .into_iter()
+                .map(|(content, _content_anchor_address)| ContentBundle {
+                    name: content.name,
+                    url: content.url,
+                    description: content.description,
+                    timestamp: content.timestamp,
+                })
+                .collect();
+
+            let mut subsections = vec![];
+            for subsection_anchor_address in section.subsections.iter() {
+                if let Some(subsection_bundle) = export_section(subsection_anchor_address)? {
+                    subsections.push(subsection_bundle);
+                }
+            }
+
+            Ok(Some(SectionBundle {
+                title: section.title,
+                timestamp: section.timestamp,
+                contents,
+                subsections,
+            }))
+        }
+        // section was deleted but is still listed in the course
+        None => Ok(None),
+    }
+}
+
+pub fn export(course_anchor_address: Address) -> ZomeApiResult<CourseBundle> {
+    match course::handlers::get_latest_course(&course_anchor_address)? {
+        Some((course, _course_address)) => {
+            let mut sections = vec![];
+            for section_anchor_address in course.sections.iter() {
+                if let Some(section_bundle) = export_section(section_anchor_address)? {
+                    sections.push(section_bundle);
+                }
+            }
+
+            Ok(CourseBundle {
+                format_version: BUNDLE_FORMAT_VERSION,
+                course: CourseBundleMetadata {
+                    title: course.title,
+                    course_anchor_address,
+                    teacher_address: course.teacher_address,
+                    timestamp: course.timestamp,
+                },
+                sections,
+            })
+        }
+        None => Err(ZomeError::deleted("course", "export").into()),
+    }
+}
+
+fn item_error(item: String, error: String) -> ImportItemError {
+    ImportItemError { item, error }
+}
+
+// checks the whole bundle before anything is committed, so we don't end up with a half-built course
+fn validate_bundle(bundle: &CourseBundle) -> Vec<ImportItemError> {
+    let mut errors = vec![];
+    if bundle.format_version != BUNDLE_FORMAT_VERSION {
+        errors.push(item_error(
+            "format_version".to_owned(),
+            format!(
+                "Unsupported bundle format version {}, expected {}",
+                bundle.format_version, BUNDLE_FORMAT_VERSION
+            ),
+        ));
+        // we can't be sure what the rest of the bundle means in another format version
+        return errors;
+    }
+    if let Err(e) = helper::validate_entity_title(
+        &bundle.course.title,
+        &Course::entry_type(),
+        course::entry::MAX_TITLE_LEN,
+    ) {
+        errors.push(item_error("course".to_owned(), e));
+    }
+    validate_sections(&bundle.sections, "sections", 1, &mut errors);
+    errors
+}
+
+// checks sections on the given depth of the tree together with their subsections. path is the location of
+// these sections in the bundle and is used to report errors
+fn validate_sections(
+    sections: &[SectionBundle],
+    path: &str,
+    depth: usize,
+    errors: &mut Vec<ImportItemError>,
+) {
+    for (section_index, section) in sections.iter().enumerate() {
+        let section_path = format!("{}[{}]", path, section_index);
+        if let Err(e) = helper::validate_entity_title(
+            &section.title,
+            &Section::entry_type(),
+            section::entry::MAX_TITLE_LEN,
+        ) {
+            errors.push(item_error(section_path.clone(), e));
+        }
+        for (content_index, content) in section.contents.iter().enumerate() {
+            if content.name.is_empty() {
+                errors.push(item_error(
+                    format!("{}.contents[{}]", section_path, content_index),
+                    ZomeError::invalid_value("content", "name", "can't be empty").into(),
+                ));
+            }
+        }
+        if !section.subsections.is_empty() && depth >= section::entry::MAX_DEPTH {
+            errors.push(item_error(
+                format!("{}.subsections", section_path),
+                ZomeError::invalid_value(
+                    "section",
+                    "parent",
+                    &format!(
+                        "sections can't be nested deeper than {} levels",
+                        section::entry::MAX_DEPTH
+                    ),
+                )
+                .into(),
+            ));
+        }
+        validate_sections(
+            &section.subsections,
+            &format!("{}.subsections", section_path),
+            depth + 1,
+            errors,
+        );
+    }
+}
+
+// recreates course from the bundle with the current agent as it's teacher.
+// If bundle is invalid, nothing is committed and all errors are listed in the report
+pub fn import(bundle: CourseBundle, timestamp: u64) -> ZomeApiResult<ImportReport> {
+    let mut errors = validate_bundle(&bundle);
+    if !errors.is_empty() {
+        return Ok(ImportReport {
+            course_anchor_address: None,
+            errors,
+        });
+    }
+
+    let course_anchor_address = match course::handlers::create(bundle.course.title, timestamp) {
+        Ok(course_anchor_address) => course_anchor_address,
+        Err(e) => {
+            errors.push(item_error("course".to_owned(), e.to_string()));
+            return Ok(ImportReport {
+                course_anchor_address: None,
+                errors,
+            });
+        }
+    };
+
+    // NOTE: from here on we're reporting errors with each item and continuing with the rest of them:
+    // bundle was already validated, so these can only be network issues that affect single items
+    import_sections(
+        bundle.sections,
+        &course_anchor_address,
+        None,
+        "sections",
+        timestamp,
+        &mut errors,
+    );
+
+    Ok(ImportReport {
+        course_anchor_address: Some(course_anchor_address),
+        errors,
+    })
+}
+
+// creates sections in the course or in the parent section if parent_address is given, together with their contents
+// and subsections. path is the location of these sections in the bundle and is used to report errors
+fn import_sections(
+    sections: Vec<SectionBundle>,
+    course_anchor_address: &Address,
+    parent_address: Option<&Address>,
+    path: &str,
+    timestamp: u64,
+    errors: &mut Vec<ImportItemError>,
+) {
+    for (section_index, section) in sections.into_iter().enumerate() {
+        let section_path = format!("{}[{}]", path, section_index);
+        // NOTE: original timestamps can't be kept because they have to be close to the time of the commit.
+        // Every section and content gets the same timestamp and is kept apart and in order by it's ordinal
+        // which create handlers take from the number of sections and contents that were already imported
+        let create_result = match parent_address {
+            Some(parent_address) => {
+                section::handlers::create_subsection(section.title, parent_address, timestamp)
+            }
+            None => section::handlers::create(section.title, course_anchor_address, timestamp),
+        };
+        let section_anchor_address = match create_result {
+            Ok(section_anchor_address) => section_anchor_address,
+            Err(e) => {
+                errors.push(item_error(section_path, e.to_string()));
+                continue;
+            }
+        };
+        for (content_index, content) in section.contents.into_iter().enumerate() {
+            if let Err(e) = content::handlers::create(
+                content.name,
+                section_anchor_address.clone(),
+                content.url,
+                timestamp,
+                content.description,
+            ) {
+                errors.push(item_error(
+                    format!("{}.contents[{}]", section_path, content_index),
+                    e.to_string(),
+                ));
+            }
+        }
+        import_sections(
+            section.subsections,
+            course_anchor_address,
+            Some(&section_anchor_address),
+            &format!("{}.subsections", section_path),
+            timestamp,
+            errors,
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use hdk::prelude::*;
+use holochain_entry_utils::HolochainEntry;
+
+use super::validation;
+use crate::anchor_trait::AnchorTrait;
+use crate::course::anchor::CourseAnchor;
+
+pub const STUDENT_TO_CERTIFICATE_LINK: &str = "student->certificate";
+pub const COURSE_ANCHOR_TO_CERTIFICATE_LINK: &str = "course_anchor->certificate";
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct Certificate {
+    pub student_address: Address,
+    pub course_anchor_address: Address,
+    // address of the Course entry that was the latest one when this certificate was issued.
+    // Courses change over time so this tells which version of the course the student has completed
+    pub course_revision: Address,
+    pub teacher_address: Address,
+    pub date: u64,
+    // teacher's signature of the payload() of this certificate
+    pub signature: String,
+}
+
+impl Certificate {
+    pub fn new(
+        student_address: Address,
+        course_anchor_address: Address,
+        course_revision: Address,
+        teacher_address: Address,
+        date: u64,
+    ) -> Self {
+        Certificate {
+            student_address,
+            course_anchor_address,
+            course_revision,
+            teacher_address,
+            date,
+            signature: String::default(),
+        }
+    }
+
+    // data that is signed by the teacher: every field of the certificate except for the signature itself
+    pub fn payload(&self) -> String {
+        serde_json::json!({
+            "student_address": self.student_address,
+            "course_anchor_address": self.course_anchor_address,
+            "course_revision": self.course_revision,
+            "teacher_address": self.teacher_address,
+            "date": self.date,
+        })
+        .to_string()
+    }
+}
+
+impl HolochainEntry for Certificate {
+    fn entry_type() -> String {
+        String::from("certificate")
+    }
+}
+
+pub fn certificate_entry_def() -> ValidatingEntryType {
+    entry!(
+        name: Certificate::entry_type(),
+        description: "this is a certificate that the teacher issues to a student who has completed the course",
+        sharing: Sharing::Public,
+        validation_package: || {
+            hdk::ValidationPackageDefinition::Entry
+        },
+        validation: | validation_data: hdk::EntryValidationData<Certificate>| {
+            match validation_data {
+                EntryValidationData::Create { entry, validation_data } => {
+                    validation::create(entry, validation_data)
+                },
+                // NOTE: the symbol .. means that we're skipping unpacking parameters that we receive here
+                // because we won't need them
+                EntryValidationData::Modify { .. } => {
+                    validation::modify()
+                },
+                EntryValidationData::Delete { old_entry, old_entry_header, validation_data } => {
+                    validation::delete(old_entry, old_entry_header, validation_data)
+                }
+            }
+        },
+        links: [
+            // link from agent that is a student who received this certificate
+            // This is for anyone to list certificates of a particular student
+            from!(
+                "%agent_id", // this is a special string that would automatically expand to the hdk::AGENT_ADDRESS
+                link_type: STUDENT_TO_CERTIFICATE_LINK,
+                validation_package: || {
+                    hdk::ValidationPackageDefinition::Entry
+                },
+                validation: | validation_data: hdk::LinkValidationData | {
+                    validation::certificate_link(validation_data)
+                }
+            ),
+            // link from the course this certificate was issued for
+            // This is for teacher to keep track of all the certificates they've issued
+            from!(
+                CourseAnchor::entry_type(),
+                link_type: COURSE_ANCHOR_TO_CERTIFICATE_LINK,
+                validation_package: || {
+                    hdk::ValidationPackageDefinition::Entry
+                },
+                validation: | validation_data: hdk::LinkValidationData | {
+                    validation::certificate_link(validation_data)
+                }
+            )
+        ]
+    )
+}
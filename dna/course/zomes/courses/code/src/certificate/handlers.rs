@@ -0,0 +1,138 @@
+use hdk::holochain_core_types::signature::{Provenance, Signature};
+use hdk::prelude::*;
+use hdk::AGENT_ADDRESS;
+use holochain_entry_utils::HolochainEntry;
+
+use super::entry::{Certificate, COURSE_ANCHOR_TO_CERTIFICATE_LINK, STUDENT_TO_CERTIFICATE_LINK};
+use crate::course;
+use crate::error::ZomeError;
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct CertificateVerification {
+    pub certificate: Certificate,
+    pub signature_valid: bool,
+    pub student_enrolled: bool,
+    pub revoked: bool,
+    // true only if all of the checks above have passed
+    pub valid: bool,
+}
+
+// checks that certificate's signature was made by the agent in it's teacher_address
+pub fn is_signature_valid(certificate: &Certificate) -> ZomeApiResult<bool> {
+    hdk::verify_signature(
+        Provenance::new(
+            certificate.teacher_address.clone(),
+            Signature::from(certificate.signature.clone()),
+        ),
+        certificate.payload(),
+    )
+}
+
+pub fn issue(
+    student_address: Address,
+    course_anchor_address: Address,
+    date: u64,
+) -> ZomeApiResult<Address> {
+    let latest_course_result = course::handlers::get_latest_course(&course_anchor_address)?;
+    match latest_course_result {
+        Some((course, course_address)) => {
+            let mut certificate = Certificate::new(
+                student_address.clone(),
+                course_anchor_address.clone(),
+                course_address,
+                course.teacher_address,
+                date,
+            );
+            certificate.signature = hdk::sign(certificate.payload())?;
+            let certificate_address = hdk::commit_entry(&certificate.entry())?;
+
+            hdk::link_entries(
+                &student_address,
+                &certificate_address,
+                STUDENT_TO_CERTIFICATE_LINK,
+                "",
+            )?;
+            hdk::link_entries(
+                &course_anchor_address,
+                &certificate_address,
+                COURSE_ANCHOR_TO_CERTIFICATE_LINK,
+                "",
+            )?;
+
+            Ok(certificate_address)
+        }
+        None => Err(ZomeError::deleted("course", "issue a certificate for").into()),
+    }
+}
+
+pub fn verify(certificate_address: Address) -> ZomeApiResult<CertificateVerification> {
+    // NOTE: get_entry_initial returns the certificate even if it was revoked (deleted)
+    // so that we're able to report revocation instead of failing with "entry not found"
+    let certificate: Certificate = match hdk::get_entry_initial(&certificate_address)? {
+        Some(entry) => Certificate::from_entry(&entry).ok_or_else(|| {
+            ZomeApiError::from(ZomeError::invalid_input(
+                "Entry at this address isn't a certificate",
+            ))
+        })?,
+        None => return Err(ZomeError::not_found("certificate").into()),
+    };
+    let revoked = hdk::get_entry(&certificate_address)?.is_none();
+    let signature_valid = is_signature_valid(&certificate)?;
+    let student_enrolled =
+        course::handlers::get_students(certificate.course_anchor_address.clone())?
+            .contains(&certificate.student_address);
+
+    Ok(CertificateVerification {
+        certificate,
+        signature_valid,
+        student_enrolled,
+        revoked,
+        valid: signature_valid && student_enrolled && !revoked,
+    })
+}
+
+pub fn get_certificates(student_address: &Address) -> ZomeApiResult<Vec<Address>> {
+    let links = hdk::get_links(
+        student_address,
+        LinkMatch::Exactly(STUDENT_TO_CERTIFICATE_LINK),
+        LinkMatch::Any,
+    )?;
+
+    Ok(links.addresses())
+}
+
+pub fn get_my_certificates() -> ZomeApiResult<Vec<Address>> {
+    get_certificates(&AGENT_ADDRESS)
+}
+
+pub fn get_course_certificates(course_anchor_address: &Address) -> ZomeApiResult<Vec<Address>> {
+    let links = hdk::get_links(
+        course_anchor_address,
+        LinkMatch::Exactly(COURSE_ANCHOR_TO_CERTIFICATE_LINK),
+        LinkMatch::Any,
+    )?;
+
+    Ok(links.addresses())
+}
+
+pub fn revoke(certificate_address: Address) -> ZomeApiResult<Address> {
+    // retrieve certificate entry. If it doesn't exist, we'll fail with error here so we're also validating input
+    let certificate: Certificate = hdk::utils::get_as_type(certificate_address.clone())?;
+
+    hdk::remove_link(
+        &certificate.student_address,
+        &certificate_address,
+        STUDENT_TO_CERTIFICATE_LINK,
+        "",
+    )?;
+    hdk::remove_link(
+        &certificate.course_anchor_address,
+        &certificate_address,
+        COURSE_ANCHOR_TO_CERTIFICATE_LINK,
+        "",
+    )?;
+
+    // NOTE: revoked certificate entry is deleted but still can be retrieved by verify
+    // which reports it as revoked
+    hdk::remove_entry(&certificate_address)
+}
@@ -0,0 +1,3 @@
+pub mod entry;
+pub mod handlers;
+mod validation;
@@ -0,0 +1,86 @@
+use hdk::holochain_core_types::chain_header::ChainHeader;
+use hdk::{LinkValidationData, ValidationData};
+
+use super::entry::Certificate;
+use super::handlers;
+use crate::course;
+use crate::course::{anchor::CourseAnchor, entry::Course};
+use crate::error::ZomeError;
+use crate::helper;
+
+pub fn create(entry: Certificate, validation_data: ValidationData) -> Result<(), String> {
+    helper::validate_only_teacher_can_do(
+        &entry.teacher_address,
+        validation_data.sources(),
+        "issue certificates for their courses",
+    )?;
+    let course_anchor: CourseAnchor = hdk::utils::get_as_type(entry.course_anchor_address.clone())?;
+    if course_anchor.teacher_address != entry.teacher_address {
+        return Err(ZomeError::forbidden(
+            "Only the teacher of the course can issue certificates for it",
+        )
+        .into());
+    }
+    let course_revision: Course = hdk::utils::get_as_type(entry.course_revision.clone())?;
+    if course_revision.anchor_address != entry.course_anchor_address {
+        return Err(ZomeError::invalid_input(
+            "Certificate's course revision has to be a version of the certified course",
+        )
+        .into());
+    }
+    let students = course::handlers::get_students(entry.course_anchor_address.clone())?;
+    if !students.contains(&entry.student_address) {
+        return Err(ZomeError::forbidden(
+            "Certificates can only be issued to students enrolled in the course",
+        )
+        .into());
+    }
+    if !handlers::is_signature_valid(&entry)? {
+        return Err(ZomeError::forbidden(
+            "Certificate has to be signed by the teacher of the course",
+        )
+        .into());
+    }
+    Ok(())
+}
+
+// NOTE: we don't accept any parameters here because we don't need them to always return an error
+// because the signed certificate can never be modified: it can only be revoked
+pub fn modify() -> Result<(), String> {
+    Err(ZomeError::immutable_entry("certificate").into())
+}
+
+pub fn delete(
+    entry: Certificate,
+    _entry_header: ChainHeader,
+    validation_data: ValidationData,
+) -> Result<(), String> {
+    helper::validate_only_teacher_can_do(
+        &entry.teacher_address,
+        validation_data.sources(),
+        "revoke certificates for their courses",
+    )
+}
+
+//  =========================== Certificate links validation
+// used for all links that point to a Certificate: only the teacher who issued it can manage them
+pub fn certificate_link(validation_data: LinkValidationData) -> Result<(), String> {
+    let (link, validation_data) = match validation_data {
+        hdk::LinkValidationData::LinkAdd {
+            link,
+            validation_data,
+        } => (link, validation_data),
+        hdk::LinkValidationData::LinkRemove {
+            link,
+            validation_data,
+        } => (link, validation_data),
+    };
+    // get author of this entry
+    let author = validation_data.package.chain_header.provenances()[0].source();
+    // get link target: entry to which the link goes
+    let target: Certificate = hdk::utils::get_as_type(link.link.target().clone())?;
+    if author != target.teacher_address {
+        return Err(ZomeError::invalid_link("*->certificate", "Can't link to Certificate because your address isn't specified as teacher address for it").into());
+    }
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use chrono::{DateTime, FixedOffset};
+use hdk::holochain_core_types::chain_header::ChainHeader;
+use hdk::prelude::*;
+use holochain_entry_utils::HolochainEntry;
+
+use crate::error::ZomeError;
+
+// hdk doesn't give zome code access to the system clock, but conductor puts the current time into the header
+// of every entry it commits. So to get the current time we're committing this tiny private entry
+// and reading the time from it's header.
+// NOTE: every call to now() adds an entry to the agent's source chain, so handlers that accept
+// timestamp from the UI are still there for UIs that can provide it
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct ClockTick {
+    // number of ticks that were committed before this one: makes every tick a different entry
+    pub sequence: u64,
+}
+
+impl HolochainEntry for ClockTick {
+    fn entry_type() -> String {
+        String::from("clock_tick")
+    }
+}
+
+pub fn clock_tick_entry_def() -> ValidatingEntryType {
+    entry!(
+        name: ClockTick::entry_type(),
+        description: "this is a private entry that is only committed to read the current time from it's header",
+        sharing: Sharing::Private,
+        validation_package: || {
+            hdk::ValidationPackageDefinition::Entry
+        },
+        validation: | validation_data: hdk::EntryValidationData<ClockTick>| {
+            match validation_data {
+                EntryValidationData::Modify { .. } => {
+                    Err(ZomeError::immutable_entry("clock_tick").into())
+                },
+                _ => Ok(())
+            }
+        },
+        links: []
+    )
+}
+
+// returns time of the chain header in seconds since Unix epoch
+pub fn header_time(header: &ChainHeader) -> u64 {
+    let time: DateTime<FixedOffset> = header.timestamp().into();
+    time.timestamp() as u64
+}
+
+// returns current time in seconds since Unix epoch according to the conductor of this agent.
+// NOTE: it commits an entry every time, so it should be called at most once per zome call and the time passed down
+// to handlers. Handlers that have just committed an entry can read the time from it's header instead
+pub fn now() -> ZomeApiResult<u64> {
+    let sequence = hdk::query(ClockTick::entry_type().into(), 0, 0)?.len() as u64;
+    let tick_address = hdk::commit_entry(&ClockTick { sequence }.entry())?;
+    let query_result = hdk::query_result(
+        ClockTick::entry_type().into(),
+        QueryArgsOptions {
+            start: 0,
+            limit: 0,
+            headers: true,
+            entries: false,
+        },
+    )?;
+    match query_result {
+        QueryResult::Headers(headers) => headers
+            .iter()
+            .find(|header| header.entry_address() == &tick_address)
+            .map(header_time)
+            .ok_or_else(|| ZomeApiError::from(ZomeError::not_found("clock_tick header"))),
+        _ => Err(
+            ZomeError::inconsistent_links("unexpected result of querying the source chain").into(),
+        ),
+    }
+}
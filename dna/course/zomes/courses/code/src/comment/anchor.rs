@@ -0,0 +1,144 @@
+use hdk::prelude::*;
+use holochain_entry_utils::HolochainEntry;
+
+use super::entry::Comment;
+use super::validation;
+use crate::anchor_trait::AnchorTrait;
+use crate::content::anchor::ContentAnchor;
+use crate::content::entry::Content;
+use crate::section::anchor::SectionAnchor;
+
+pub const SECTION_ANCHOR_TO_COMMENT_ANCHOR_LINK: &str = "section_anchor->comment_anchor";
+pub const CONTENT_ANCHOR_TO_COMMENT_ANCHOR_LINK: &str = "content_anchor->comment_anchor";
+// NOTE: threads about content that were started before ContentAnchor was introduced are linked from the Content entry
+pub const CONTENT_TO_COMMENT_ANCHOR_LINK: &str = "content->comment_anchor";
+pub const COMMENT_ANCHOR_TO_REPLY_LINK: &str = "comment_anchor->reply";
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct CommentAnchor {
+    // address of the SectionAnchor or ContentAnchor entry that is being discussed
+    pub target_address: Address,
+    // address of the CommentAnchor this comment replies to. None for comments that start a new thread
+    pub parent_address: Option<Address>,
+    pub author_address: Address,
+    pub timestamp: u64,
+    // NOTE: course_address and teacher_address are stored here for the same reason as teacher_address in
+    // the SectionAnchor: validation doesn't have to walk target -> SectionAnchor -> Course on every change
+    pub course_address: Address,
+    pub teacher_address: Address,
+}
+
+impl AnchorTrait for CommentAnchor {
+    fn entry_type() -> String {
+        String::from("comment_anchor")
+    }
+    fn link_to() -> String {
+        Comment::entry_type()
+    }
+    fn link_type() -> String {
+        "comment_anchor->comment".to_owned()
+    }
+}
+
+impl CommentAnchor {
+    pub fn new(
+        target_address: Address,
+        parent_address: Option<Address>,
+        author_address: Address,
+        timestamp: u64,
+        course_address: Address,
+        teacher_address: Address,
+    ) -> Self {
+        CommentAnchor {
+            target_address,
+            parent_address,
+            author_address,
+            timestamp,
+            course_address,
+            teacher_address,
+        }
+    }
+}
+
+pub fn comment_anchor_def() -> ValidatingEntryType {
+    entry!(
+        name: CommentAnchor::entry_type(),
+        description: "Anchor to the valid comment",
+        sharing: Sharing::Public,
+        validation_package: || {
+            hdk::ValidationPackageDefinition::Entry
+        },
+        validation: | validation_data: hdk::EntryValidationData<CommentAnchor>| {
+            match validation_data{
+                EntryValidationData::Create { entry, validation_data } => {
+                    validation::anchor_create(entry, validation_data)
+                 },
+                 // NOTE: the symbol .. means that we're skipping unpacking parameters that we receive here
+                 // because we won't need them
+                 EntryValidationData::Modify { .. } => {
+                    validation::anchor_modify()
+                 },
+                 EntryValidationData::Delete { old_entry, old_entry_header, validation_data } => {
+                    validation::anchor_delete(old_entry, old_entry_header, validation_data)
+                 }
+            }
+        },
+        links:[
+            // link that connects CommentAnchor to the latest Comment entry
+            to!(
+                CommentAnchor::link_to(),
+                link_type: CommentAnchor::link_type(),
+                validation_package:||{
+                    hdk::ValidationPackageDefinition::Entry
+                },
+                validation:|validation_data: hdk::LinkValidationData|{
+                    validation::anchor_to_comment_link(validation_data)
+                }
+            ),
+            // link from the section that is discussed in the thread started by this comment
+            from!(
+                SectionAnchor::entry_type(),
+                link_type: SECTION_ANCHOR_TO_COMMENT_ANCHOR_LINK,
+                validation_package:||{
+                    hdk::ValidationPackageDefinition::Entry
+                },
+                validation:|validation_data: hdk::LinkValidationData|{
+                    validation::to_anchor_link(validation_data)
+                }
+            ),
+            // link from the content that is discussed in the thread started by this comment
+            from!(
+                ContentAnchor::entry_type(),
+                link_type: CONTENT_ANCHOR_TO_COMMENT_ANCHOR_LINK,
+                validation_package:||{
+                    hdk::ValidationPackageDefinition::Entry
+                },
+                validation:|validation_data: hdk::LinkValidationData|{
+                    validation::to_anchor_link(validation_data)
+                }
+            ),
+            // link from the Content entry that was discussed before ContentAnchor was introduced
+            from!(
+                Content::entry_type(),
+                link_type: CONTENT_TO_COMMENT_ANCHOR_LINK,
+                validation_package:||{
+                    hdk::ValidationPackageDefinition::Entry
+                },
+                validation:|validation_data: hdk::LinkValidationData|{
+                    validation::to_anchor_link(validation_data)
+                }
+            ),
+            // link from the comment that this comment replies to
+            from!(
+                CommentAnchor::entry_type(),
+                link_type: COMMENT_ANCHOR_TO_REPLY_LINK,
+                validation_package:||{
+                    hdk::ValidationPackageDefinition::Entry
+                },
+                validation:|validation_data: hdk::LinkValidationData|{
+                    validation::to_anchor_link(validation_data)
+                }
+            )
+        ]
+    )
+}
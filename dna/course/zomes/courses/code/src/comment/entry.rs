@@ -0,0 +1,69 @@
+use hdk::prelude::*;
+use holochain_entry_utils::HolochainEntry;
+
+use super::validation;
+
+pub const MAX_TEXT_LEN: usize = 2000;
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct Comment {
+    pub text: String,
+    // true if the teacher of the course has hidden this comment from students
+    pub hidden: bool,
+    pub timestamp: u64,
+    pub anchor_address: Address,
+    // NOTE: fields below are copied from the CommentAnchor so that Comment entry can be validated on it's own
+    pub author_address: Address,
+    pub teacher_address: Address,
+}
+
+impl Comment {
+    pub fn new(
+        text: String,
+        timestamp: u64,
+        anchor_address: Address,
+        author_address: Address,
+        teacher_address: Address,
+    ) -> Self {
+        Comment {
+            text,
+            hidden: false,
+            timestamp,
+            anchor_address,
+            author_address,
+            teacher_address,
+        }
+    }
+}
+
+impl HolochainEntry for Comment {
+    fn entry_type() -> String {
+        String::from("comment")
+    }
+}
+
+pub fn comment_entry_def() -> ValidatingEntryType {
+    entry!(
+        name: Comment::entry_type(),
+        description: "this is a comment in the discussion of a section or content",
+        sharing: Sharing::Public,
+        validation_package: || {
+            hdk::ValidationPackageDefinition::Entry
+        },
+        validation: | validation_data: hdk::EntryValidationData<Comment>| {
+            match validation_data {
+                EntryValidationData::Create { entry, validation_data } => {
+                    validation::create(entry, validation_data)
+                },
+                EntryValidationData::Modify { new_entry, old_entry, old_entry_header, validation_data } => {
+                    validation::modify(new_entry, old_entry, old_entry_header, validation_data)
+                },
+                EntryValidationData::Delete { old_entry, old_entry_header, validation_data } => {
+                    validation::delete(old_entry, old_entry_header, validation_data)
+                }
+            }
+        },
+        // Comment is a data entry that is hidden behind the CommentAnchor so it doesn't have any links
+        links: []
+    )
+}
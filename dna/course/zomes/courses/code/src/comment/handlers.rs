@@ -0,0 +1,281 @@
+use hdk::prelude::*;
+use hdk::AGENT_ADDRESS;
+use holochain_entry_utils::HolochainEntry;
+
+use super::anchor::{
+    CommentAnchor, COMMENT_ANCHOR_TO_REPLY_LINK, CONTENT_ANCHOR_TO_COMMENT_ANCHOR_LINK,
+    CONTENT_TO_COMMENT_ANCHOR_LINK, SECTION_ANCHOR_TO_COMMENT_ANCHOR_LINK,
+};
+use super::entry::Comment;
+use crate::anchor_trait::AnchorTrait;
+use crate::content;
+use crate::content::anchor::ContentAnchor;
+use crate::content::entry::Content;
+use crate::error::ZomeError;
+use crate::helper;
+use crate::section::anchor::SectionAnchor;
+
+// returns SectionAnchor of the entry at target_address which has to be either a SectionAnchor or ContentAnchor.
+// Content entries are still accepted for threads that were started before ContentAnchor was introduced
+pub fn get_target_section_anchor(target_address: &Address) -> ZomeApiResult<SectionAnchor> {
+    let target_entry = hdk::get_entry(target_address)?
+        .ok_or_else(|| ZomeApiError::from(ZomeError::not_found("commented entry")))?;
+    if let Some(section_anchor) = SectionAnchor::from_entry(&target_entry) {
+        return Ok(section_anchor);
+    }
+    if let Some(content_anchor) = ContentAnchor::from_entry(&target_entry) {
+        return hdk::utils::get_as_type(content_anchor.section_anchor_address);
+    }
+    if let Some(content) = Content::from_entry(&target_entry) {
+        return hdk::utils::get_as_type(content.section_anchor_address);
+    }
+    Err(ZomeError::forbidden("Only sections and contents can be commented on").into())
+}
+
+// returns link type that connects the entry at target_address to comments that discuss it
+fn get_target_link_type(target_address: &Address) -> ZomeApiResult<&'static str> {
+    // NOTE: we're asking for the initial version of the entry because hdk::get_entry doesn't return deleted
+    // entries, and comments on deleted sections and contents still have to be deletable
+    let get_entry_result = hdk::get_entry_result(
+        target_address,
+        GetEntryOptions {
+            status_request: StatusRequestKind::Initial,
+            ..GetEntryOptions::default()
+        },
+    )?;
+    let target_entry = match get_entry_result.result {
+        GetEntryResultType::Single(item) => item.entry,
+        GetEntryResultType::All(_history) => None,
+    }
+    .ok_or_else(|| ZomeApiError::from(ZomeError::not_found("commented entry")))?;
+    if SectionAnchor::from_entry(&target_entry).is_some() {
+        Ok(SECTION_ANCHOR_TO_COMMENT_ANCHOR_LINK)
+    } else if ContentAnchor::from_entry(&target_entry).is_some() {
+        Ok(CONTENT_ANCHOR_TO_COMMENT_ANCHOR_LINK)
+    } else {
+        Ok(CONTENT_TO_COMMENT_ANCHOR_LINK)
+    }
+}
+
+fn commit_comment(
+    target_address: Address,
+    parent_address: Option<Address>,
+    text: String,
+    timestamp: u64,
+) -> ZomeApiResult<Address> {
+    let section_anchor = get_target_section_anchor(&target_address)?;
+
+    let comment_anchor = CommentAnchor::new(
+        target_address,
+        parent_address,
+        AGENT_ADDRESS.clone(),
+        timestamp,
+        section_anchor.course_address,
+        section_anchor.teacher_address.clone(),
+    );
+    let comment_anchor_address = hdk::commit_entry(&comment_anchor.entry())?;
+
+    let new_comment = Comment::new(
+        text,
+        timestamp,
+        comment_anchor_address.clone(),
+        AGENT_ADDRESS.clone(),
+        section_anchor.teacher_address,
+    );
+    let new_comment_address = hdk::commit_entry(&new_comment.entry())?;
+
+    hdk::link_entries(
+        &comment_anchor_address,
+        &new_comment_address,
+        CommentAnchor::link_type(),
+        "".to_owned(),
+    )?;
+
+    Ok(comment_anchor_address)
+}
+
+pub fn create(target_address: Address, text: String, timestamp: u64) -> ZomeApiResult<Address> {
+    let link_type = get_target_link_type(&target_address)?;
+    let comment_anchor_address = commit_comment(target_address.clone(), None, text, timestamp)?;
+
+    // link discussed entry to the CommentAnchor for this thread to be findable
+    hdk::link_entries(&target_address, &comment_anchor_address, link_type, "")?;
+
+    // CommentAnchor serves as this comment's ID so we return it
+    Ok(comment_anchor_address)
+}
+
+pub fn reply(
+    parent_comment_anchor_address: Address,
+    text: String,
+    timestamp: u64,
+) -> ZomeApiResult<Address> {
+    let parent_anchor: CommentAnchor =
+        hdk::utils::get_as_type(parent_comment_anchor_address.clone())?;
+    let comment_anchor_address = commit_comment(
+        parent_anchor.target_address,
+        Some(parent_comment_anchor_address.clone()),
+        text,
+        timestamp,
+    )?;
+
+    // link parent comment to the reply for it to be findable
+    hdk::link_entries(
+        &parent_comment_anchor_address,
+        &comment_anchor_address,
+        COMMENT_ANCHOR_TO_REPLY_LINK,
+        "",
+    )?;
+
+    Ok(comment_anchor_address)
+}
+
+pub fn get_latest_comment(
+    comment_anchor_address: &Address,
+) -> ZomeApiResult<Option<(Comment, Address)>> {
+    helper::get_latest_data_entry::<Comment>(comment_anchor_address, &CommentAnchor::link_type())
+}
+
+pub fn get_latest_comment_entry(comment_anchor_address: Address) -> ZomeApiResult<Option<Comment>> {
+    match get_latest_comment(&comment_anchor_address)? {
+        Some((mut comment_entry, _comment_entry_address)) => {
+            // NOTE: this doesn't make hidden comments secret because they're still on the DHT,
+            // but it keeps them out of the UI of everyone except the teacher and the author
+            if comment_entry.hidden
+                && &comment_entry.teacher_address != *AGENT_ADDRESS
+                && &comment_entry.author_address != *AGENT_ADDRESS
+            {
+                comment_entry.text = String::default();
+            }
+            Ok(Some(comment_entry))
+        }
+        None => Ok(None),
+    }
+}
+
+// lists comments that start new threads in the discussion of a section or content
+pub fn get_comments(target_address: &Address) -> ZomeApiResult<Vec<Address>> {
+    let link_type = get_target_link_type(target_address)?;
+    let mut comment_anchor_addresses = hdk::get_links(
+        target_address,
+        LinkMatch::Exactly(link_type),
+        LinkMatch::Any,
+    )?
+    .addresses();
+
+    // threads that were started before the content was migrated to ContentAnchor are linked from the Content entry
+    if link_type == CONTENT_ANCHOR_TO_COMMENT_ANCHOR_LINK {
+        for content_address in content::handlers::get_legacy_contents(target_address)? {
+            comment_anchor_addresses.extend(
+                hdk::get_links(
+                    &content_address,
+                    LinkMatch::Exactly(CONTENT_TO_COMMENT_ANCHOR_LINK),
+                    LinkMatch::Any,
+                )?
+                .addresses(),
+            );
+        }
+    }
+
+    Ok(comment_anchor_addresses)
+}
+
+pub fn get_replies(comment_anchor_address: &Address) -> ZomeApiResult<Vec<Address>> {
+    let links = hdk::get_links(
+        comment_anchor_address,
+        LinkMatch::Exactly(COMMENT_ANCHOR_TO_REPLY_LINK),
+        LinkMatch::Any,
+    )?;
+
+    Ok(links.addresses())
+}
+
+fn commit_update(
+    comment: Comment,
+    previous_comment_address: &Address,
+    comment_anchor_address: &Address,
+) -> ZomeApiResult<Address> {
+    // commit updated comment to DHT and get it's new address
+    let new_comment_address = hdk::update_entry(comment.entry(), previous_comment_address)?;
+
+    // remove link to previous version of comment
+    hdk::remove_link(
+        comment_anchor_address,
+        previous_comment_address,
+        CommentAnchor::link_type(),
+        "".to_owned(),
+    )?;
+
+    // create link to new version of comment
+    hdk::link_entries(
+        comment_anchor_address,
+        &new_comment_address,
+        CommentAnchor::link_type(),
+        "".to_owned(),
+    )?;
+
+    Ok(comment_anchor_address.to_owned())
+}
+
+pub fn update(
+    comment_anchor_address: &Address,
+    text: String,
+    timestamp: u64,
+) -> ZomeApiResult<Address> {
+    match get_latest_comment(comment_anchor_address)? {
+        Some((mut previous_comment, previous_comment_address)) => {
+            previous_comment.text = text;
+            previous_comment.timestamp = timestamp;
+            commit_update(
+                previous_comment,
+                &previous_comment_address,
+                comment_anchor_address,
+            )
+        }
+        None => Err(ZomeError::deleted("comment", "update").into()),
+    }
+}
+
+// used by the teacher to hide a comment from students or to show it again
+pub fn set_hidden(
+    comment_anchor_address: &Address,
+    hidden: bool,
+    timestamp: u64,
+) -> ZomeApiResult<Address> {
+    match get_latest_comment(comment_anchor_address)? {
+        Some((mut previous_comment, previous_comment_address)) => {
+            previous_comment.hidden = hidden;
+            previous_comment.timestamp = timestamp;
+            commit_update(
+                previous_comment,
+                &previous_comment_address,
+                comment_anchor_address,
+            )
+        }
+        None => Err(ZomeError::deleted("comment", "hide").into()),
+    }
+}
+
+pub fn delete(comment_anchor_address: Address) -> ZomeApiResult<Address> {
+    // retrieve comment_anchor entry. If it doesn't exist, we'll fail with error here so we're also validating input
+    let comment_anchor: CommentAnchor = hdk::utils::get_as_type(comment_anchor_address.clone())?;
+
+    match &comment_anchor.parent_address {
+        Some(parent_address) => hdk::remove_link(
+            parent_address,
+            &comment_anchor_address,
+            COMMENT_ANCHOR_TO_REPLY_LINK,
+            "",
+        )?,
+        None => hdk::remove_link(
+            &comment_anchor.target_address,
+            &comment_anchor_address,
+            get_target_link_type(&comment_anchor.target_address)?,
+            "",
+        )?,
+    };
+
+    // NOTE: same as with courses and sections, we're only deleting an anchor and leave Comment entry as is.
+    // Replies to this comment stay linked to it's anchor so the thread isn't lost
+    hdk::remove_entry(&comment_anchor_address)
+}
@@ -0,0 +1,202 @@
+use hdk::holochain_core_types::chain_header::ChainHeader;
+use hdk::{LinkValidationData, ValidationData};
+use holochain_entry_utils::HolochainEntry;
+
+use super::{
+    anchor::CommentAnchor,
+    entry::{Comment, MAX_TEXT_LEN},
+    handlers,
+};
+use crate::error::ZomeError;
+use crate::helper;
+
+fn validate_text(text: &str) -> Result<(), String> {
+    if text.len() > MAX_TEXT_LEN {
+        return Err(ZomeError::text_too_long("comment", "text", MAX_TEXT_LEN).into());
+    }
+    Ok(())
+}
+
+pub fn create(entry: Comment, validation_data: ValidationData) -> Result<(), String> {
+    helper::validate_only_author_can_do(
+        &entry.author_address,
+        validation_data.sources(),
+        &Comment::entry_type(),
+        "create",
+    )?;
+    validate_text(&entry.text)?;
+    if entry.hidden {
+        return Err(ZomeError::invalid_input("Comment can't be hidden when it's created").into());
+    }
+    let anchor: CommentAnchor = hdk::utils::get_as_type(entry.anchor_address.clone())?;
+    if anchor.author_address != entry.author_address
+        || anchor.teacher_address != entry.teacher_address
+    {
+        return Err(ZomeError::invalid_input(
+            "Comment has to have the same author and teacher as it's CommentAnchor",
+        )
+        .into());
+    }
+    Ok(())
+}
+
+pub fn modify(
+    new_entry: Comment,
+    old_entry: Comment,
+    _old_entry_header: ChainHeader,
+    validation_data: ValidationData,
+) -> Result<(), String> {
+    let sources = validation_data.sources();
+    // author can edit the text of their comment and teacher can hide it (or show it again)
+    // and nobody else can do anything with it
+    if !sources.contains(&old_entry.author_address) && !sources.contains(&old_entry.teacher_address)
+    {
+        return Err(ZomeError::forbidden(
+            "Only the author of the comment or the teacher of the course can modify it",
+        )
+        .into());
+    }
+    if new_entry.text != old_entry.text {
+        helper::validate_only_author_can_do(
+            &old_entry.author_address,
+            sources.clone(),
+            &Comment::entry_type(),
+            "edit",
+        )?;
+    }
+    if new_entry.hidden != old_entry.hidden {
+        helper::validate_only_teacher_can_do(&old_entry.teacher_address, sources, "hide comments")?;
+    }
+    if new_entry.anchor_address != old_entry.anchor_address {
+        return Err(ZomeError::immutable_field("comment", "anchor").into());
+    }
+    if new_entry.author_address != old_entry.author_address {
+        return Err(ZomeError::immutable_field("comment", "author").into());
+    }
+    validate_text(&new_entry.text)?;
+    helper::validate_no_teacher_change(
+        old_entry.teacher_address,
+        new_entry.teacher_address,
+        &Comment::entry_type(),
+    )
+}
+
+pub fn delete(
+    entry: Comment,
+    _entry_header: ChainHeader,
+    validation_data: ValidationData,
+) -> Result<(), String> {
+    helper::validate_only_author_can_do(
+        &entry.author_address,
+        validation_data.sources(),
+        &Comment::entry_type(),
+        "delete",
+    )
+}
+
+// =========================== CommentAnchor validation
+pub fn anchor_create(entry: CommentAnchor, validation_data: ValidationData) -> Result<(), String> {
+    helper::validate_only_author_can_do(
+        &entry.author_address,
+        validation_data.sources(),
+        &Comment::entry_type(),
+        "create",
+    )?;
+    // make sure that course_address and teacher_address are the ones of the course that is discussed
+    let section_anchor = handlers::get_target_section_anchor(&entry.target_address)?;
+    if section_anchor.course_address != entry.course_address
+        || section_anchor.teacher_address != entry.teacher_address
+    {
+        return Err(ZomeError::invalid_input(
+            "Comment has to belong to the same course as the section or content it discusses",
+        )
+        .into());
+    }
+    if let Some(parent_address) = &entry.parent_address {
+        let parent: CommentAnchor = hdk::utils::get_as_type(parent_address.clone())?;
+        if parent.target_address != entry.target_address {
+            return Err(ZomeError::invalid_input(
+                "Reply has to discuss the same section or content as the comment it replies to",
+            )
+            .into());
+        }
+    }
+    helper::validate_only_course_member_can_do(
+        &entry.course_address,
+        &entry.teacher_address,
+        &entry.author_address,
+        "post comments",
+    )
+}
+
+// NOTE: we don't accept any parameters here because we don't need them to always return an error
+// because this anchor can never be modified
+pub fn anchor_modify() -> Result<(), String> {
+    Err(ZomeError::immutable_entry("comment_anchor").into())
+}
+
+pub fn anchor_delete(
+    entry: CommentAnchor,
+    _entry_header: ChainHeader,
+    validation_data: ValidationData,
+) -> Result<(), String> {
+    helper::validate_only_author_can_do(
+        &entry.author_address,
+        validation_data.sources(),
+        &Comment::entry_type(),
+        "delete",
+    )
+}
+
+//  =========================== CommentAnchor links validation
+pub fn anchor_to_comment_link(validation_data: LinkValidationData) -> Result<(), String> {
+    let (link, validation_data) = match validation_data {
+        hdk::LinkValidationData::LinkAdd {
+            link,
+            validation_data,
+        } => (link, validation_data),
+        hdk::LinkValidationData::LinkRemove {
+            link,
+            validation_data,
+        } => (link, validation_data),
+    };
+    // get author of this entry
+    let author = validation_data.package.chain_header.provenances()[0].source();
+    // get link base: entry from which the link goes
+    let base: CommentAnchor = hdk::utils::get_as_type(link.link.base().clone())?;
+    // both author (when editing) and teacher (when hiding) relink the anchor to the latest Comment
+    if author != base.author_address && author != base.teacher_address {
+        return Err(ZomeError::invalid_link("comment_anchor->comment", "Can't link CommentAnchor to Comment because you're neither the author of the comment nor the teacher of the course").into());
+    }
+    Ok(())
+}
+
+// used for all links that point to a CommentAnchor: only the author of the comment can manage them
+pub fn to_anchor_link(validation_data: LinkValidationData) -> Result<(), String> {
+    let (link, validation_data) = match validation_data {
+        hdk::LinkValidationData::LinkAdd {
+            link,
+            validation_data,
+        } => (link, validation_data),
+        hdk::LinkValidationData::LinkRemove {
+            link,
+            validation_data,
+        } => (link, validation_data),
+    };
+    let author = validation_data.package.chain_header.provenances()[0].source();
+    // get link target: entry to which the link goes
+    let target: CommentAnchor = hdk::utils::get_as_type(link.link.target().clone())?;
+    if author != target.author_address {
+        return Err(ZomeError::invalid_link("*->comment_anchor", "Can't link to CommentAnchor because your address isn't specified as author address for this comment").into());
+    }
+    // replies are linked from their parent comment and new threads are linked from the discussed entry
+    let expected_base = target.parent_address.unwrap_or(target.target_address);
+    if link.link.base() != &expected_base {
+        return Err(ZomeError::invalid_link(
+            "*->comment_anchor",
+            "Can't link to CommentAnchor from an entry that it doesn't belong to",
+        )
+        .into());
+    }
+    Ok(())
+}
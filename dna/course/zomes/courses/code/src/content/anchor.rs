@@ -0,0 +1,113 @@
+use super::{entry::Content, validation};
+use crate::anchor_trait::AnchorTrait;
+
+use hdk::prelude::*;
+use holochain_entry_utils::HolochainEntry;
+
+// NOTE: Content entries that were created before ContentAnchor was introduced are linked to and from the anchor
+// they were migrated to, so comments and progress that still point to them can be found from the anchor and vice versa
+pub const CONTENT_TO_CONTENT_ANCHOR_LINK: &str = "content->content_anchor";
+pub const CONTENT_ANCHOR_TO_LEGACY_CONTENT_LINK: &str = "content_anchor->legacy_content";
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct ContentAnchor {
+    // NOTE: these fields are here to ensure the uniqueness of every particular anchor
+    //  and wouldn't be used to display data about content to a user
+    pub name: String,
+    pub section_anchor_address: Address,
+    pub timestamp: u64,
+    // position of the content in it's section when it was created. It orders contents that were created
+    // in the same second and keeps their anchors apart.
+    // NOTE: anchors that were created before it was introduced don't have this field
+    #[serde(default)]
+    pub ordinal: u64,
+    // NOTE: same as in SectionAnchor, we're storing teacher_address here to validate deletion of this anchor
+    // without retrieving anything from DHT
+    pub teacher_address: Address,
+}
+
+impl AnchorTrait for ContentAnchor {
+    fn entry_type() -> String {
+        String::from("content_anchor")
+    }
+    fn link_to() -> String {
+        Content::entry_type()
+    }
+    fn link_type() -> String {
+        "content_anchor->content".to_string()
+    }
+}
+
+impl ContentAnchor {
+    pub fn new(
+        name: String,
+        section_anchor_address: Address,
+        timestamp: u64,
+        ordinal: u64,
+        teacher_address: Address,
+    ) -> Self {
+        ContentAnchor {
+            name,
+            section_anchor_address,
+            timestamp,
+            ordinal,
+            teacher_address,
+        }
+    }
+}
+
+pub fn content_anchor_def() -> ValidatingEntryType {
+    entry!(
+        name: ContentAnchor::entry_type(),
+        description: "Anchor to the valid section content",
+        sharing: Sharing::Public,
+        validation_package: || {
+            hdk::ValidationPackageDefinition::Entry
+        },
+        validation: | validation_data: hdk::EntryValidationData<ContentAnchor>| {
+            match validation_data{
+                EntryValidationData::Create { entry, validation_data } => {
+                    validation::anchor_create(entry, validation_data)
+                 },
+                 EntryValidationData::Modify { .. } => {
+                    validation::anchor_modify()
+                 },
+                 EntryValidationData::Delete { old_entry, old_entry_header, validation_data } => {
+                    validation::anchor_delete(old_entry, old_entry_header, validation_data)
+                 }
+            }
+        },
+        links:[
+            to!(
+                ContentAnchor::link_to(),
+                link_type: ContentAnchor::link_type(),
+                validation_package:||{
+                    hdk::ValidationPackageDefinition::Entry
+                },
+                validation:|validation_data: hdk::LinkValidationData|{
+                   validation::anchor_to_content_link(validation_data)
+                }
+            ),
+            to!(
+                Content::entry_type(),
+                link_type: CONTENT_ANCHOR_TO_LEGACY_CONTENT_LINK,
+                validation_package:||{
+                    hdk::ValidationPackageDefinition::Entry
+                },
+                validation:|validation_data: hdk::LinkValidationData|{
+                   validation::legacy_content_link(validation_data)
+                }
+            ),
+            from!(
+                Content::entry_type(),
+                link_type: CONTENT_TO_CONTENT_ANCHOR_LINK,
+                validation_package:||{
+                    hdk::ValidationPackageDefinition::Entry
+                },
+                validation:|validation_data: hdk::LinkValidationData|{
+                   validation::legacy_content_link(validation_data)
+                }
+            )
+        ]
+    )
+}
@@ -1,12 +1,73 @@
 use hdk::error::ZomeApiResult;
 use hdk::holochain_persistence_api::cas::content::Address;
 use hdk::prelude::LinkMatch;
-use hdk::prelude::*;
+use hdk::AGENT_ADDRESS;
 use holochain_entry_utils::HolochainEntry;
 
+use super::anchor::{
+    ContentAnchor, CONTENT_ANCHOR_TO_LEGACY_CONTENT_LINK, CONTENT_TO_CONTENT_ANCHOR_LINK,
+};
 use super::entry::Content;
+use crate::anchor_trait::AnchorTrait;
+use crate::course;
+use crate::error::ZomeError;
+use crate::helper;
+use crate::release;
 use crate::section;
-use crate::section::anchor::SECTION_TO_CONTENT_LINK;
+use crate::section::anchor::SectionAnchor;
+use crate::section::anchor::{SECTION_TO_CONTENT_ANCHOR_LINK, SECTION_TO_CONTENT_LINK};
+use crate::signal::{self, ChangeKind, ChangeNotice};
+
+// commits ContentAnchor and Content entries, links them together and links the anchor to it's section.
+// This is for callers that already know the section exists, like batch::handlers::apply.
+// ordinal is the position of the content in the section, see ContentAnchor::ordinal
+pub fn commit_new_content(content: Content, ordinal: u64) -> ZomeApiResult<Address> {
+    let content_anchor = ContentAnchor::new(
+        content.name.clone(),
+        content.section_anchor_address.clone(),
+        content.timestamp,
+        ordinal,
+        content.teacher_address.clone(),
+    );
+    // check before committing anything, otherwise both contents would be linked to the same anchor
+    validate_anchor_is_new(&content_anchor)?;
+    link_content_to_anchor(content_anchor, &hdk::commit_entry(&content.entry())?)
+}
+
+// returns an error if the same ContentAnchor was already committed: ContentAnchor address depends only on it's fields
+fn validate_anchor_is_new(content_anchor: &ContentAnchor) -> ZomeApiResult<()> {
+    if hdk::get_entry(&content_anchor.address()?)?.is_some() {
+        return Err(ZomeError::already_exists(
+            "content",
+            "Content with this name was already created in this section at the same time",
+        )
+        .into());
+    }
+    Ok(())
+}
+
+// commits ContentAnchor, links it to the already committed Content entry at content_address
+// and links the section to the anchor
+fn link_content_to_anchor(
+    content_anchor: ContentAnchor,
+    content_address: &Address,
+) -> ZomeApiResult<Address> {
+    let section_anchor_address = content_anchor.section_anchor_address.clone();
+    let content_anchor_address = hdk::commit_entry(&content_anchor.entry())?;
+    hdk::link_entries(
+        &content_anchor_address,
+        content_address,
+        ContentAnchor::link_type(),
+        "".to_owned(),
+    )?;
+    hdk::link_entries(
+        &section_anchor_address,
+        &content_anchor_address,
+        SECTION_TO_CONTENT_ANCHOR_LINK,
+        "",
+    )?;
+    Ok(content_anchor_address)
+}
 
 pub fn create(
     name: String,
@@ -24,80 +85,292 @@ pub fn create(
                 url,
                 timestamp,
                 description,
-                current_section.teacher_address,
+                current_section.teacher_address.clone(),
             );
-            let new_content_address = hdk::commit_entry(&new_content.entry())?;
-            hdk::link_entries(
-                &section_anchor_address,
-                &new_content_address,
-                SECTION_TO_CONTENT_LINK,
-                "",
-            )?;
+            let ordinal = get_contents(&section_anchor_address)?.len() as u64;
+            let content_anchor_address = commit_new_content(new_content, ordinal)?;
+
+            signal::notify(ChangeNotice::content(
+                ChangeKind::Created,
+                current_section.course_address,
+                section_anchor_address,
+                content_anchor_address.clone(),
+            ))?;
 
-            Ok(new_content_address)
+            // ContentAnchor serves as this content's ID so we return it
+            Ok(content_anchor_address)
         }
         None => {
-            return Err(ZomeApiError::from(
-                "Can't create a content in deleted section".to_owned(),
-            ));
+            return Err(ZomeError::deleted("section", "create a content in").into());
         }
     }
 }
 
+// returns addresses of ContentAnchor entries of all contents in the section
 pub fn get_contents(section_anchor_address: &Address) -> ZomeApiResult<Vec<Address>> {
     let links = hdk::get_links(
         &section_anchor_address,
-        LinkMatch::Exactly(SECTION_TO_CONTENT_LINK),
+        LinkMatch::Exactly(SECTION_TO_CONTENT_ANCHOR_LINK),
         LinkMatch::Any,
     )?;
 
     Ok(links.addresses())
 }
 
-pub fn update(
-    content_address: Address,
-    name: String,
-    url: String,
-    description: String,
-    timestamp: u64,
+// returns addresses of ContentAnchor entries of the section if it's available to the current agent
+// or an error that tells when it's going to be released
+pub fn get_released_contents(section_anchor_address: &Address) -> ZomeApiResult<Vec<Address>> {
+    release::handlers::validate_section_unlocked(section_anchor_address)?;
+    get_contents(section_anchor_address)
+}
+
+// returns latest versions of contents of the section with addresses of their ContentAnchor entries,
+// in the order they were created. Contents that were deleted are skipped
+pub fn get_ordered_contents(
+    section_anchor_address: &Address,
+) -> ZomeApiResult<Vec<(Content, Address)>> {
+    let mut contents = vec![];
+    for content_anchor_address in get_contents(section_anchor_address)? {
+        if let Some((content, _content_address)) = get_latest_content(&content_anchor_address)? {
+            let content_anchor: ContentAnchor =
+                hdk::utils::get_as_type(content_anchor_address.clone())?;
+            contents.push((
+                (content_anchor.timestamp, content_anchor.ordinal),
+                content,
+                content_anchor_address,
+            ));
+        }
+    }
+    // links don't have any particular order so we're restoring the order in which contents were created.
+    // NOTE: Content timestamp changes with every update, so we're using the one from ContentAnchor
+    contents.sort_by_key(|(created_at, _content, _content_anchor_address)| *created_at);
+    Ok(contents
+        .into_iter()
+        .map(|(_created_at, content, content_anchor_address)| (content, content_anchor_address))
+        .collect())
+}
+
+pub fn get_latest_content(
+    content_anchor_address: &Address,
+) -> ZomeApiResult<Option<(Content, Address)>> {
+    helper::get_latest_data_entry::<Content>(content_anchor_address, &ContentAnchor::link_type())
+}
+
+pub fn get_latest_content_entry(content_anchor_address: Address) -> ZomeApiResult<Option<Content>> {
+    let latest_content_result = get_latest_content(&content_anchor_address)?;
+    match latest_content_result {
+        Some((content_entry, _content_entry_address)) => Ok(Some(content_entry)),
+        None => Ok(None),
+    }
+}
+
+// commits updated content and moves link from ContentAnchor to it's new version
+pub fn commit_update(
+    content: Content,
+    previous_content_address: &Address,
+    content_anchor_address: &Address,
 ) -> ZomeApiResult<Address> {
-    let mut content: Content = hdk::utils::get_as_type(content_address.clone())?;
-    content.description = description;
-    content.name = name;
-    content.url = url;
-    content.timestamp = timestamp;
     // commit updates to the content entry and get it's new address
-    let updated_content_address = hdk::update_entry(content.clone().entry(), &content_address)?;
+    let updated_content_address = hdk::update_entry(content.entry(), previous_content_address)?;
 
     // remove link to previous version of content
     hdk::remove_link(
-        &content.section_anchor_address,
-        &content_address,
-        SECTION_TO_CONTENT_LINK,
-        "",
+        content_anchor_address,
+        previous_content_address,
+        ContentAnchor::link_type(),
+        "".to_owned(),
     )?;
 
     // create link to the updated version of content
     hdk::link_entries(
-        &content.section_anchor_address,
+        content_anchor_address,
         &updated_content_address,
-        SECTION_TO_CONTENT_LINK,
-        "",
+        ContentAnchor::link_type(),
+        "".to_owned(),
     )?;
 
-    // return address of the updated content entry
     Ok(updated_content_address)
 }
 
-pub fn delete(content_address: Address) -> ZomeApiResult<Address> {
-    let content: Content = hdk::utils::get_as_type(content_address.clone())?;
+// returns an error if the section of the content or the course of this section was deleted:
+// content of deleted sections and courses can't be changed anymore
+pub fn validate_section_not_deleted(
+    section_anchor_address: &Address,
+    action: &str,
+) -> ZomeApiResult<()> {
+    match section::handlers::get_latest_section(section_anchor_address)? {
+        Some((section, _section_address)) => {
+            match course::handlers::get_latest_course(&section.course_address)? {
+                Some(_) => Ok(()),
+                None => Err(ZomeError::deleted("course", action).into()),
+            }
+        }
+        None => Err(ZomeError::deleted("section", action).into()),
+    }
+}
+
+pub fn update(
+    content_anchor_address: Address,
+    name: String,
+    url: String,
+    description: String,
+    timestamp: u64,
+) -> ZomeApiResult<Address> {
+    let latest_content_result = get_latest_content(&content_anchor_address)?;
+    match latest_content_result {
+        Some((mut content, previous_content_address)) => {
+            validate_section_not_deleted(&content.section_anchor_address, "update content in")?;
+            content.description = description;
+            content.name = name;
+            content.url = url;
+            content.timestamp = timestamp;
+            commit_update(
+                content.clone(),
+                &previous_content_address,
+                &content_anchor_address,
+            )?;
+
+            notify_content_changed(
+                ChangeKind::Updated,
+                content.section_anchor_address,
+                content_anchor_address.clone(),
+            )?;
 
+            // content keeps it's ID across updates so we return the same anchor address
+            Ok(content_anchor_address)
+        }
+        None => Err(ZomeError::deleted("content", "update").into()),
+    }
+}
+
+// unlinks content from it's section and deletes it's anchor.
+// NOTE: same as with sections, we're only deleting the anchor and leave Content entries as they are
+pub fn remove(content: &Content, content_anchor_address: &Address) -> ZomeApiResult<Address> {
     hdk::remove_link(
         &content.section_anchor_address,
-        &content_address,
-        SECTION_TO_CONTENT_LINK,
+        content_anchor_address,
+        SECTION_TO_CONTENT_ANCHOR_LINK,
         "",
     )?;
 
-    hdk::remove_entry(&content_address)
+    hdk::remove_entry(content_anchor_address)
+}
+
+pub fn delete(content_anchor_address: Address) -> ZomeApiResult<Address> {
+    let latest_content_result = get_latest_content(&content_anchor_address)?;
+    match latest_content_result {
+        Some((content, _content_address)) => {
+            validate_section_not_deleted(&content.section_anchor_address, "delete content from")?;
+
+            let result = remove(&content, &content_anchor_address)?;
+
+            notify_content_changed(
+                ChangeKind::Deleted,
+                content.section_anchor_address,
+                content_anchor_address,
+            )?;
+            Ok(result)
+        }
+        None => Err(ZomeError::deleted("content", "delete").into()),
+    }
+}
+
+// replaces links from sections of the course to Content entries, that were created before ContentAnchor was
+// introduced, with links to new ContentAnchor entries. Returns addresses of created anchors
+pub fn migrate_contents(
+    course_anchor_address: &Address,
+    timestamp: u64,
+) -> ZomeApiResult<Vec<Address>> {
+    let course = match course::handlers::get_latest_course(course_anchor_address)? {
+        Some((course, _course_address)) => course,
+        None => return Err(ZomeError::deleted("course", "migrate contents of").into()),
+    };
+    if &course.teacher_address != *AGENT_ADDRESS {
+        return Err(ZomeError::not_teacher("migrate contents of this course").into());
+    }
+
+    // anchors have to be timestamped close to the current time to pass validation,
+    // so it's index of the content that keeps anchors of contents with the same name apart
+    let mut content_anchor_addresses = Vec::new();
+    for section_anchor_address in course.sections {
+        let content_addresses = hdk::get_links(
+            &section_anchor_address,
+            LinkMatch::Exactly(SECTION_TO_CONTENT_LINK),
+            LinkMatch::Any,
+        )?
+        .addresses();
+        for (content_index, content_address) in content_addresses.into_iter().enumerate() {
+            let content: Content = hdk::utils::get_as_type(content_address.clone())?;
+            let content_anchor = ContentAnchor::new(
+                content.name,
+                section_anchor_address.clone(),
+                timestamp,
+                content_index as u64,
+                content.teacher_address,
+            );
+            validate_anchor_is_new(&content_anchor)?;
+            let content_anchor_address = link_content_to_anchor(content_anchor, &content_address)?;
+            // comments and completions of students still point to the Content entry,
+            // so we're linking it with it's anchor both ways to find them
+            hdk::link_entries(
+                &content_address,
+                &content_anchor_address,
+                CONTENT_TO_CONTENT_ANCHOR_LINK,
+                "",
+            )?;
+            hdk::link_entries(
+                &content_anchor_address,
+                &content_address,
+                CONTENT_ANCHOR_TO_LEGACY_CONTENT_LINK,
+                "",
+            )?;
+            content_anchor_addresses.push(content_anchor_address);
+            hdk::remove_link(
+                &section_anchor_address,
+                &content_address,
+                SECTION_TO_CONTENT_LINK,
+                "",
+            )?;
+        }
+    }
+    Ok(content_anchor_addresses)
+}
+
+// returns address of the ContentAnchor that the Content entry created before ContentAnchor was introduced
+// was migrated to, or None if it wasn't migrated
+pub fn get_migrated_anchor(content_address: &Address) -> ZomeApiResult<Option<Address>> {
+    let links = hdk::get_links(
+        content_address,
+        LinkMatch::Exactly(CONTENT_TO_CONTENT_ANCHOR_LINK),
+        LinkMatch::Any,
+    )?;
+
+    Ok(links.addresses().into_iter().next())
+}
+
+// returns addresses of Content entries created before ContentAnchor was introduced that were migrated to this anchor
+pub fn get_legacy_contents(content_anchor_address: &Address) -> ZomeApiResult<Vec<Address>> {
+    let links = hdk::get_links(
+        content_anchor_address,
+        LinkMatch::Exactly(CONTENT_ANCHOR_TO_LEGACY_CONTENT_LINK),
+        LinkMatch::Any,
+    )?;
+
+    Ok(links.addresses())
+}
+
+// NOTE: content only stores the address of it's section, so we're using SectionAnchor to find the course
+// that the change belongs to
+fn notify_content_changed(
+    kind: ChangeKind,
+    section_anchor_address: Address,
+    content_anchor_address: Address,
+) -> ZomeApiResult<()> {
+    let section_anchor: SectionAnchor = hdk::utils::get_as_type(section_anchor_address.clone())?;
+    signal::notify(ChangeNotice::content(
+        kind,
+        section_anchor.course_address,
+        section_anchor_address,
+        content_anchor_address,
+    ))
 }
@@ -1,15 +1,19 @@
-use super::entry::Content;
+use super::{
+    anchor::{ContentAnchor, CONTENT_TO_CONTENT_ANCHOR_LINK},
+    entry::Content,
+};
+use crate::anchor_trait::AnchorTrait;
+use crate::error::ZomeError;
 use crate::helper;
+use courses_core::content as rules;
 use hdk::holochain_core_types::chain_header::ChainHeader;
-use hdk::ValidationData;
+use hdk::{LinkValidationData, ValidationData};
 use holochain_entry_utils::HolochainEntry;
 
 pub fn create(entry: Content, validation_data: ValidationData) -> Result<(), String> {
-    helper::validate_only_teacher_can_do(
-        &entry.teacher_address,
-        validation_data.sources(),
-        "create content in the section of this course",
-    )
+    rules::validate_create(&entry.teacher_address, &validation_data.sources())
+        .map_err(helper::rule_error)?;
+    helper::validate_timestamp(entry.timestamp, &validation_data, &Content::entry_type())
 }
 
 pub fn modify(
@@ -18,14 +22,18 @@ pub fn modify(
     _old_entry_header: ChainHeader,
     validation_data: ValidationData,
 ) -> Result<(), String> {
-    helper::validate_only_teacher_can_do(
+    rules::validate_modify(
         &old_entry.teacher_address,
-        validation_data.sources(),
-        "modify content in the section of this course",
-    )?;
-    helper::validate_no_teacher_change(
-        old_entry.teacher_address,
-        new_entry.teacher_address,
+        &new_entry.teacher_address,
+        &validation_data.sources(),
+        &old_entry.section_anchor_address,
+        &new_entry.section_anchor_address,
+    )
+    .map_err(helper::rule_error)?;
+    helper::validate_timestamp_update(
+        old_entry.timestamp,
+        new_entry.timestamp,
+        &validation_data,
         &Content::entry_type(),
     )
 }
@@ -35,9 +43,109 @@ pub fn delete(
     _entry_header: ChainHeader,
     validation_data: ValidationData,
 ) -> Result<(), String> {
-    helper::validate_only_teacher_can_do(
-        &entry.teacher_address,
-        validation_data.sources(),
-        "delete content in the section of this course",
+    rules::validate_delete(&entry.teacher_address, &validation_data.sources())
+        .map_err(helper::rule_error)
+}
+
+// =========================== ContentAnchor validation
+pub fn anchor_create(entry: ContentAnchor, validation_data: ValidationData) -> Result<(), String> {
+    rules::validate_create(&entry.teacher_address, &validation_data.sources())
+        .map_err(helper::rule_error)?;
+    helper::validate_timestamp(
+        entry.timestamp,
+        &validation_data,
+        &ContentAnchor::entry_type(),
     )
 }
+
+// NOTE: we don't accept any parameters here because we don't need them to always return an error
+// because this anchor can never be modified
+pub fn anchor_modify() -> Result<(), String> {
+    Err(ZomeError::immutable_entry("content_anchor").into())
+}
+
+pub fn anchor_delete(
+    entry: ContentAnchor,
+    _entry_header: ChainHeader,
+    validation_data: ValidationData,
+) -> Result<(), String> {
+    rules::validate_delete(&entry.teacher_address, &validation_data.sources())
+        .map_err(helper::rule_error)
+}
+
+//  =========================== ContentAnchor links validation
+pub fn anchor_to_content_link(validation_data: LinkValidationData) -> Result<(), String> {
+    match validation_data {
+        hdk::LinkValidationData::LinkAdd {
+            link,
+            validation_data,
+        } => {
+            let author = validation_data.package.chain_header.provenances()[0].source();
+            let base: ContentAnchor = hdk::utils::get_as_type(link.link.base().clone())?;
+            let target: Content = hdk::utils::get_as_type(link.link.target().clone())?;
+            if author != base.teacher_address {
+                return Err(ZomeError::invalid_link("content_anchor->content", "Can't link ContentAnchor to Content because your address isn't specified as teacher address for this course").into());
+            }
+            if base.teacher_address != target.teacher_address {
+                return Err(ZomeError::invalid_link("content_anchor->content", "Can't link ContentAnchor to Content because their teacher addresses are different").into());
+            }
+            if base.section_anchor_address != target.section_anchor_address {
+                return Err(ZomeError::invalid_link(
+                    "content_anchor->content",
+                    "Can't link ContentAnchor to Content because they belong to different sections",
+                )
+                .into());
+            }
+            Ok(())
+        }
+        hdk::LinkValidationData::LinkRemove {
+            link,
+            validation_data,
+        } => {
+            let author = validation_data.package.chain_header.provenances()[0].source();
+            let base: ContentAnchor = hdk::utils::get_as_type(link.link.base().clone())?;
+            if author != base.teacher_address {
+                return Err(ZomeError::invalid_link("content_anchor->content", "Can't remove link from ContentAnchor to Content because your address isn't specified as teacher_address for this course").into());
+            }
+            Ok(())
+        }
+    }
+}
+
+// used for links in both directions between ContentAnchor and the legacy Content entry that was migrated to it
+pub fn legacy_content_link(validation_data: LinkValidationData) -> Result<(), String> {
+    let (link, validation_data) = match validation_data {
+        hdk::LinkValidationData::LinkAdd {
+            link,
+            validation_data,
+        } => (link, validation_data),
+        hdk::LinkValidationData::LinkRemove {
+            link,
+            validation_data,
+        } => (link, validation_data),
+    };
+    let author = validation_data.package.chain_header.provenances()[0].source();
+    let link_type = link.link.link_type().to_owned();
+    let (anchor_address, content_address) = if link_type == CONTENT_TO_CONTENT_ANCHOR_LINK {
+        (link.link.target(), link.link.base())
+    } else {
+        (link.link.base(), link.link.target())
+    };
+    let anchor: ContentAnchor = hdk::utils::get_as_type(anchor_address.clone())?;
+    let content: Content = hdk::utils::get_as_type(content_address.clone())?;
+    if author != anchor.teacher_address {
+        return Err(ZomeError::invalid_link(
+            &link_type,
+            "Only the teacher of the course can link contents to their anchors",
+        )
+        .into());
+    }
+    if anchor.section_anchor_address != content.section_anchor_address {
+        return Err(ZomeError::invalid_link(
+            &link_type,
+            "Can't link ContentAnchor to Content because they belong to different sections",
+        )
+        .into());
+    }
+    Ok(())
+}
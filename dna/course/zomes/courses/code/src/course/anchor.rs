@@ -8,6 +8,8 @@ use crate::anchor_trait::AnchorTrait;
 pub const TEACHER_TO_COURSE_ANCHOR_LINK: &str = "teacher->course_anchor";
 pub const STUDENT_TO_COURSE_ANCHOR_LINK: &str = "student->course_anchor";
 pub const COURSE_ANCHOR_TO_STUDENT_LINK: &str = "course_anchor->student";
+pub const COURSE_ANCHOR_TO_FORK_LINK: &str = "course_anchor->fork";
+pub const FORK_TO_ORIGIN_COURSE_ANCHOR_LINK: &str = "forked_from";
 
 #[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
 pub struct CourseAnchor {
@@ -16,6 +18,10 @@ pub struct CourseAnchor {
     pub title: String,
     pub teacher_address: Address,
     pub timestamp: u64,
+    // teacher grant of the agent who created the course. None if roles aren't enforced in this DNA.
+    // NOTE: courses that were created before roles were introduced don't have this field
+    #[serde(default)]
+    pub role_grant_address: Option<Address>,
 }
 
 impl AnchorTrait for CourseAnchor {
@@ -31,11 +37,17 @@ impl AnchorTrait for CourseAnchor {
 }
 
 impl CourseAnchor {
-    pub fn new(title: String, teacher_address: Address, timestamp: u64) -> Self {
+    pub fn new(
+        title: String,
+        teacher_address: Address,
+        timestamp: u64,
+        role_grant_address: Option<Address>,
+    ) -> Self {
         CourseAnchor {
             title: title,
             teacher_address: teacher_address,
             timestamp: timestamp,
+            role_grant_address,
         }
     }
 }
@@ -111,6 +123,30 @@ pub fn course_anchor_def() -> ValidatingEntryType {
                 validation: | _validation_data: hdk::LinkValidationData | {
                     Ok(())
                 }
+            ),
+            // link to a course that was forked from this course
+            // This is for teacher to see who's reusing their course
+            to!(
+                CourseAnchor::entry_type(),
+                link_type: COURSE_ANCHOR_TO_FORK_LINK,
+                validation_package: || {
+                    hdk::ValidationPackageDefinition::Entry
+                },
+                validation: | validation_data: hdk::LinkValidationData | {
+                    validation::anchor_to_fork_link(validation_data)
+                }
+            ),
+            // link from the fork to the course it was forked from
+            // This is to give attribution to the original course
+            to!(
+                CourseAnchor::entry_type(),
+                link_type: FORK_TO_ORIGIN_COURSE_ANCHOR_LINK,
+                validation_package: || {
+                    hdk::ValidationPackageDefinition::Entry
+                },
+                validation: | validation_data: hdk::LinkValidationData | {
+                    validation::fork_to_origin_link(validation_data)
+                }
             )
         ]
     )
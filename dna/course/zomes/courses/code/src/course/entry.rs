@@ -7,7 +7,17 @@ use hdk::{
 };
 use holochain_entry_utils::HolochainEntry;
 
-pub const MAX_TITLE_LEN: usize = 50;
+pub use courses_core::course::MAX_TITLE_LEN;
+
+// Rule that releases sections of the course to every student separately
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum DripRule {
+    // every section unlocks days after the student enrolled in the course
+    DaysAfterEnrolment { days: u64 },
+    // every section unlocks when the student completes all content of the previous section
+    AfterPreviousSection,
+}
 
 #[derive(Serialize, Deserialize, Debug, DefaultJson, Clone)]
 pub struct Course {
@@ -16,6 +26,13 @@ pub struct Course {
     pub teacher_address: Address,
     pub timestamp: u64,
     pub anchor_address: Address,
+    // None means all sections are available to students as soon as they're released.
+    // NOTE: courses that were created before drip rules were introduced don't have this field
+    #[serde(default)]
+    pub drip_rule: Option<DripRule>,
+    // same as CourseAnchor::role_grant_address
+    #[serde(default)]
+    pub role_grant_address: Option<Address>,
 }
 
 impl HolochainEntry for Course {
@@ -31,6 +48,7 @@ impl Course {
         teacher_address: Address,
         timestamp: u64,
         anchor_address: Address,
+        role_grant_address: Option<Address>,
     ) -> Self {
         Course {
             title: title,
@@ -38,6 +56,8 @@ impl Course {
             teacher_address: teacher_address,
             timestamp: timestamp,
             anchor_address: anchor_address,
+            drip_rule: None,
+            role_grant_address,
         }
     }
 }
@@ -1,19 +1,56 @@
 use hdk::holochain_persistence_api::cas::content::Address;
 use hdk::prelude::LinkMatch;
-use hdk::{
-    error::{ZomeApiError, ZomeApiResult},
-    AGENT_ADDRESS,
-};
+use hdk::prelude::*;
+use hdk::{error::ZomeApiResult, AGENT_ADDRESS};
 use holochain_entry_utils::HolochainEntry;
 
 use super::anchor::{
-    CourseAnchor, COURSE_ANCHOR_TO_STUDENT_LINK, STUDENT_TO_COURSE_ANCHOR_LINK,
+    CourseAnchor, COURSE_ANCHOR_TO_FORK_LINK, COURSE_ANCHOR_TO_STUDENT_LINK,
+    FORK_TO_ORIGIN_COURSE_ANCHOR_LINK, STUDENT_TO_COURSE_ANCHOR_LINK,
     TEACHER_TO_COURSE_ANCHOR_LINK,
 };
 use super::catalog_anchor::CourseCatalogAnchor;
-use super::entry::Course;
+use super::entry::{Course, DripRule};
 use crate::anchor_trait::AnchorTrait;
+use crate::content;
+use crate::content::entry::Content;
+use crate::error::ZomeError;
 use crate::helper;
+use crate::release;
+use crate::release::handlers::{SectionLock, SectionLocks};
+use crate::role;
+use crate::role::entry::Role;
+use crate::section;
+use crate::section::entry::Section;
+use crate::signal::{self, ChangeKind, ChangeNotice};
+
+// Latest version of the course with all of it's sections and their contents resolved.
+// This is for UI to render the whole course page in a single call
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct CourseTree {
+    pub course_anchor_address: Address,
+    pub course: Course,
+    // sections in the same order as they are listed in the course
+    pub sections: Vec<SectionTree>,
+}
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct SectionTree {
+    pub section_anchor_address: Address,
+    pub section: Section,
+    // why the section isn't available to the current agent yet. Locked sections have no contents and subsections
+    pub lock: Option<SectionLock>,
+    // contents in the order they were created
+    pub contents: Vec<ContentWithAddress>,
+    // subsections in the same order as they are listed in the section
+    pub subsections: Vec<SectionTree>,
+}
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct ContentWithAddress {
+    pub content_anchor_address: Address,
+    pub content: Content,
+}
 
 pub fn create(title: String, timestamp: u64) -> ZomeApiResult<Address> {
     // if catalog anchor already exists, this function would just return it's address without actually writing anything
@@ -23,8 +60,16 @@ pub fn create(title: String, timestamp: u64) -> ZomeApiResult<Address> {
     // just a helper variable because we'll need this value a few times
     let teacher_address = AGENT_ADDRESS.clone();
 
+    // courses refer to the teacher grant they were created with, see role::validation::validate_has_role
+    let role_grant_address = role::handlers::get_my_role_grant(&Role::Teacher)?;
+
     // initialize CourseAnchor instance to represent this particular course
-    let course_anchor = CourseAnchor::new(title.clone(), teacher_address.clone(), timestamp);
+    let course_anchor = CourseAnchor::new(
+        title.clone(),
+        teacher_address.clone(),
+        timestamp,
+        role_grant_address.clone(),
+    );
     // commit CourseAnchor to DHT
     let course_anchor_address = hdk::commit_entry(&course_anchor.entry())?;
 
@@ -35,6 +80,7 @@ pub fn create(title: String, timestamp: u64) -> ZomeApiResult<Address> {
         teacher_address.to_owned().into(),
         timestamp,
         course_anchor_address.clone(),
+        role_grant_address,
     );
     // commit this entry to DHT and save it's address
     let new_course_address = hdk::commit_entry(&new_course.entry())?;
@@ -64,6 +110,11 @@ pub fn create(title: String, timestamp: u64) -> ZomeApiResult<Address> {
         "",
     )?;
 
+    signal::notify(ChangeNotice::course(
+        ChangeKind::Created,
+        course_anchor_address.clone(),
+    ))?;
+
     Ok(course_anchor_address)
 }
 
@@ -87,8 +138,8 @@ pub fn get_latest_course_entry(course_anchor_address: Address) -> ZomeApiResult<
     }
 }
 
-// NOTE: this function isn't public because it's only needed in the current module
-fn commit_update(
+// commits updated course and moves link from CourseAnchor to it's new version
+pub fn commit_update(
     course: Course,
     previous_course_address: &Address,
     course_anchor_address: &Address,
@@ -138,15 +189,46 @@ pub fn update(
                 course_anchor_address,
             )?;
 
+            signal::notify(ChangeNotice::course(
+                ChangeKind::Updated,
+                course_anchor_address.clone(),
+            ))?;
+
             // returning address of the course anchor. Sure, it doesn't change, but it makes our API consistent with hdk:: API
             // that always returns address of an updated entry
             return Ok(course_anchor_address.clone());
         }
         None => {
-            return Err(ZomeApiError::from(
-                "Can't update a deleted course".to_owned(),
-            ));
+            return Err(ZomeError::deleted("course", "update").into());
+        }
+    }
+}
+
+// sets the rule that releases sections of the course to students. None releases all of them right away
+pub fn set_drip_rule(
+    course_anchor_address: &Address,
+    drip_rule: Option<DripRule>,
+    timestamp: u64,
+) -> ZomeApiResult<Address> {
+    match get_latest_course(course_anchor_address)? {
+        Some((mut previous_course, previous_course_address)) => {
+            previous_course.drip_rule = drip_rule;
+            previous_course.timestamp = timestamp;
+
+            commit_update(
+                previous_course,
+                &previous_course_address,
+                course_anchor_address,
+            )?;
+
+            signal::notify(ChangeNotice::course(
+                ChangeKind::Updated,
+                course_anchor_address.clone(),
+            ))?;
+
+            Ok(course_anchor_address.clone())
         }
+        None => Err(ZomeError::deleted("course", "set drip rule of").into()),
     }
 }
 
@@ -165,9 +247,9 @@ pub fn delete(course_anchor_address: Address) -> ZomeApiResult<Address> {
     // retrieve list of students that have enrolled in this course
     let students = get_students(course_anchor_address.clone())?;
     // go through all students and remove their links to this course
-    for student in students {
+    for student in students.iter() {
         hdk::remove_link(
-            &student,
+            student,
             &course_anchor_address,
             STUDENT_TO_COURSE_ANCHOR_LINK,
             "",
@@ -187,7 +269,15 @@ pub fn delete(course_anchor_address: Address) -> ZomeApiResult<Address> {
     // reasons:
     // 1) without it, we won't be able to reach the Course because everywhere we link to course we only use anchor address
     // 2) we'll avoid polluting DHT by new deletion metadata
-    hdk::remove_entry(&course_anchor_address)
+    let result = hdk::remove_entry(&course_anchor_address)?;
+
+    // NOTE: links to students are already removed at this point, so notify_course_members can't deliver
+    // this notice to them. They'll find out that the course is deleted when they fail to get it
+    signal::notify(ChangeNotice::course(
+        ChangeKind::Deleted,
+        course_anchor_address,
+    ))?;
+    Ok(result)
 }
 
 pub fn list_all_courses() -> ZomeApiResult<Vec<Address>> {
@@ -242,12 +332,15 @@ pub fn enrol_in_course(course_anchor_address: Address) -> ZomeApiResult<Address>
         "",
     )?;
     // create a link that would allow course to list it's students
-    hdk::link_entries(
+    let link_address = hdk::link_entries(
         &course_anchor_address,
         &AGENT_ADDRESS,
         COURSE_ANCHOR_TO_STUDENT_LINK,
         "",
-    )
+    )?;
+    // remember when student enrolled for sections that are released days after enrolment
+    release::handlers::record_enrolment(&course_anchor_address)?;
+    Ok(link_address)
 }
 
 pub fn add_section(
@@ -273,9 +366,7 @@ pub fn add_section(
             Ok(course_anchor_address.clone())
         }
         None => {
-            return Err(ZomeApiError::from(
-                "Can't add section to a deleted course".to_owned(),
-            ));
+            return Err(ZomeError::deleted("course", "add section to").into());
         }
     }
 }
@@ -301,9 +392,218 @@ pub fn delete_section(
             Ok(course_anchor_address.clone())
         }
         None => {
-            return Err(ZomeApiError::from(
-                "Can't delete section from a deleted course".to_owned(),
-            ));
+            return Err(ZomeError::deleted("course", "delete section from").into());
+        }
+    }
+}
+
+// copies the latest version of the section with it's contents and subsections into the forked course
+fn fork_section(
+    section_anchor_address: &Address,
+    fork_anchor_address: &Address,
+    fork_parent_address: Option<&Address>,
+    timestamp: u64,
+) -> ZomeApiResult<()> {
+    // skip sections that were deleted but are still listed in the course
+    if let Some((section, _section_address)) =
+        section::handlers::get_latest_section(section_anchor_address)?
+    {
+        // NOTE: original timestamps can't be kept because they have to be close to the time of the commit.
+        // Every section and content gets the same timestamp and is kept apart and in order by it's ordinal
+        // which create handlers take from the number of sections and contents that were already copied
+        let fork_section_anchor_address = match fork_parent_address {
+            Some(parent_address) => {
+                section::handlers::create_subsection(section.title, parent_address, timestamp)?
+            }
+            None => section::handlers::create(section.title, fork_anchor_address, timestamp)?,
+        };
+        if section.release_at.is_some() {
+            section::handlers::set_release_time(
+                &fork_section_anchor_address,
+                section.release_at,
+                timestamp,
+            )?;
+        }
+        let contents = content::handlers::get_ordered_contents(section_anchor_address)?;
+        for (content, _content_anchor_address) in contents {
+            content::handlers::create(
+                content.name,
+                fork_section_anchor_address.clone(),
+                content.url,
+                timestamp,
+                content.description,
+            )?;
+        }
+        for subsection_anchor_address in section.subsections.iter() {
+            fork_section(
+                subsection_anchor_address,
+                fork_anchor_address,
+                Some(&fork_section_anchor_address),
+                timestamp,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+// copies the latest version of the course with all of it's sections and contents into a new course
+// that is taught by the current agent
+pub fn fork(
+    course_anchor_address: Address,
+    new_title: String,
+    timestamp: u64,
+) -> ZomeApiResult<Address> {
+    let course = match get_latest_course(&course_anchor_address)? {
+        Some((course, _course_address)) => course,
+        None => return Err(ZomeError::deleted("course", "fork").into()),
+    };
+
+    let fork_anchor_address = create(new_title, timestamp)?;
+    if course.drip_rule.is_some() {
+        set_drip_rule(&fork_anchor_address, course.drip_rule.clone(), timestamp)?;
+    }
+
+    // link both ways: to give attribution to the original course and to let it's teacher find all forks.
+    // NOTE: this goes before copying sections because commits aren't rolled back when the zome call fails,
+    // so even a partially copied fork is attributed to it's origin and can be found and deleted
+    hdk::link_entries(
+        &fork_anchor_address,
+        &course_anchor_address,
+        FORK_TO_ORIGIN_COURSE_ANCHOR_LINK,
+        "",
+    )?;
+    hdk::link_entries(
+        &course_anchor_address,
+        &fork_anchor_address,
+        COURSE_ANCHOR_TO_FORK_LINK,
+        "",
+    )?;
+
+    for section_anchor_address in course.sections.iter() {
+        fork_section(
+            section_anchor_address,
+            &fork_anchor_address,
+            None,
+            timestamp,
+        )?;
+    }
+
+    Ok(fork_anchor_address)
+}
+
+pub fn get_forks(course_anchor_address: Address) -> ZomeApiResult<Vec<Address>> {
+    let links = hdk::get_links(
+        &course_anchor_address,
+        LinkMatch::Exactly(COURSE_ANCHOR_TO_FORK_LINK),
+        LinkMatch::Any,
+    )?;
+
+    Ok(links.addresses())
+}
+
+// returns address of the course this course was forked from or None if it's an original course
+pub fn get_fork_origin(course_anchor_address: Address) -> ZomeApiResult<Option<Address>> {
+    let links = hdk::get_links(
+        &course_anchor_address,
+        LinkMatch::Exactly(FORK_TO_ORIGIN_COURSE_ANCHOR_LINK),
+        LinkMatch::Any,
+    )?;
+
+    Ok(links.addresses().into_iter().next())
+}
+
+// returns the latest version of the section with it's contents and the whole tree of it's subsections
+pub fn get_section_tree(section_anchor_address: &Address) -> ZomeApiResult<Option<SectionTree>> {
+    match section::handlers::get_latest_section(section_anchor_address)? {
+        Some((section, _section_address)) => {
+            let locks = SectionLocks::load(&section.course_address)?;
+            Ok(Some(build_section_tree(
+                section_anchor_address,
+                section,
+                &locks,
+            )?))
+        }
+        // section was deleted but is still listed in the course
+        None => Ok(None),
+    }
+}
+
+// same as get_section_tree but with locks that are shared by all sections of the course tree
+fn get_section_tree_with_locks(
+    section_anchor_address: &Address,
+    locks: &SectionLocks,
+) -> ZomeApiResult<Option<SectionTree>> {
+    match section::handlers::get_latest_section(section_anchor_address)? {
+        Some((section, _section_address)) => Ok(Some(build_section_tree(
+            section_anchor_address,
+            section,
+            locks,
+        )?)),
+        None => Ok(None),
+    }
+}
+
+fn build_section_tree(
+    section_anchor_address: &Address,
+    section: Section,
+    locks: &SectionLocks,
+) -> ZomeApiResult<SectionTree> {
+    let lock = locks.get(&section)?;
+    if lock.is_some() {
+        return Ok(SectionTree {
+            section_anchor_address: section_anchor_address.clone(),
+            section,
+            lock,
+            contents: vec![],
+            subsections: vec![],
+        });
+    }
+
+    let contents = content::handlers::get_ordered_contents(section_anchor_address)?
+        .into_iter()
+        .map(|(content, content_anchor_address)| ContentWithAddress {
+            content_anchor_address,
+            content,
+        })
+        .collect();
+
+    let mut subsections = vec![];
+    for subsection_anchor_address in section.subsections.iter() {
+        if let Some(subsection_tree) =
+            get_section_tree_with_locks(subsection_anchor_address, locks)?
+        {
+            subsections.push(subsection_tree);
+        }
+    }
+
+    Ok(SectionTree {
+        section_anchor_address: section_anchor_address.clone(),
+        section,
+        lock,
+        contents,
+        subsections,
+    })
+}
+
+pub fn get_course_tree(course_anchor_address: Address) -> ZomeApiResult<Option<CourseTree>> {
+    match get_latest_course(&course_anchor_address)? {
+        Some((course, _course_address)) => {
+            let locks = SectionLocks::load(&course_anchor_address)?;
+            let mut sections = vec![];
+            for section_anchor_address in course.sections.iter() {
+                if let Some(section_tree) =
+                    get_section_tree_with_locks(section_anchor_address, &locks)?
+                {
+                    sections.push(section_tree);
+                }
+            }
+
+            Ok(Some(CourseTree {
+                course_anchor_address,
+                course,
+                sections,
+            }))
         }
+        None => Ok(None),
     }
 }
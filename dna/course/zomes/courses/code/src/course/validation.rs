@@ -1,21 +1,34 @@
 use super::{
     anchor::CourseAnchor,
     catalog_anchor::CourseCatalogAnchor,
-    entry::{Course, MAX_TITLE_LEN},
+    entry::{Course, DripRule},
 };
 use crate::anchor_trait::AnchorTrait;
+use crate::error::ZomeError;
 use crate::helper;
+use crate::role::{entry::Role, validation::validate_has_role};
+use courses_core::course as rules;
+use courses_core::release;
 use hdk::holochain_core_types::chain_header::ChainHeader;
+use hdk::holochain_persistence_api::cas::content::Address;
 use hdk::{LinkValidationData, ValidationData};
 use holochain_entry_utils::HolochainEntry;
 
 pub fn create(entry: Course, validation_data: ValidationData) -> Result<(), String> {
-    helper::validate_only_teacher_can_do(
+    rules::validate_create(
         &entry.teacher_address,
-        validation_data.sources(),
-        "create their courses",
-    )?;
-    helper::validate_entity_title(&entry.title, &Course::entry_type(), MAX_TITLE_LEN)
+        &validation_data.sources(),
+        &entry.title,
+        &Course::entry_type(),
+    )
+    .map_err(helper::rule_error)?;
+    helper::validate_timestamp(entry.timestamp, &validation_data, &Course::entry_type())?;
+    validate_has_role(
+        &entry.teacher_address,
+        Role::Teacher,
+        &entry.role_grant_address,
+        &validation_data,
+    )
 }
 
 pub fn modify(
@@ -24,15 +37,21 @@ pub fn modify(
     _old_entry_header: ChainHeader,
     validation_data: ValidationData,
 ) -> Result<(), String> {
-    helper::validate_only_teacher_can_do(
+    rules::validate_modify(
         &old_entry.teacher_address,
-        validation_data.sources(),
-        "modify their courses",
-    )?;
-    helper::validate_entity_title(&new_entry.title, &Course::entry_type(), MAX_TITLE_LEN)?;
-    helper::validate_no_teacher_change(
-        old_entry.teacher_address,
-        new_entry.teacher_address,
+        &new_entry.teacher_address,
+        &validation_data.sources(),
+        &new_entry.title,
+        &Course::entry_type(),
+    )
+    .map_err(helper::rule_error)?;
+    if let Some(DripRule::DaysAfterEnrolment { days }) = new_entry.drip_rule {
+        release::validate_drip_days(days).map_err(helper::rule_error)?;
+    }
+    helper::validate_timestamp_update(
+        old_entry.timestamp,
+        new_entry.timestamp,
+        &validation_data,
         &Course::entry_type(),
     )
 }
@@ -42,29 +61,36 @@ pub fn delete(
     _entry_header: ChainHeader,
     validation_data: ValidationData,
 ) -> Result<(), String> {
-    helper::validate_only_teacher_can_do(
-        &entry.teacher_address,
-        validation_data.sources(),
-        "delete their courses",
-    )
+    rules::validate_delete(&entry.teacher_address, &validation_data.sources())
+        .map_err(helper::rule_error)
 }
 
 // =========================== CourseAnchor validation
 pub fn anchor_create(entry: CourseAnchor, validation_data: ValidationData) -> Result<(), String> {
-    helper::validate_only_teacher_can_do(
+    rules::validate_create(
         &entry.teacher_address,
-        validation_data.sources(),
-        "create their courses",
+        &validation_data.sources(),
+        &entry.title,
+        &CourseAnchor::entry_type(),
+    )
+    .map_err(helper::rule_error)?;
+    helper::validate_timestamp(
+        entry.timestamp,
+        &validation_data,
+        &CourseAnchor::entry_type(),
     )?;
-    helper::validate_entity_title(&entry.title, &CourseAnchor::entry_type(), MAX_TITLE_LEN)
+    validate_has_role(
+        &entry.teacher_address,
+        Role::Teacher,
+        &entry.role_grant_address,
+        &validation_data,
+    )
 }
 
 // NOTE: we don't accept any parameters here because we don't need them to always return an error
 // because this anchor can never be modified
 pub fn anchor_modify() -> Result<(), String> {
-    Err(String::from(
-        "Can't modify the CourseAnchor entry: it can only be created or deleted",
-    ))
+    Err(ZomeError::immutable_entry("course_anchor").into())
 }
 
 pub fn anchor_delete(
@@ -72,11 +98,8 @@ pub fn anchor_delete(
     _entry_header: ChainHeader,
     validation_data: ValidationData,
 ) -> Result<(), String> {
-    helper::validate_only_teacher_can_do(
-        &entry.teacher_address,
-        validation_data.sources(),
-        "delete their courses",
-    )
+    rules::validate_delete(&entry.teacher_address, &validation_data.sources())
+        .map_err(helper::rule_error)
 }
 
 // =========================== CourseCatalogAnchor validation
@@ -91,13 +114,13 @@ pub fn catalog_create(
 // NOTE: we don't accept any parameters here because we don't need them to always return an error
 // because this anchor can never be modified
 pub fn catalog_modify() -> Result<(), String> {
-    Err(String::from("Can't modify the CourseAnchorCatalog entry"))
+    Err(ZomeError::immutable_entry("course_anchor_catalog").into())
 }
 
 // NOTE: we don't accept any parameters here because we don't need them to always return an error
 // because this anchor can never be deleted
 pub fn catalog_delete() -> Result<(), String> {
-    Err(String::from("Can't delete the CourseAnchorCatalog entry"))
+    Err(ZomeError::forbidden("Can't delete the CourseAnchorCatalog entry").into())
 }
 
 //  =========================== CourseAnchor links validation
@@ -117,13 +140,9 @@ pub fn anchor_to_course_link(validation_data: LinkValidationData) -> Result<(),
             if base.teacher_address != target.teacher_address {
                 // notice that we're using return and ending this statement with ; symbol
                 // You can do both: skip ; symbol in the last fn statement or explicitly add return to it and then leave ; as is
-                return Err(String::from(
-                    "Can't link CourseAnchor to Course because their teacher addresses are different",
-                ));
+                return Err(ZomeError::invalid_link("course_anchor->course", "Can't link CourseAnchor to Course because their teacher addresses are different").into());
             } else if author != base.teacher_address {
-                return Err(String::from(
-                    "Can't link CourseAnchor to Course because your address isn't specified as teacher address for this course",
-                ));
+                return Err(ZomeError::invalid_link("course_anchor->course", "Can't link CourseAnchor to Course because your address isn't specified as teacher address for this course").into());
             }
             Ok(())
         }
@@ -136,11 +155,57 @@ pub fn anchor_to_course_link(validation_data: LinkValidationData) -> Result<(),
             // get link base: entry from which the link goes
             let base: CourseAnchor = hdk::utils::get_as_type(link.link.base().clone())?;
             if author != base.teacher_address {
-                return Err(String::from(
-                    "Can't remove link from CourseAnchor to Course because your address isn't specified as teacher_address for this course",
-                ));
+                return Err(ZomeError::invalid_link("course_anchor->course", "Can't remove link from CourseAnchor to Course because your address isn't specified as teacher_address for this course").into());
             }
             Ok(())
         }
     }
 }
+
+// returns author of the link together with base and target of the link between CourseAnchors
+fn get_course_anchor_link_data(
+    validation_data: LinkValidationData,
+) -> Result<(Address, Address, Address), String> {
+    let (link, validation_data) = match validation_data {
+        hdk::LinkValidationData::LinkAdd {
+            link,
+            validation_data,
+        } => (link, validation_data),
+        hdk::LinkValidationData::LinkRemove {
+            link,
+            validation_data,
+        } => (link, validation_data),
+    };
+    let author = validation_data.package.chain_header.provenances()[0].source();
+    Ok((author, link.link.base().clone(), link.link.target().clone()))
+}
+
+// validates that author of the link is the teacher of the fork
+fn validate_only_fork_teacher_can_link(
+    author: Address,
+    fork_anchor_address: Address,
+) -> Result<(), String> {
+    let fork_anchor: CourseAnchor = hdk::utils::get_as_type(fork_anchor_address)?;
+    if author != fork_anchor.teacher_address {
+        return Err(ZomeError::forbidden(
+            "Only the teacher of the forked course can link it to the original course",
+        )
+        .into());
+    }
+    Ok(())
+}
+
+pub fn anchor_to_fork_link(validation_data: LinkValidationData) -> Result<(), String> {
+    let (author, _origin_anchor_address, fork_anchor_address) =
+        get_course_anchor_link_data(validation_data)?;
+    validate_only_fork_teacher_can_link(author, fork_anchor_address)
+}
+
+pub fn fork_to_origin_link(validation_data: LinkValidationData) -> Result<(), String> {
+    let (author, fork_anchor_address, origin_anchor_address) =
+        get_course_anchor_link_data(validation_data)?;
+    if fork_anchor_address == origin_anchor_address {
+        return Err(ZomeError::invalid_input("Course can't be forked from itself").into());
+    }
+    validate_only_fork_teacher_can_link(author, fork_anchor_address)
+}
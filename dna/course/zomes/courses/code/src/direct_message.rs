@@ -0,0 +1,83 @@
+use hdk::holochain_core_types::time::Timeout;
+use hdk::prelude::*;
+use hdk::AGENT_ADDRESS;
+
+use crate::announcement;
+use crate::announcement::entry::Announcement;
+use crate::error::ZomeError;
+use crate::message;
+use crate::message::entry::Message;
+use crate::signal::{self, ChangeNotice};
+
+// how long to wait for the receiving agent to respond before giving up on delivery
+pub const SEND_TIMEOUT_MS: usize = 10000;
+// same as SEND_TIMEOUT_MS, but for messages that are delivered to many agents one after another:
+// agents that are offline shouldn't hold the caller for long
+pub const BROADCAST_TIMEOUT_MS: usize = 1000;
+
+// All node-to-node messages that agents of this DNA send to each other.
+// NOTE: zome can only have a single receive callback, so every kind of message has to be a variant of this enum
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type", content = "payload")]
+pub enum DirectMessage {
+    // teacher delivers a new announcement to a student of the course
+    Announcement {
+        announcement_address: Address,
+        announcement: Announcement,
+    },
+    // private message between the teacher of the course and one of it's students
+    Message(Message),
+    // notice about the change in the course that the receiver is a member of
+    ChangeNotice(ChangeNotice),
+}
+
+// sends message to to_agent and waits for it to be processed on their side
+pub fn send(to_agent: Address, message: DirectMessage, timeout_ms: usize) -> ZomeApiResult<()> {
+    let payload = serde_json::to_string(&message).map_err(|e| {
+        ZomeApiError::from(ZomeError::invalid_input(&format!(
+            "Can't serialize message: {}",
+            e
+        )))
+    })?;
+    let response = hdk::send(to_agent, payload, Timeout::new(timeout_ms))?;
+    // receiving side responds with serialized Result<(), String>
+    let result: Result<(), String> = serde_json::from_str(&response).map_err(|e| {
+        ZomeApiError::from(ZomeError::inconsistent_links(&format!(
+            "Can't parse response to message: {}",
+            e
+        )))
+    })?;
+    result.map_err(ZomeApiError::from)
+}
+
+// sends the same message to every agent in recipients except the current one and returns addresses
+// of agents that received it. Delivery is best-effort: agents that are offline just won't get the message
+pub fn broadcast(recipients: Vec<Address>, message: DirectMessage) -> Vec<Address> {
+    recipients
+        .into_iter()
+        .filter(|recipient| recipient != *AGENT_ADDRESS)
+        .filter(|recipient| send(recipient.clone(), message.clone(), BROADCAST_TIMEOUT_MS).is_ok())
+        .collect()
+}
+
+// handles message that was sent by the agent at from_address and returns serialized Result<(), String>
+// to be returned from the zome's receive callback
+pub fn receive(from_address: Address, message_json: String) -> String {
+    let result = match serde_json::from_str::<DirectMessage>(&message_json) {
+        Ok(DirectMessage::Announcement {
+            announcement_address,
+            announcement,
+        }) => announcement::handlers::receive(from_address, announcement_address, announcement)
+            .map_err(|e| e.to_string()),
+        Ok(DirectMessage::Message(message)) => {
+            message::handlers::receive(from_address, message).map_err(|e| e.to_string())
+        }
+        Ok(DirectMessage::ChangeNotice(notice)) => {
+            signal::receive(from_address, notice).map_err(|e| e.to_string())
+        }
+        Err(e) => {
+            Err(ZomeError::invalid_input(&format!("Can't parse received message: {}", e)).into())
+        }
+    };
+    serde_json::to_string(&result).unwrap_or_default()
+}
@@ -0,0 +1,309 @@
+use courses_core::error::RuleError;
+use hdk::error::ZomeApiError;
+use std::fmt;
+
+// Errors that this zome returns from it's handlers and validation callbacks.
+// Every error is serialized as a JSON object with a stable "code" field, fields of the variant and
+// a human-readable "message", e.g.:
+//  {"code":"TitleTooLong","entity":"course","max_length":50,"message":"course title is too long, ..."}
+// so UI can react to the code without matching English text.
+// NOTE: never rename variants or their fields: UI relies on them
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "code")]
+pub enum ZomeError {
+    // entity doesn't exist at all
+    NotFound {
+        entity: String,
+    },
+    // entity existed but was deleted
+    Deleted {
+        entity: String,
+        action: String,
+    },
+    NotTeacher {
+        action: String,
+    },
+    NotAuthor {
+        entity: String,
+        action: String,
+    },
+    NotCourseMember {
+        action: String,
+    },
+    NotAdmin {
+        action: String,
+    },
+    TitleTooLong {
+        entity: String,
+        max_length: usize,
+    },
+    TextTooLong {
+        entity: String,
+        field: String,
+        max_length: usize,
+    },
+    // value of the field doesn't satisfy some rule, reason describes which one
+    InvalidValue {
+        entity: String,
+        field: String,
+        reason: String,
+    },
+    // entry can't be modified at all
+    ImmutableEntry {
+        entity: String,
+    },
+    // field of the entry can't be changed once the entry is created
+    ImmutableField {
+        entity: String,
+        field: String,
+    },
+    AlreadyExists {
+        entity: String,
+        reason: String,
+    },
+    // links on the DHT are in the state that should never happen
+    InconsistentLinks {
+        details: String,
+    },
+    // link validation failed
+    InvalidLink {
+        link_type: String,
+        reason: String,
+    },
+    // agent isn't allowed to do this for a reason that isn't covered by the variants above
+    Forbidden {
+        reason: String,
+    },
+    // input of the zome call doesn't make sense
+    InvalidInput {
+        reason: String,
+    },
+    // content isn't released to the student yet. unlocks_at is None when it doesn't unlock at a known time
+    Locked {
+        entity: String,
+        reason: String,
+        unlocks_at: Option<u64>,
+    },
+}
+
+impl ZomeError {
+    pub fn not_found(entity: &str) -> Self {
+        ZomeError::NotFound {
+            entity: entity.to_owned(),
+        }
+    }
+
+    pub fn deleted(entity: &str, action: &str) -> Self {
+        ZomeError::Deleted {
+            entity: entity.to_owned(),
+            action: action.to_owned(),
+        }
+    }
+
+    pub fn not_teacher(action: &str) -> Self {
+        ZomeError::NotTeacher {
+            action: action.to_owned(),
+        }
+    }
+
+    pub fn not_author(entity: &str, action: &str) -> Self {
+        ZomeError::NotAuthor {
+            entity: entity.to_owned(),
+            action: action.to_owned(),
+        }
+    }
+
+    pub fn not_course_member(action: &str) -> Self {
+        ZomeError::NotCourseMember {
+            action: action.to_owned(),
+        }
+    }
+
+    pub fn not_admin(action: &str) -> Self {
+        ZomeError::NotAdmin {
+            action: action.to_owned(),
+        }
+    }
+
+    pub fn title_too_long(entity: &str, max_length: usize) -> Self {
+        ZomeError::TitleTooLong {
+            entity: entity.to_owned(),
+            max_length,
+        }
+    }
+
+    pub fn text_too_long(entity: &str, field: &str, max_length: usize) -> Self {
+        ZomeError::TextTooLong {
+            entity: entity.to_owned(),
+            field: field.to_owned(),
+            max_length,
+        }
+    }
+
+    pub fn invalid_value(entity: &str, field: &str, reason: &str) -> Self {
+        ZomeError::InvalidValue {
+            entity: entity.to_owned(),
+            field: field.to_owned(),
+            reason: reason.to_owned(),
+        }
+    }
+
+    pub fn immutable_entry(entity: &str) -> Self {
+        ZomeError::ImmutableEntry {
+            entity: entity.to_owned(),
+        }
+    }
+
+    pub fn immutable_field(entity: &str, field: &str) -> Self {
+        ZomeError::ImmutableField {
+            entity: entity.to_owned(),
+            field: field.to_owned(),
+        }
+    }
+
+    pub fn already_exists(entity: &str, reason: &str) -> Self {
+        ZomeError::AlreadyExists {
+            entity: entity.to_owned(),
+            reason: reason.to_owned(),
+        }
+    }
+
+    pub fn inconsistent_links(details: &str) -> Self {
+        ZomeError::InconsistentLinks {
+            details: details.to_owned(),
+        }
+    }
+
+    pub fn invalid_link(link_type: &str, reason: &str) -> Self {
+        ZomeError::InvalidLink {
+            link_type: link_type.to_owned(),
+            reason: reason.to_owned(),
+        }
+    }
+
+    pub fn forbidden(reason: &str) -> Self {
+        ZomeError::Forbidden {
+            reason: reason.to_owned(),
+        }
+    }
+
+    pub fn invalid_input(reason: &str) -> Self {
+        ZomeError::InvalidInput {
+            reason: reason.to_owned(),
+        }
+    }
+
+    pub fn locked(entity: &str, reason: &str, unlocks_at: Option<u64>) -> Self {
+        ZomeError::Locked {
+            entity: entity.to_owned(),
+            reason: reason.to_owned(),
+            unlocks_at,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut error_json = serde_json::to_value(self).unwrap_or_default();
+        if let Some(error_object) = error_json.as_object_mut() {
+            error_object.insert(
+                "message".to_owned(),
+                serde_json::Value::String(self.to_string()),
+            );
+        }
+        error_json.to_string()
+    }
+}
+
+impl fmt::Display for ZomeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ZomeError::NotFound { entity } => write!(f, "{} doesn't exist", entity),
+            ZomeError::Deleted { entity, action } => {
+                write!(f, "Can't {} a deleted {}", action, entity)
+            }
+            ZomeError::NotTeacher { action } => write!(f, "Only the teacher can {}", action),
+            ZomeError::NotAuthor { entity, action } => {
+                write!(f, "Only the author of the {} can {} it", entity, action)
+            }
+            ZomeError::NotCourseMember { action } => write!(
+                f,
+                "Only the teacher and students enrolled in the course can {}",
+                action
+            ),
+            ZomeError::NotAdmin { action } => write!(f, "Only admins can {}", action),
+            ZomeError::TitleTooLong { entity, max_length } => write!(
+                f,
+                "{} title is too long, has to be no longer than {}",
+                entity, max_length
+            ),
+            ZomeError::TextTooLong {
+                entity,
+                field,
+                max_length,
+            } => write!(
+                f,
+                "{} {} is too long, has to be no longer than {}",
+                entity, field, max_length
+            ),
+            ZomeError::InvalidValue {
+                entity,
+                field,
+                reason,
+            } => write!(f, "Invalid {} {}: {}", entity, field, reason),
+            ZomeError::ImmutableEntry { entity } => {
+                write!(f, "Can't modify the {} entry", entity)
+            }
+            ZomeError::ImmutableField { entity, field } => {
+                write!(f, "Cannot change the {} of the {}", field, entity)
+            }
+            ZomeError::AlreadyExists { reason, .. } => write!(f, "{}", reason),
+            ZomeError::InconsistentLinks { details } => {
+                write!(f, "Something is wrong with links: {}", details)
+            }
+            ZomeError::InvalidLink { reason, .. } => write!(f, "{}", reason),
+            ZomeError::Forbidden { reason } => write!(f, "{}", reason),
+            ZomeError::InvalidInput { reason } => write!(f, "{}", reason),
+            ZomeError::Locked { entity, reason, .. } => {
+                write!(f, "This {} is locked: {}", entity, reason)
+            }
+        }
+    }
+}
+
+impl From<RuleError> for ZomeError {
+    fn from(error: RuleError) -> Self {
+        match error {
+            RuleError::TitleTooLong { entity, max_length } => {
+                ZomeError::TitleTooLong { entity, max_length }
+            }
+            RuleError::NotTeacher { action } => ZomeError::NotTeacher { action },
+            RuleError::NotAuthor { entity, action } => ZomeError::NotAuthor { entity, action },
+            RuleError::NotCourseMember { action } => ZomeError::NotCourseMember { action },
+            RuleError::ImmutableField { entity, field } => {
+                ZomeError::ImmutableField { entity, field }
+            }
+            RuleError::InvalidValue {
+                entity,
+                field,
+                reason,
+            } => ZomeError::InvalidValue {
+                entity,
+                field,
+                reason,
+            },
+        }
+    }
+}
+
+// this allows to return ZomeError from handlers with ? or .into()
+impl From<ZomeError> for ZomeApiError {
+    fn from(error: ZomeError) -> Self {
+        ZomeApiError::Internal(error.to_json())
+    }
+}
+
+// this allows to return ZomeError from validation callbacks that have to return Result<(), String>
+impl From<ZomeError> for String {
+    fn from(error: ZomeError) -> Self {
+        error.to_json()
+    }
+}
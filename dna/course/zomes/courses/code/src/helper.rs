@@ -1,24 +1,27 @@
+use courses_core::error::RuleError;
+use courses_core::rules;
 use hdk::{
-    error::{ZomeApiError, ZomeApiResult},
+    error::ZomeApiResult,
     holochain_persistence_api::cas::content::Address,
-    prelude::LinkMatch,
+    prelude::{LinkMatch, QueryArgsOptions, QueryResult},
+    ValidationData,
 };
 use holochain_entry_utils::HolochainEntry;
 
+use crate::clock;
+use crate::course;
+use crate::error::ZomeError;
+
+// NOTE: rules themselves live in the courses_core crate so they can be unit tested without a conductor.
+// Functions below only adapt them to the data that validation callbacks have
+
 // validates title of some entity on not being longer than allowed_legth
 pub fn validate_entity_title(
     title: &str,
     entity_name: &str,
     allowed_length: usize,
 ) -> Result<(), String> {
-    if title.len() > allowed_length {
-        Err(format!(
-            "{} title is too long, has to be no longer than {}",
-            entity_name, allowed_length
-        ))
-    } else {
-        Ok(())
-    }
+    rules::validate_entity_title(title, entity_name, allowed_length).map_err(rule_error)
 }
 
 // validates that agent with teacher_address is listed in the validation_data_sources
@@ -27,10 +30,46 @@ pub fn validate_only_teacher_can_do(
     validation_data_sources: Vec<Address>,
     action_name: &str,
 ) -> Result<(), String> {
-    if !validation_data_sources.contains(teacher_address) {
-        return Err(format!("Only the teacher can {}", action_name));
-    }
-    Ok(())
+    rules::validate_only_teacher_can_do(teacher_address, &validation_data_sources, action_name)
+        .map_err(rule_error)
+}
+
+// validates that agent with author_address is listed in the validation_data_sources
+pub fn validate_only_author_can_do(
+    author_address: &Address,
+    validation_data_sources: Vec<Address>,
+    entity_name: &str,
+    action_name: &str,
+) -> Result<(), String> {
+    rules::validate_only_author_can_do(
+        author_address,
+        &validation_data_sources,
+        entity_name,
+        action_name,
+    )
+    .map_err(rule_error)
+}
+
+// validates that agent with agent_address is either the teacher or one of the students of the course
+pub fn validate_only_course_member_can_do(
+    course_anchor_address: &Address,
+    teacher_address: &Address,
+    agent_address: &Address,
+    action_name: &str,
+) -> Result<(), String> {
+    // teacher is always a member of the course so we don't need to get students from the DHT
+    let students = if agent_address == teacher_address {
+        vec![]
+    } else {
+        course::handlers::get_students(course_anchor_address.clone())?
+    };
+    rules::validate_only_course_member_can_do(
+        teacher_address,
+        &students,
+        agent_address,
+        action_name,
+    )
+    .map_err(rule_error)
 }
 
 pub fn validate_no_teacher_change(
@@ -38,10 +77,36 @@ pub fn validate_no_teacher_change(
     new_teacher_address: Address,
     entity_name: &str,
 ) -> Result<(), String> {
-    if old_teacher_address != new_teacher_address {
-        return Err(format!("Cannot change the teacher of the {}", entity_name));
-    }
-    Ok(())
+    rules::validate_no_teacher_change(&old_teacher_address, &new_teacher_address, entity_name)
+        .map_err(rule_error)
+}
+
+// validates timestamp of the newly created entry against the time in it's chain header
+pub fn validate_timestamp(
+    timestamp: u64,
+    validation_data: &ValidationData,
+    entity_name: &str,
+) -> Result<(), String> {
+    // NOTE: chain header is created by the author's conductor, so unlike timestamps in entries it can't be set by the UI
+    let header_timestamp = clock::header_time(&validation_data.package.chain_header);
+    rules::validate_timestamp(timestamp, header_timestamp, entity_name).map_err(rule_error)
+}
+
+// validates timestamp of the new version of the entry against the time in it's chain header and the previous version
+pub fn validate_timestamp_update(
+    old_timestamp: u64,
+    new_timestamp: u64,
+    validation_data: &ValidationData,
+    entity_name: &str,
+) -> Result<(), String> {
+    validate_timestamp(new_timestamp, validation_data, entity_name)?;
+    rules::validate_timestamp_not_decreasing(old_timestamp, new_timestamp, entity_name)
+        .map_err(rule_error)
+}
+
+// converts error of the courses_core rule into the error that validation callbacks return
+pub fn rule_error(error: RuleError) -> String {
+    ZomeError::from(error).into()
 }
 
 // gets latest data entry that is linked to anchor at entry_anchor_address
@@ -73,9 +138,12 @@ pub fn get_latest_data_entry<T: HolochainEntry>(
             // 4. using Course entry address from this link as latest_entry_address
             // We're not going into that right now to not overload people with the content
             if entry_addresses.len() != 1 {
-                return Err(ZomeApiError::from(
-                    "Something is wrong with links from CourseAnchor to Course".to_owned(),
-                ));
+                return Err(ZomeError::inconsistent_links(&format!(
+                    "expected exactly one link of type {} from the anchor, found {}",
+                    link_type,
+                    entry_addresses.len()
+                ))
+                .into());
             } else {
                 let latest_entry_address = entry_addresses[0].clone();
                 let latest_entry: T = hdk::utils::get_as_type(latest_entry_address.clone())?;
@@ -88,3 +156,43 @@ pub fn get_latest_data_entry<T: HolochainEntry>(
         None => return Ok(None),
     }
 }
+
+// gets all entries of type T that were committed to the source chain of the current agent
+// together with their addresses. This is a helper for private entries that aren't available on the DHT.
+pub fn get_my_entries<T: HolochainEntry>() -> ZomeApiResult<Vec<(T, Address)>> {
+    let query_result = hdk::query_result(
+        T::entry_type().into(),
+        QueryArgsOptions {
+            start: 0,
+            limit: 0,
+            headers: false,
+            entries: true,
+        },
+    )?;
+    match query_result {
+        QueryResult::Entries(entries) => Ok(entries
+            .iter()
+            .filter_map(|(address, entry)| T::from_entry(entry).map(|t| (t, address.clone())))
+            .collect()),
+        _ => Err(
+            ZomeError::inconsistent_links("unexpected result of querying the source chain").into(),
+        ),
+    }
+}
+
+// reads DNA property that holds a list of agent addresses.
+// Returns None if DNA doesn't define this property
+pub fn get_address_list_property(property_name: &str) -> ZomeApiResult<Option<Vec<Address>>> {
+    match hdk::property(property_name) {
+        Ok(addresses_json) => serde_json::from_str(&String::from(addresses_json)).map_err(|e| {
+            ZomeError::invalid_value(
+                "DNA property",
+                property_name,
+                &format!("has to be a list of agent addresses: {}", e),
+            )
+            .into()
+        }),
+        // property isn't defined in the DNA
+        Err(_) => Ok(None),
+    }
+}
@@ -23,10 +23,28 @@ use hdk_proc_macros::zome;
 
 // Declaring Rust modules that are used in our project
 mod anchor_trait;
+mod announcement;
+mod assignment;
+mod batch;
+mod bundle;
+mod certificate;
+mod clock;
+mod comment;
 mod content;
 mod course;
+mod direct_message;
+mod error;
 mod helper;
+mod membrane;
+mod message;
+mod profile;
+mod progress;
+mod question;
+mod release;
+mod review;
+mod role;
 mod section;
+mod signal;
 
 #[zome]
 mod courses {
@@ -37,10 +55,20 @@ mod courses {
         Ok(())
     }
 
-    // Things to be done to validate each agent in the network, we skip this for now
+    // Things to be done to validate each agent in the network: if DNA lists invite signers
+    // in it's properties, agent has to present an invitation signed by one of them
     #[validate_agent]
     pub fn validate_agent(validation_data: EntryValidationData<AgentId>) {
-        Ok(())
+        match validation_data {
+            EntryValidationData::Create { entry, .. } => membrane::validate_agent(entry),
+            _ => Err(crate::error::ZomeError::immutable_entry("agent").into()),
+        }
+    }
+
+    // Handles messages that other agents send directly to this agent with hdk::send
+    #[receive]
+    pub fn receive(from: Address, msg_json: String) -> String {
+        direct_message::receive(from, msg_json)
     }
 
     #[zome_fn("hc_public")]
@@ -53,6 +81,11 @@ mod courses {
         hdk::get_entry(&address)
     }
 
+    #[zome_fn("hc_public")]
+    fn sign_invitation(invitee_address: Address) -> ZomeApiResult<membrane::Invitation> {
+        membrane::sign_invitation(invitee_address)
+    }
+
     //  ====================== Course definitions
     #[entry_def]
     fn course_catalog_anchor_entry_definition() -> ValidatingEntryType {
@@ -76,6 +109,12 @@ mod courses {
         course::handlers::create(title, timestamp)
     }
 
+    // same as create_course but takes the timestamp from the conductor's clock
+    #[zome_fn("hc_public")]
+    fn create_course_now(title: String) -> ZomeApiResult<Address> {
+        course::handlers::create(title, clock::now()?)
+    }
+
     #[zome_fn("hc_public")]
     fn get_latest_course_entry(
         course_anchor_address: Address,
@@ -93,6 +132,20 @@ mod courses {
         course::handlers::update(title, sections_addresses, &course_anchor_address, timestamp)
     }
 
+    #[zome_fn("hc_public")]
+    fn update_course_now(
+        title: String,
+        sections_addresses: Vec<Address>,
+        course_anchor_address: Address,
+    ) -> ZomeApiResult<Address> {
+        course::handlers::update(
+            title,
+            sections_addresses,
+            &course_anchor_address,
+            clock::now()?,
+        )
+    }
+
     #[zome_fn("hc_public")]
     fn delete_course(course_anchor_address: Address) -> ZomeApiResult<Address> {
         course::handlers::delete(course_anchor_address)
@@ -118,11 +171,56 @@ mod courses {
         course::handlers::enrol_in_course(course_anchor_address)
     }
 
+    // drip_rule decides when students get access to sections of the course. None releases all sections right away
+    #[zome_fn("hc_public")]
+    fn set_course_drip_rule(
+        course_anchor_address: Address,
+        drip_rule: Option<course::entry::DripRule>,
+        timestamp: u64,
+    ) -> ZomeApiResult<Address> {
+        course::handlers::set_drip_rule(&course_anchor_address, drip_rule, timestamp)
+    }
+
     #[zome_fn("hc_public")]
     fn get_all_students(course_anchor_address: Address) -> ZomeApiResult<Vec<Address>> {
         course::handlers::get_students(course_anchor_address)
     }
 
+    #[zome_fn("hc_public")]
+    fn apply_course_batch(
+        course_anchor_address: Address,
+        operations: Vec<batch::operation::BatchOperation>,
+        timestamp: u64,
+    ) -> ZomeApiResult<Vec<Address>> {
+        batch::handlers::apply(course_anchor_address, operations, timestamp)
+    }
+
+    #[zome_fn("hc_public")]
+    fn get_course_tree(
+        course_anchor_address: Address,
+    ) -> ZomeApiResult<Option<course::handlers::CourseTree>> {
+        course::handlers::get_course_tree(course_anchor_address)
+    }
+
+    #[zome_fn("hc_public")]
+    fn fork_course(
+        course_anchor_address: Address,
+        new_title: String,
+        timestamp: u64,
+    ) -> ZomeApiResult<Address> {
+        course::handlers::fork(course_anchor_address, new_title, timestamp)
+    }
+
+    #[zome_fn("hc_public")]
+    fn get_forks(course_anchor_address: Address) -> ZomeApiResult<Vec<Address>> {
+        course::handlers::get_forks(course_anchor_address)
+    }
+
+    #[zome_fn("hc_public")]
+    fn get_fork_origin(course_anchor_address: Address) -> ZomeApiResult<Option<Address>> {
+        course::handlers::get_fork_origin(course_anchor_address)
+    }
+
     //  ====================== Section definitions
     #[entry_def]
     fn section_anchor_entry_definition() -> ValidatingEntryType {
@@ -137,10 +235,20 @@ mod courses {
     #[zome_fn("hc_public")]
     fn get_latest_section_entry(
         section_anchor_address: Address,
-    ) -> ZomeApiResult<Option<section::entry::Section>> {
+    ) -> ZomeApiResult<Option<section::handlers::SectionWithLock>> {
         section::handlers::get_latest_section_entry(section_anchor_address)
     }
 
+    // release_at is the time before which students can't see the content of the section
+    #[zome_fn("hc_public")]
+    fn set_section_release_time(
+        section_anchor_address: Address,
+        release_at: Option<u64>,
+        timestamp: u64,
+    ) -> ZomeApiResult<Address> {
+        section::handlers::set_release_time(&section_anchor_address, release_at, timestamp)
+    }
+
     #[zome_fn("hc_public")]
     fn create_section(
         title: String,
@@ -150,6 +258,45 @@ mod courses {
         section::handlers::create(title, &course_anchor_address, timestamp)
     }
 
+    #[zome_fn("hc_public")]
+    fn create_section_now(title: String, course_anchor_address: Address) -> ZomeApiResult<Address> {
+        section::handlers::create(title, &course_anchor_address, clock::now()?)
+    }
+
+    // parent_section_anchor_address is the address of the section that the new one is nested in
+    #[zome_fn("hc_public")]
+    fn create_subsection(
+        title: String,
+        parent_section_anchor_address: Address,
+        timestamp: u64,
+    ) -> ZomeApiResult<Address> {
+        section::handlers::create_subsection(title, &parent_section_anchor_address, timestamp)
+    }
+
+    #[zome_fn("hc_public")]
+    fn create_subsection_now(
+        title: String,
+        parent_section_anchor_address: Address,
+    ) -> ZomeApiResult<Address> {
+        section::handlers::create_subsection(title, &parent_section_anchor_address, clock::now()?)
+    }
+
+    #[zome_fn("hc_public")]
+    fn reorder_subsections(
+        section_anchor_address: Address,
+        subsections: Vec<Address>,
+        timestamp: u64,
+    ) -> ZomeApiResult<Address> {
+        section::handlers::reorder_subsections(&section_anchor_address, subsections, timestamp)
+    }
+
+    #[zome_fn("hc_public")]
+    fn get_section_tree(
+        section_anchor_address: Address,
+    ) -> ZomeApiResult<Option<course::handlers::SectionTree>> {
+        course::handlers::get_section_tree(&section_anchor_address)
+    }
+
     #[zome_fn("hc_public")]
     fn update_section(
         title: String,
@@ -159,12 +306,56 @@ mod courses {
         section::handlers::update(title, &section_anchor_address, timestamp)
     }
 
+    #[zome_fn("hc_public")]
+    fn update_section_now(
+        title: String,
+        section_anchor_address: Address,
+    ) -> ZomeApiResult<Address> {
+        section::handlers::update(title, &section_anchor_address, clock::now()?)
+    }
+
     #[zome_fn("hc_public")]
     fn delete_section(section_anchor_address: Address, timestamp: u64) -> ZomeApiResult<Address> {
         section::handlers::delete(section_anchor_address, timestamp)
     }
 
+    #[zome_fn("hc_public")]
+    fn delete_section_now(section_anchor_address: Address) -> ZomeApiResult<Address> {
+        section::handlers::delete(section_anchor_address, clock::now()?)
+    }
+
+    // moves section and it's content to another course that is taught by the same teacher
+    #[zome_fn("hc_public")]
+    fn move_section_to_course(
+        section_anchor_address: Address,
+        target_course_anchor_address: Address,
+        timestamp: u64,
+    ) -> ZomeApiResult<section::handlers::MovedSection> {
+        section::handlers::move_to_course(
+            section_anchor_address,
+            &target_course_anchor_address,
+            timestamp,
+        )
+    }
+
+    #[zome_fn("hc_public")]
+    fn move_section_to_course_now(
+        section_anchor_address: Address,
+        target_course_anchor_address: Address,
+    ) -> ZomeApiResult<section::handlers::MovedSection> {
+        section::handlers::move_to_course(
+            section_anchor_address,
+            &target_course_anchor_address,
+            clock::now()?,
+        )
+    }
+
     //  ====================== Content definitions
+    #[entry_def]
+    fn content_anchor_entry_definition() -> ValidatingEntryType {
+        content::anchor::content_anchor_def()
+    }
+
     #[entry_def]
     fn content_entry_definition() -> ValidatingEntryType {
         content::entry::content_entry_def()
@@ -181,24 +372,579 @@ mod courses {
         content::handlers::create(name, section_anchor_address, url, timestamp, description)
     }
 
+    #[zome_fn("hc_public")]
+    fn create_content_now(
+        name: String,
+        section_anchor_address: Address,
+        url: String,
+        description: String,
+    ) -> ZomeApiResult<Address> {
+        content::handlers::create(
+            name,
+            section_anchor_address,
+            url,
+            clock::now()?,
+            description,
+        )
+    }
+
     #[zome_fn("hc_public")]
     fn get_contents(section_anchor_address: Address) -> ZomeApiResult<Vec<Address>> {
-        content::handlers::get_contents(&section_anchor_address)
+        content::handlers::get_released_contents(&section_anchor_address)
+    }
+
+    #[zome_fn("hc_public")]
+    fn get_latest_content_entry(
+        content_anchor_address: Address,
+    ) -> ZomeApiResult<Option<content::entry::Content>> {
+        content::handlers::get_latest_content_entry(content_anchor_address)
     }
 
     #[zome_fn("hc_public")]
     fn update_content(
-        content_address: Address,
+        content_anchor_address: Address,
         name: String,
         url: String,
         description: String,
         timestamp: u64,
     ) -> ZomeApiResult<Address> {
-        content::handlers::update(content_address, name, url, description, timestamp)
+        content::handlers::update(content_anchor_address, name, url, description, timestamp)
+    }
+
+    #[zome_fn("hc_public")]
+    fn update_content_now(
+        content_anchor_address: Address,
+        name: String,
+        url: String,
+        description: String,
+    ) -> ZomeApiResult<Address> {
+        content::handlers::update(
+            content_anchor_address,
+            name,
+            url,
+            description,
+            clock::now()?,
+        )
+    }
+
+    #[zome_fn("hc_public")]
+    fn delete_content(content_anchor_address: Address) -> ZomeApiResult<Address> {
+        content::handlers::delete(content_anchor_address)
+    }
+
+    // links contents that were created before ContentAnchor was introduced to new anchors.
+    // Only the teacher of the course can do it
+    #[zome_fn("hc_public")]
+    fn migrate_contents(course_anchor_address: Address) -> ZomeApiResult<Vec<Address>> {
+        content::handlers::migrate_contents(&course_anchor_address, clock::now()?)
+    }
+    //  ====================== Assignment definitions
+    #[entry_def]
+    fn assignment_entry_definition() -> ValidatingEntryType {
+        assignment::entry::assignment_entry_def()
+    }
+
+    #[entry_def]
+    fn submission_entry_definition() -> ValidatingEntryType {
+        assignment::submission::submission_entry_def()
+    }
+
+    #[entry_def]
+    fn grade_entry_definition() -> ValidatingEntryType {
+        assignment::grade::grade_entry_def()
+    }
+
+    #[zome_fn("hc_public")]
+    fn create_assignment(
+        title: String,
+        prompt: String,
+        due_date: u64,
+        max_score: u32,
+        section_anchor_address: Address,
+        timestamp: u64,
+    ) -> ZomeApiResult<Address> {
+        assignment::handlers::create(
+            title,
+            prompt,
+            due_date,
+            max_score,
+            section_anchor_address,
+            timestamp,
+        )
+    }
+
+    #[zome_fn("hc_public")]
+    fn get_assignments(section_anchor_address: Address) -> ZomeApiResult<Vec<Address>> {
+        assignment::handlers::get_assignments(&section_anchor_address)
+    }
+
+    #[zome_fn("hc_public")]
+    fn delete_assignment(assignment_address: Address) -> ZomeApiResult<Address> {
+        assignment::handlers::delete(assignment_address)
+    }
+
+    #[zome_fn("hc_public")]
+    fn submit_assignment(
+        assignment_address: Address,
+        answer: String,
+        timestamp: u64,
+    ) -> ZomeApiResult<Address> {
+        assignment::handlers::submit(assignment_address, answer, timestamp)
+    }
+
+    #[zome_fn("hc_public")]
+    fn get_my_submissions() -> ZomeApiResult<Vec<Address>> {
+        assignment::handlers::get_my_submissions()
     }
 
     #[zome_fn("hc_public")]
-    fn delete_content(content_address: Address) -> ZomeApiResult<Address> {
-        content::handlers::delete(content_address)
+    fn get_submissions(assignment_address: Address) -> ZomeApiResult<Vec<Address>> {
+        assignment::handlers::get_submissions(&assignment_address)
+    }
+
+    #[zome_fn("hc_public")]
+    fn get_ungraded_submissions(assignment_address: Address) -> ZomeApiResult<Vec<Address>> {
+        assignment::handlers::get_ungraded_submissions(&assignment_address)
+    }
+
+    #[zome_fn("hc_public")]
+    fn grade_submission(
+        submission_address: Address,
+        score: u32,
+        feedback: String,
+        timestamp: u64,
+    ) -> ZomeApiResult<Address> {
+        assignment::handlers::grade(submission_address, score, feedback, timestamp)
+    }
+
+    #[zome_fn("hc_public")]
+    fn get_grade(submission_address: Address) -> ZomeApiResult<Option<assignment::grade::Grade>> {
+        assignment::handlers::get_grade_entry(submission_address)
+    }
+    //  ====================== Progress definitions
+    #[entry_def]
+    fn content_completion_entry_definition() -> ValidatingEntryType {
+        progress::entry::content_completion_entry_def()
+    }
+
+    #[zome_fn("hc_public")]
+    fn mark_content_done(
+        content_anchor_address: Address,
+        timestamp: u64,
+    ) -> ZomeApiResult<Address> {
+        progress::handlers::mark_content_done(content_anchor_address, timestamp)
+    }
+
+    #[zome_fn("hc_public")]
+    fn get_my_progress(
+        course_anchor_address: Address,
+    ) -> ZomeApiResult<progress::handlers::CourseProgress> {
+        progress::handlers::get_my_progress(course_anchor_address)
+    }
+    //  ====================== Certificate definitions
+    #[entry_def]
+    fn certificate_entry_definition() -> ValidatingEntryType {
+        certificate::entry::certificate_entry_def()
+    }
+
+    #[zome_fn("hc_public")]
+    fn issue_certificate(
+        student_address: Address,
+        course_anchor_address: Address,
+        date: u64,
+    ) -> ZomeApiResult<Address> {
+        certificate::handlers::issue(student_address, course_anchor_address, date)
+    }
+
+    #[zome_fn("hc_public")]
+    fn verify_certificate(
+        certificate_address: Address,
+    ) -> ZomeApiResult<certificate::handlers::CertificateVerification> {
+        certificate::handlers::verify(certificate_address)
+    }
+
+    #[zome_fn("hc_public")]
+    fn revoke_certificate(certificate_address: Address) -> ZomeApiResult<Address> {
+        certificate::handlers::revoke(certificate_address)
+    }
+
+    #[zome_fn("hc_public")]
+    fn get_certificates(student_address: Address) -> ZomeApiResult<Vec<Address>> {
+        certificate::handlers::get_certificates(&student_address)
+    }
+
+    #[zome_fn("hc_public")]
+    fn get_my_certificates() -> ZomeApiResult<Vec<Address>> {
+        certificate::handlers::get_my_certificates()
+    }
+
+    #[zome_fn("hc_public")]
+    fn get_course_certificates(course_anchor_address: Address) -> ZomeApiResult<Vec<Address>> {
+        certificate::handlers::get_course_certificates(&course_anchor_address)
+    }
+    //  ====================== Review definitions
+    #[entry_def]
+    fn review_anchor_entry_definition() -> ValidatingEntryType {
+        review::anchor::review_anchor_def()
+    }
+
+    #[entry_def]
+    fn review_entry_definition() -> ValidatingEntryType {
+        review::entry::review_entry_def()
+    }
+
+    #[zome_fn("hc_public")]
+    fn create_review(
+        course_anchor_address: Address,
+        rating: u8,
+        text: String,
+        timestamp: u64,
+    ) -> ZomeApiResult<Address> {
+        review::handlers::create(course_anchor_address, rating, text, timestamp)
+    }
+
+    #[zome_fn("hc_public")]
+    fn update_review(
+        review_anchor_address: Address,
+        rating: u8,
+        text: String,
+        timestamp: u64,
+    ) -> ZomeApiResult<Address> {
+        review::handlers::update(&review_anchor_address, rating, text, timestamp)
+    }
+
+    #[zome_fn("hc_public")]
+    fn get_latest_review_entry(
+        review_anchor_address: Address,
+    ) -> ZomeApiResult<Option<review::entry::Review>> {
+        review::handlers::get_latest_review_entry(review_anchor_address)
+    }
+
+    #[zome_fn("hc_public")]
+    fn get_reviews(course_anchor_address: Address) -> ZomeApiResult<Vec<Address>> {
+        review::handlers::get_reviews(&course_anchor_address)
+    }
+
+    #[zome_fn("hc_public")]
+    fn get_course_rating_summary(
+        course_anchor_address: Address,
+    ) -> ZomeApiResult<review::handlers::RatingSummary> {
+        review::handlers::get_rating_summary(&course_anchor_address)
+    }
+    //  ====================== Comment definitions
+    #[entry_def]
+    fn comment_anchor_entry_definition() -> ValidatingEntryType {
+        comment::anchor::comment_anchor_def()
+    }
+
+    #[entry_def]
+    fn comment_entry_definition() -> ValidatingEntryType {
+        comment::entry::comment_entry_def()
+    }
+
+    // target_address is an address of either SectionAnchor or ContentAnchor that is being discussed
+    #[zome_fn("hc_public")]
+    fn create_comment(
+        target_address: Address,
+        text: String,
+        timestamp: u64,
+    ) -> ZomeApiResult<Address> {
+        comment::handlers::create(target_address, text, timestamp)
+    }
+
+    #[zome_fn("hc_public")]
+    fn reply_to_comment(
+        comment_anchor_address: Address,
+        text: String,
+        timestamp: u64,
+    ) -> ZomeApiResult<Address> {
+        comment::handlers::reply(comment_anchor_address, text, timestamp)
+    }
+
+    #[zome_fn("hc_public")]
+    fn get_latest_comment_entry(
+        comment_anchor_address: Address,
+    ) -> ZomeApiResult<Option<comment::entry::Comment>> {
+        comment::handlers::get_latest_comment_entry(comment_anchor_address)
+    }
+
+    #[zome_fn("hc_public")]
+    fn get_comments(target_address: Address) -> ZomeApiResult<Vec<Address>> {
+        comment::handlers::get_comments(&target_address)
+    }
+
+    #[zome_fn("hc_public")]
+    fn get_replies(comment_anchor_address: Address) -> ZomeApiResult<Vec<Address>> {
+        comment::handlers::get_replies(&comment_anchor_address)
+    }
+
+    #[zome_fn("hc_public")]
+    fn update_comment(
+        comment_anchor_address: Address,
+        text: String,
+        timestamp: u64,
+    ) -> ZomeApiResult<Address> {
+        comment::handlers::update(&comment_anchor_address, text, timestamp)
+    }
+
+    #[zome_fn("hc_public")]
+    fn hide_comment(comment_anchor_address: Address, timestamp: u64) -> ZomeApiResult<Address> {
+        comment::handlers::set_hidden(&comment_anchor_address, true, timestamp)
+    }
+
+    #[zome_fn("hc_public")]
+    fn unhide_comment(comment_anchor_address: Address, timestamp: u64) -> ZomeApiResult<Address> {
+        comment::handlers::set_hidden(&comment_anchor_address, false, timestamp)
+    }
+
+    #[zome_fn("hc_public")]
+    fn delete_comment(comment_anchor_address: Address) -> ZomeApiResult<Address> {
+        comment::handlers::delete(comment_anchor_address)
+    }
+    //  ====================== Q&A definitions
+    #[entry_def]
+    fn question_entry_definition() -> ValidatingEntryType {
+        question::entry::question_entry_def()
+    }
+
+    #[entry_def]
+    fn answer_entry_definition() -> ValidatingEntryType {
+        question::answer::answer_entry_def()
+    }
+
+    #[entry_def]
+    fn vote_entry_definition() -> ValidatingEntryType {
+        question::vote::vote_entry_def()
+    }
+
+    #[zome_fn("hc_public")]
+    fn ask_question(
+        course_anchor_address: Address,
+        title: String,
+        text: String,
+        timestamp: u64,
+    ) -> ZomeApiResult<Address> {
+        question::handlers::ask(course_anchor_address, title, text, timestamp)
+    }
+
+    #[zome_fn("hc_public")]
+    fn get_questions(
+        course_anchor_address: Address,
+        sort_by: question::handlers::QuestionSort,
+    ) -> ZomeApiResult<Vec<question::handlers::QuestionSummary>> {
+        question::handlers::get_questions(&course_anchor_address, sort_by)
+    }
+
+    #[zome_fn("hc_public")]
+    fn answer_question(
+        question_address: Address,
+        text: String,
+        timestamp: u64,
+    ) -> ZomeApiResult<Address> {
+        question::handlers::answer(question_address, text, timestamp)
+    }
+
+    #[zome_fn("hc_public")]
+    fn get_answers(
+        question_address: Address,
+    ) -> ZomeApiResult<Vec<question::handlers::AnswerSummary>> {
+        question::handlers::get_answers(&question_address)
+    }
+
+    #[zome_fn("hc_public")]
+    fn accept_answer(question_address: Address, answer_address: Address) -> ZomeApiResult<Address> {
+        question::handlers::accept_answer(question_address, answer_address)
+    }
+
+    #[zome_fn("hc_public")]
+    fn vote_for_answer(answer_address: Address) -> ZomeApiResult<Address> {
+        question::handlers::vote(answer_address)
+    }
+
+    #[zome_fn("hc_public")]
+    fn remove_vote_for_answer(answer_address: Address) -> ZomeApiResult<Address> {
+        question::handlers::remove_vote(answer_address)
+    }
+    //  ====================== Announcement definitions
+    #[entry_def]
+    fn announcement_entry_definition() -> ValidatingEntryType {
+        announcement::entry::announcement_entry_def()
+    }
+
+    #[entry_def]
+    fn received_announcement_entry_definition() -> ValidatingEntryType {
+        announcement::inbox::received_announcement_entry_def()
+    }
+
+    #[zome_fn("hc_public")]
+    fn create_announcement(
+        course_anchor_address: Address,
+        title: String,
+        text: String,
+        timestamp: u64,
+    ) -> ZomeApiResult<Address> {
+        announcement::handlers::create(course_anchor_address, title, text, timestamp)
+    }
+
+    // returns addresses of students who received the announcement
+    #[zome_fn("hc_public")]
+    fn deliver_announcement(announcement_address: Address) -> ZomeApiResult<Vec<Address>> {
+        announcement::handlers::deliver(announcement_address)
+    }
+
+    #[zome_fn("hc_public")]
+    fn get_announcements(course_anchor_address: Address) -> ZomeApiResult<Vec<Address>> {
+        announcement::handlers::get_announcements(&course_anchor_address)
+    }
+
+    #[zome_fn("hc_public")]
+    fn get_announcement_inbox() -> ZomeApiResult<Vec<announcement::inbox::ReceivedAnnouncement>> {
+        announcement::handlers::get_inbox()
+    }
+
+    //  ====================== Signal definitions
+    // sends the change made by the current agent to other members of the course so their UIs can refresh.
+    // Returns addresses of agents that received it
+    #[zome_fn("hc_public")]
+    fn notify_course_members(notice: signal::ChangeNotice) -> ZomeApiResult<Vec<Address>> {
+        signal::notify_course_members(notice)
+    }
+
+    //  ====================== Message definitions
+    #[entry_def]
+    fn message_entry_definition() -> ValidatingEntryType {
+        message::entry::message_entry_def()
+    }
+
+    #[zome_fn("hc_public")]
+    fn send_message(
+        to_agent: Address,
+        course_anchor_address: Address,
+        body: String,
+        timestamp: u64,
+    ) -> ZomeApiResult<Address> {
+        message::handlers::send(to_agent, course_anchor_address, body, timestamp)
+    }
+
+    #[zome_fn("hc_public")]
+    fn get_conversations() -> ZomeApiResult<Vec<message::handlers::Conversation>> {
+        message::handlers::get_conversations()
+    }
+
+    #[zome_fn("hc_public")]
+    fn get_messages(with_agent: Address) -> ZomeApiResult<Vec<message::entry::Message>> {
+        message::handlers::get_messages(with_agent)
+    }
+
+    //  ====================== Profile definitions
+    #[entry_def]
+    fn profile_anchor_entry_definition() -> ValidatingEntryType {
+        profile::anchor::profile_anchor_def()
+    }
+
+    #[entry_def]
+    fn username_anchor_entry_definition() -> ValidatingEntryType {
+        profile::anchor::username_anchor_def()
+    }
+
+    #[entry_def]
+    fn profile_entry_definition() -> ValidatingEntryType {
+        profile::entry::profile_entry_def()
+    }
+
+    #[zome_fn("hc_public")]
+    fn create_profile(
+        username: String,
+        display_name: String,
+        bio: String,
+        avatar: String,
+        timestamp: u64,
+    ) -> ZomeApiResult<Address> {
+        profile::handlers::create(username, display_name, bio, avatar, timestamp)
+    }
+
+    #[zome_fn("hc_public")]
+    fn update_profile(
+        display_name: String,
+        bio: String,
+        avatar: String,
+        timestamp: u64,
+    ) -> ZomeApiResult<Address> {
+        profile::handlers::update(display_name, bio, avatar, timestamp)
+    }
+
+    #[zome_fn("hc_public")]
+    fn get_profile(agent_address: Address) -> ZomeApiResult<Option<profile::entry::Profile>> {
+        profile::handlers::get_profile(agent_address)
+    }
+
+    #[zome_fn("hc_public")]
+    fn get_profiles(
+        agent_addresses: Vec<Address>,
+    ) -> ZomeApiResult<Vec<profile::handlers::AgentProfile>> {
+        profile::handlers::get_profiles(agent_addresses)
+    }
+
+    //  ====================== Role definitions
+    #[entry_def]
+    fn role_anchor_entry_definition() -> ValidatingEntryType {
+        role::anchor::role_anchor_def()
+    }
+
+    #[entry_def]
+    fn role_grant_entry_definition() -> ValidatingEntryType {
+        role::entry::role_grant_entry_def()
+    }
+
+    #[zome_fn("hc_public")]
+    fn grant_role(
+        agent_address: Address,
+        role: role::entry::Role,
+        timestamp: u64,
+    ) -> ZomeApiResult<Address> {
+        role::handlers::grant(agent_address, role, timestamp)
+    }
+
+    #[zome_fn("hc_public")]
+    fn revoke_role(agent_address: Address, role: role::entry::Role) -> ZomeApiResult<Vec<Address>> {
+        role::handlers::revoke(agent_address, role)
+    }
+
+    #[zome_fn("hc_public")]
+    fn get_my_roles() -> ZomeApiResult<Vec<role::entry::Role>> {
+        role::handlers::get_my_roles()
+    }
+
+    #[zome_fn("hc_public")]
+    fn list_teachers() -> ZomeApiResult<Vec<Address>> {
+        role::handlers::list_teachers()
+    }
+
+    //  ====================== Course bundle definitions
+    #[zome_fn("hc_public")]
+    fn export_course(
+        course_anchor_address: Address,
+    ) -> ZomeApiResult<bundle::format::CourseBundle> {
+        bundle::handlers::export(course_anchor_address)
+    }
+
+    #[zome_fn("hc_public")]
+    fn import_course(
+        bundle: bundle::format::CourseBundle,
+        timestamp: u64,
+    ) -> ZomeApiResult<bundle::format::ImportReport> {
+        bundle::handlers::import(bundle, timestamp)
+    }
+
+    //  ====================== Release definitions
+    #[entry_def]
+    fn enrolment_entry_definition() -> ValidatingEntryType {
+        release::enrolment::enrolment_entry_def()
+    }
+
+    //  ====================== Clock definitions
+    #[entry_def]
+    fn clock_tick_entry_definition() -> ValidatingEntryType {
+        clock::clock_tick_entry_def()
     }
 }
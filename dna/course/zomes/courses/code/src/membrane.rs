@@ -0,0 +1,95 @@
+use hdk::holochain_core_types::{
+    agent::AgentId,
+    signature::{Provenance, Signature},
+};
+use hdk::prelude::*;
+use hdk::AGENT_ADDRESS;
+
+use crate::error::ZomeError;
+use crate::helper;
+
+// name of the DNA property that lists addresses of agents who can invite others to the network.
+// If the DNA doesn't have this property, the network is open and every agent can join
+pub const INVITE_SIGNERS_PROPERTY: &str = "invite_signers";
+
+// Invitation to join the network, signed by one of the invite signers.
+// NOTE: hdk doesn't expose the membrane proof to the validate_agent callback yet, so the serialized
+// invitation is passed in the agent's nick instead (it's the agent "name" in the conductor config).
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct Invitation {
+    pub invitee_address: Address,
+    pub signer_address: Address,
+    pub signature: String,
+}
+
+impl Invitation {
+    // data that is signed by the invite signer. Invitation is only valid for a single agent
+    pub fn payload(invitee_address: &Address) -> String {
+        serde_json::json!({
+            "invitation_for": invitee_address,
+        })
+        .to_string()
+    }
+}
+
+fn get_invite_signers() -> ZomeApiResult<Option<Vec<Address>>> {
+    helper::get_address_list_property(INVITE_SIGNERS_PROPERTY)
+}
+
+pub fn validate_agent(agent: AgentId) -> Result<(), String> {
+    let invite_signers = match get_invite_signers()? {
+        Some(invite_signers) => invite_signers,
+        // this is an open network
+        None => return Ok(()),
+    };
+    let agent_address = agent.address();
+    // invite signers don't need an invitation to join
+    if invite_signers.contains(&agent_address) {
+        return Ok(());
+    }
+
+    let invitation: Invitation = serde_json::from_str(&agent.nick).map_err(|_| {
+        String::from(ZomeError::forbidden(
+            "Joining this network requires an invitation",
+        ))
+    })?;
+    if invitation.invitee_address != agent_address {
+        return Err(ZomeError::forbidden("This invitation was issued for another agent").into());
+    }
+    if !invite_signers.contains(&invitation.signer_address) {
+        return Err(ZomeError::forbidden(
+            "This invitation wasn't signed by any of the invite signers of this network",
+        )
+        .into());
+    }
+    let signature_valid = hdk::verify_signature(
+        Provenance::new(
+            invitation.signer_address,
+            Signature::from(invitation.signature),
+        ),
+        Invitation::payload(&invitation.invitee_address),
+    )?;
+    if !signature_valid {
+        return Err(ZomeError::forbidden("Invitation signature is invalid").into());
+    }
+    Ok(())
+}
+
+// signs an invitation for the agent at invitee_address. Only invite signers can create valid invitations
+pub fn sign_invitation(invitee_address: Address) -> ZomeApiResult<Invitation> {
+    match get_invite_signers()? {
+        Some(invite_signers) if invite_signers.contains(&AGENT_ADDRESS) => Ok(Invitation {
+            signature: hdk::sign(Invitation::payload(&invitee_address))?,
+            invitee_address,
+            signer_address: AGENT_ADDRESS.clone(),
+        }),
+        Some(_) => Err(ZomeError::forbidden(
+            "Only invite signers of this network can sign invitations",
+        )
+        .into()),
+        None => Err(ZomeError::invalid_input(
+            "This network is open so it doesn't need invitations",
+        )
+        .into()),
+    }
+}
@@ -0,0 +1,79 @@
+use hdk::prelude::*;
+use holochain_entry_utils::HolochainEntry;
+
+use super::validation;
+
+pub const MAX_BODY_LEN: usize = 5000;
+
+// Message between the teacher of the course and one of it's students.
+// NOTE: this entry is private: sender and receiver both commit the same message to their own source chains
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone, PartialEq)]
+pub struct Message {
+    pub body: String,
+    pub course_anchor_address: Address,
+    pub sender_address: Address,
+    pub receiver_address: Address,
+    pub timestamp: u64,
+}
+
+impl Message {
+    pub fn new(
+        body: String,
+        course_anchor_address: Address,
+        sender_address: Address,
+        receiver_address: Address,
+        timestamp: u64,
+    ) -> Self {
+        Message {
+            body,
+            course_anchor_address,
+            sender_address,
+            receiver_address,
+            timestamp,
+        }
+    }
+
+    // returns address of the other participant of the conversation this message belongs to
+    pub fn counterpart_address(&self, agent_address: &Address) -> Address {
+        if &self.sender_address == agent_address {
+            self.receiver_address.clone()
+        } else {
+            self.sender_address.clone()
+        }
+    }
+}
+
+impl HolochainEntry for Message {
+    fn entry_type() -> String {
+        String::from("message")
+    }
+}
+
+pub fn message_entry_def() -> ValidatingEntryType {
+    entry!(
+        name: Message::entry_type(),
+        description: "this is a private message between the teacher and a student of the course",
+        sharing: Sharing::Private,
+        validation_package: || {
+            hdk::ValidationPackageDefinition::Entry
+        },
+        validation: | validation_data: hdk::EntryValidationData<Message>| {
+            match validation_data {
+                EntryValidationData::Create { entry, validation_data } => {
+                    validation::create(entry, validation_data)
+                },
+                // NOTE: the symbol .. means that we're skipping unpacking parameters that we receive here
+                // because we won't need them
+                EntryValidationData::Modify { .. } => {
+                    validation::modify()
+                },
+                // agents can clean up their own copies of messages however they want
+                EntryValidationData::Delete { .. } => {
+                    Ok(())
+                }
+            }
+        },
+        // Message is private so nothing on the DHT can link to it
+        links: []
+    )
+}
@@ -0,0 +1,97 @@
+use hdk::prelude::*;
+use hdk::AGENT_ADDRESS;
+use holochain_entry_utils::HolochainEntry;
+use std::collections::HashMap;
+
+use super::entry::Message;
+use crate::direct_message::{self, DirectMessage};
+use crate::error::ZomeError;
+use crate::helper;
+
+// name of the signal that is emitted to the UI when a new message is received
+pub const MESSAGE_RECEIVED_SIGNAL: &str = "message_received";
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct Conversation {
+    pub with_agent: Address,
+    pub message_count: usize,
+    pub last_message: Message,
+}
+
+pub fn send(
+    to_agent: Address,
+    course_anchor_address: Address,
+    body: String,
+    timestamp: u64,
+) -> ZomeApiResult<Address> {
+    if &to_agent == *AGENT_ADDRESS {
+        return Err(ZomeError::invalid_input("Can't send a message to yourself").into());
+    }
+    let message = Message::new(
+        body,
+        course_anchor_address,
+        AGENT_ADDRESS.clone(),
+        to_agent.clone(),
+        timestamp,
+    );
+    // NOTE: we only store our copy of the message after the receiver accepted theirs
+    // so both copies are always in sync
+    direct_message::send(
+        to_agent,
+        DirectMessage::Message(message.clone()),
+        direct_message::SEND_TIMEOUT_MS,
+    )?;
+    hdk::commit_entry(&message.entry())
+}
+
+// called from the zome's receive callback when another agent sends us a message
+pub fn receive(from_address: Address, message: Message) -> ZomeApiResult<()> {
+    if from_address != message.sender_address {
+        return Err(ZomeError::forbidden("Message has to be sent by it's sender").into());
+    }
+    if &message.receiver_address != *AGENT_ADDRESS {
+        return Err(ZomeError::forbidden("Message isn't addressed to this agent").into());
+    }
+    hdk::commit_entry(&message.clone().entry())?;
+    hdk::emit_signal(MESSAGE_RECEIVED_SIGNAL, message)?;
+    Ok(())
+}
+
+fn get_my_messages() -> ZomeApiResult<Vec<Message>> {
+    let mut messages: Vec<Message> = helper::get_my_entries::<Message>()?
+        .into_iter()
+        .map(|(message, _address)| message)
+        .collect();
+    messages.sort_by_key(|message| message.timestamp);
+    Ok(messages)
+}
+
+// lists all agents this agent has exchanged messages with, most recently active conversations first
+pub fn get_conversations() -> ZomeApiResult<Vec<Conversation>> {
+    let mut conversations: HashMap<Address, Conversation> = HashMap::new();
+    for message in get_my_messages()? {
+        let with_agent = message.counterpart_address(&AGENT_ADDRESS);
+        conversations
+            .entry(with_agent.clone())
+            .and_modify(|conversation| {
+                conversation.message_count += 1;
+                conversation.last_message = message.clone();
+            })
+            .or_insert(Conversation {
+                with_agent,
+                message_count: 1,
+                last_message: message,
+            });
+    }
+    let mut conversations: Vec<Conversation> = conversations.into_iter().map(|(_, c)| c).collect();
+    conversations.sort_by(|a, b| b.last_message.timestamp.cmp(&a.last_message.timestamp));
+    Ok(conversations)
+}
+
+// lists all messages exchanged with with_agent in chronological order
+pub fn get_messages(with_agent: Address) -> ZomeApiResult<Vec<Message>> {
+    Ok(get_my_messages()?
+        .into_iter()
+        .filter(|message| message.counterpart_address(&AGENT_ADDRESS) == with_agent)
+        .collect())
+}
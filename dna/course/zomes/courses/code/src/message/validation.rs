@@ -0,0 +1,50 @@
+use hdk::ValidationData;
+
+use super::entry::{Message, MAX_BODY_LEN};
+use crate::course;
+use crate::course::anchor::CourseAnchor;
+use crate::error::ZomeError;
+
+pub fn create(entry: Message, validation_data: ValidationData) -> Result<(), String> {
+    // both sender and receiver store their own copy of the message
+    let sources = validation_data.sources();
+    if !sources.contains(&entry.sender_address) && !sources.contains(&entry.receiver_address) {
+        return Err(ZomeError::forbidden(
+            "Only the sender or the receiver of the message can store it",
+        )
+        .into());
+    }
+    if entry.body.is_empty() {
+        return Err(ZomeError::invalid_value("message", "body", "can't be empty").into());
+    }
+    if entry.body.len() > MAX_BODY_LEN {
+        return Err(ZomeError::text_too_long("message", "body", MAX_BODY_LEN).into());
+    }
+
+    // messages are only allowed between the teacher of the course and it's students
+    let course_anchor: CourseAnchor = hdk::utils::get_as_type(entry.course_anchor_address.clone())?;
+    let student_address = if entry.sender_address == course_anchor.teacher_address {
+        &entry.receiver_address
+    } else if entry.receiver_address == course_anchor.teacher_address {
+        &entry.sender_address
+    } else {
+        return Err(ZomeError::forbidden(
+            "Messages can only be sent between the teacher of the course and it's students",
+        )
+        .into());
+    };
+    let students = course::handlers::get_students(entry.course_anchor_address.clone())?;
+    if !students.contains(student_address) {
+        return Err(ZomeError::forbidden(
+            "Messages can only be sent between the teacher of the course and it's students",
+        )
+        .into());
+    }
+    Ok(())
+}
+
+// NOTE: we don't accept any parameters here because we don't need them to always return an error
+// because message that was already sent can't be changed
+pub fn modify() -> Result<(), String> {
+    Err(ZomeError::immutable_entry("message").into())
+}
@@ -0,0 +1,133 @@
+use hdk::prelude::*;
+use holochain_entry_utils::HolochainEntry;
+
+use super::entry::Profile;
+use super::validation;
+use crate::anchor_trait::AnchorTrait;
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct ProfileAnchor {
+    // NOTE: there are no other fields here on purpose: this makes the anchor address the same for
+    // every profile of an agent, so each agent can only have one profile and anyone can find it by agent address
+    pub agent_address: Address,
+}
+
+impl AnchorTrait for ProfileAnchor {
+    fn entry_type() -> String {
+        String::from("profile_anchor")
+    }
+    fn link_to() -> String {
+        Profile::entry_type()
+    }
+    fn link_type() -> String {
+        "profile_anchor->profile".to_owned()
+    }
+}
+
+impl ProfileAnchor {
+    pub fn new(agent_address: Address) -> Self {
+        ProfileAnchor { agent_address }
+    }
+}
+
+pub fn profile_anchor_def() -> ValidatingEntryType {
+    entry!(
+        name: ProfileAnchor::entry_type(),
+        description: "Anchor to the valid agent profile",
+        sharing: Sharing::Public,
+        validation_package: || {
+            hdk::ValidationPackageDefinition::Entry
+        },
+        validation: | validation_data: hdk::EntryValidationData<ProfileAnchor>| {
+            match validation_data{
+                EntryValidationData::Create { entry, validation_data } => {
+                    validation::anchor_create(entry, validation_data)
+                 },
+                 // NOTE: the symbol .. means that we're skipping unpacking parameters that we receive here
+                 // because we won't need them
+                 EntryValidationData::Modify { .. } => {
+                    validation::anchor_modify()
+                 },
+                 EntryValidationData::Delete { .. } => {
+                    validation::anchor_delete()
+                 }
+            }
+        },
+        links:[
+            // link that connects ProfileAnchor to the latest Profile entry
+            to!(
+                ProfileAnchor::link_to(),
+                link_type: ProfileAnchor::link_type(),
+                validation_package:||{
+                    hdk::ValidationPackageDefinition::Entry
+                },
+                validation:|validation_data: hdk::LinkValidationData|{
+                    validation::anchor_to_profile_link(validation_data)
+                }
+            )
+        ]
+    )
+}
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct UsernameAnchor {
+    // NOTE: username is the only field here on purpose: every agent who wants to use this username
+    // would get the same anchor address, so we can check if it's already taken
+    pub username: String,
+}
+
+impl AnchorTrait for UsernameAnchor {
+    fn entry_type() -> String {
+        String::from("username_anchor")
+    }
+    fn link_to() -> String {
+        ProfileAnchor::entry_type()
+    }
+    fn link_type() -> String {
+        "username_anchor->profile_anchor".to_owned()
+    }
+}
+
+impl UsernameAnchor {
+    pub fn new(username: String) -> Self {
+        UsernameAnchor { username }
+    }
+}
+
+pub fn username_anchor_def() -> ValidatingEntryType {
+    entry!(
+        name: UsernameAnchor::entry_type(),
+        description: "Anchor that reserves a username for a single agent",
+        sharing: Sharing::Public,
+        validation_package: || {
+            hdk::ValidationPackageDefinition::Entry
+        },
+        validation: | validation_data: hdk::EntryValidationData<UsernameAnchor>| {
+            match validation_data{
+                EntryValidationData::Create { entry, .. } => {
+                    validation::validate_username(&entry.username)
+                 },
+                 EntryValidationData::Modify { .. } => {
+                    validation::username_anchor_modify()
+                 },
+                 EntryValidationData::Delete { .. } => {
+                    validation::username_anchor_delete()
+                 }
+            }
+        },
+        links:[
+            // link that connects username to the profile of the agent who claimed it.
+            // Only one agent can claim each username
+            to!(
+                UsernameAnchor::link_to(),
+                link_type: UsernameAnchor::link_type(),
+                validation_package:||{
+                    hdk::ValidationPackageDefinition::Entry
+                },
+                validation:|validation_data: hdk::LinkValidationData|{
+                    validation::username_anchor_to_profile_anchor_link(validation_data)
+                }
+            )
+        ]
+    )
+}
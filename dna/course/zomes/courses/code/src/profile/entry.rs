@@ -0,0 +1,76 @@
+use hdk::prelude::*;
+use holochain_entry_utils::HolochainEntry;
+
+use super::validation;
+
+pub const MIN_USERNAME_LEN: usize = 3;
+pub const MAX_USERNAME_LEN: usize = 30;
+pub const MAX_DISPLAY_NAME_LEN: usize = 50;
+pub const MAX_BIO_LEN: usize = 1000;
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct Profile {
+    // NOTE: username is unique across the whole network and can't be changed once the profile is created
+    pub username: String,
+    pub display_name: String,
+    pub bio: String,
+    // reference to the avatar image (e.g. it's URL). Empty if agent doesn't have an avatar
+    pub avatar: String,
+    pub agent_address: Address,
+    pub timestamp: u64,
+    pub anchor_address: Address,
+}
+
+impl Profile {
+    pub fn new(
+        username: String,
+        display_name: String,
+        bio: String,
+        avatar: String,
+        agent_address: Address,
+        timestamp: u64,
+        anchor_address: Address,
+    ) -> Self {
+        Profile {
+            username,
+            display_name,
+            bio,
+            avatar,
+            agent_address,
+            timestamp,
+            anchor_address,
+        }
+    }
+}
+
+impl HolochainEntry for Profile {
+    fn entry_type() -> String {
+        String::from("profile")
+    }
+}
+
+pub fn profile_entry_def() -> ValidatingEntryType {
+    entry!(
+        name: Profile::entry_type(),
+        description: "this is the public profile of an agent",
+        sharing: Sharing::Public,
+        validation_package: || {
+            hdk::ValidationPackageDefinition::Entry
+        },
+        validation: | validation_data: hdk::EntryValidationData<Profile>| {
+            match validation_data {
+                EntryValidationData::Create { entry, validation_data } => {
+                    validation::create(entry, validation_data)
+                },
+                EntryValidationData::Modify { new_entry, old_entry, old_entry_header, validation_data } => {
+                    validation::modify(new_entry, old_entry, old_entry_header, validation_data)
+                },
+                EntryValidationData::Delete { old_entry, old_entry_header, validation_data } => {
+                    validation::delete(old_entry, old_entry_header, validation_data)
+                }
+            }
+        },
+        // NOTE: Profile is linked from the ProfileAnchor so links are defined in profile_anchor_def
+        links: []
+    )
+}
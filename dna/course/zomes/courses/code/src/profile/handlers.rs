@@ -0,0 +1,142 @@
+use hdk::prelude::*;
+use hdk::AGENT_ADDRESS;
+use holochain_entry_utils::HolochainEntry;
+
+use super::anchor::{ProfileAnchor, UsernameAnchor};
+use super::entry::Profile;
+use crate::anchor_trait::AnchorTrait;
+use crate::error::ZomeError;
+use crate::helper;
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct AgentProfile {
+    pub agent_address: Address,
+    // None if agent hasn't created their profile yet
+    pub profile: Option<Profile>,
+}
+
+pub fn create(
+    username: String,
+    display_name: String,
+    bio: String,
+    avatar: String,
+    timestamp: u64,
+) -> ZomeApiResult<Address> {
+    let profile_anchor = ProfileAnchor::new(AGENT_ADDRESS.clone());
+    // ProfileAnchor address is the same for every profile of this agent, so if it
+    // exists, this agent has already created their profile
+    if hdk::get_entry(&profile_anchor.address()?)?.is_some() {
+        return Err(ZomeError::already_exists(
+            "profile",
+            "You've already created your profile, update it instead",
+        )
+        .into());
+    }
+    let username_anchor = UsernameAnchor::new(username.clone());
+    // check this early to give a nice error: validation would reject it anyway
+    let claimed_by = hdk::get_links(
+        &username_anchor.address()?,
+        LinkMatch::Exactly(&UsernameAnchor::link_type()),
+        LinkMatch::Any,
+    )?;
+    if !claimed_by.addresses().is_empty() {
+        return Err(ZomeError::already_exists(
+            "username",
+            &format!("Username {} is already taken", username),
+        )
+        .into());
+    }
+
+    let profile_anchor_address = hdk::commit_entry(&profile_anchor.entry())?;
+
+    let new_profile = Profile::new(
+        username,
+        display_name,
+        bio,
+        avatar,
+        AGENT_ADDRESS.clone(),
+        timestamp,
+        profile_anchor_address.clone(),
+    );
+    let new_profile_address = hdk::commit_entry(&new_profile.entry())?;
+    hdk::link_entries(
+        &profile_anchor_address,
+        &new_profile_address,
+        ProfileAnchor::link_type(),
+        "".to_owned(),
+    )?;
+
+    // claim the username. NOTE: this goes after the profile is linked to it's anchor
+    // because validation checks that the username belongs to the profile
+    let username_anchor_address = hdk::commit_entry(&username_anchor.entry())?;
+    hdk::link_entries(
+        &username_anchor_address,
+        &profile_anchor_address,
+        UsernameAnchor::link_type(),
+        "".to_owned(),
+    )?;
+
+    // ProfileAnchor serves as this profile's ID so we return it
+    Ok(profile_anchor_address)
+}
+
+pub fn get_latest_profile(agent_address: &Address) -> ZomeApiResult<Option<(Profile, Address)>> {
+    let profile_anchor_address = ProfileAnchor::new(agent_address.clone()).address()?;
+    helper::get_latest_data_entry::<Profile>(&profile_anchor_address, &ProfileAnchor::link_type())
+}
+
+pub fn get_profile(agent_address: Address) -> ZomeApiResult<Option<Profile>> {
+    match get_latest_profile(&agent_address)? {
+        Some((profile_entry, _profile_entry_address)) => Ok(Some(profile_entry)),
+        None => Ok(None),
+    }
+}
+
+pub fn get_profiles(agent_addresses: Vec<Address>) -> ZomeApiResult<Vec<AgentProfile>> {
+    agent_addresses
+        .into_iter()
+        .map(|agent_address| {
+            Ok(AgentProfile {
+                profile: get_profile(agent_address.clone())?,
+                agent_address,
+            })
+        })
+        .collect()
+}
+
+pub fn update(
+    display_name: String,
+    bio: String,
+    avatar: String,
+    timestamp: u64,
+) -> ZomeApiResult<Address> {
+    match get_latest_profile(&AGENT_ADDRESS)? {
+        Some((mut previous_profile, previous_profile_address)) => {
+            previous_profile.display_name = display_name;
+            previous_profile.bio = bio;
+            previous_profile.avatar = avatar;
+            previous_profile.timestamp = timestamp;
+            let profile_anchor_address = previous_profile.anchor_address.clone();
+
+            let new_profile_address =
+                hdk::update_entry(previous_profile.entry(), &previous_profile_address)?;
+            // remove link to previous version of profile
+            hdk::remove_link(
+                &profile_anchor_address,
+                &previous_profile_address,
+                ProfileAnchor::link_type(),
+                "".to_owned(),
+            )?;
+            // create link to new version of profile
+            hdk::link_entries(
+                &profile_anchor_address,
+                &new_profile_address,
+                ProfileAnchor::link_type(),
+                "".to_owned(),
+            )?;
+
+            Ok(profile_anchor_address)
+        }
+        None => Err(ZomeError::not_found("profile").into()),
+    }
+}
@@ -0,0 +1,238 @@
+use hdk::holochain_core_types::chain_header::ChainHeader;
+use hdk::prelude::*;
+use hdk::{LinkValidationData, ValidationData};
+
+use super::{
+    anchor::{ProfileAnchor, UsernameAnchor},
+    entry::{Profile, MAX_BIO_LEN, MAX_DISPLAY_NAME_LEN, MAX_USERNAME_LEN, MIN_USERNAME_LEN},
+    handlers,
+};
+use crate::anchor_trait::AnchorTrait;
+use crate::error::ZomeError;
+use crate::helper;
+
+pub fn validate_username(username: &str) -> Result<(), String> {
+    if username.len() < MIN_USERNAME_LEN || username.len() > MAX_USERNAME_LEN {
+        return Err(ZomeError::invalid_value(
+            "profile",
+            "username",
+            &format!(
+                "has to be from {} to {} characters long",
+                MIN_USERNAME_LEN, MAX_USERNAME_LEN
+            ),
+        )
+        .into());
+    }
+    if !username
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    {
+        return Err(ZomeError::invalid_value(
+            "profile",
+            "username",
+            "can only contain lowercase latin letters, digits and underscores",
+        )
+        .into());
+    }
+    Ok(())
+}
+
+// validates that username isn't claimed by any agent other than agent_address
+fn validate_username_is_free(username: &str, agent_address: &Address) -> Result<(), String> {
+    let profile_anchor_address = ProfileAnchor::new(agent_address.clone()).address()?;
+    let claimed_by = hdk::get_links(
+        &UsernameAnchor::new(username.to_owned()).address()?,
+        LinkMatch::Exactly(&UsernameAnchor::link_type()),
+        LinkMatch::Any,
+    )?
+    .addresses();
+    if claimed_by
+        .iter()
+        .any(|address| address != &profile_anchor_address)
+    {
+        return Err(ZomeError::already_exists(
+            "username",
+            &format!("Username {} is already taken", username),
+        )
+        .into());
+    }
+    Ok(())
+}
+
+fn validate_profile_content(profile: &Profile) -> Result<(), String> {
+    validate_username(&profile.username)?;
+    if profile.display_name.len() > MAX_DISPLAY_NAME_LEN {
+        return Err(
+            ZomeError::text_too_long("profile", "display_name", MAX_DISPLAY_NAME_LEN).into(),
+        );
+    }
+    if profile.bio.len() > MAX_BIO_LEN {
+        return Err(ZomeError::text_too_long("profile", "bio", MAX_BIO_LEN).into());
+    }
+    Ok(())
+}
+
+pub fn create(entry: Profile, validation_data: ValidationData) -> Result<(), String> {
+    helper::validate_only_author_can_do(
+        &entry.agent_address,
+        validation_data.sources(),
+        "profile",
+        "create",
+    )?;
+    validate_profile_content(&entry)?;
+    validate_username_is_free(&entry.username, &entry.agent_address)
+}
+
+pub fn modify(
+    new_entry: Profile,
+    old_entry: Profile,
+    _old_entry_header: ChainHeader,
+    validation_data: ValidationData,
+) -> Result<(), String> {
+    helper::validate_only_author_can_do(
+        &old_entry.agent_address,
+        validation_data.sources(),
+        "profile",
+        "modify",
+    )?;
+    if new_entry.agent_address != old_entry.agent_address {
+        return Err(ZomeError::immutable_field("profile", "owner").into());
+    }
+    if new_entry.username != old_entry.username {
+        return Err(ZomeError::immutable_field("profile", "username").into());
+    }
+    validate_profile_content(&new_entry)
+}
+
+pub fn delete(
+    entry: Profile,
+    _entry_header: ChainHeader,
+    validation_data: ValidationData,
+) -> Result<(), String> {
+    helper::validate_only_author_can_do(
+        &entry.agent_address,
+        validation_data.sources(),
+        "profile",
+        "delete",
+    )
+}
+
+// =========================== ProfileAnchor validation
+pub fn anchor_create(entry: ProfileAnchor, validation_data: ValidationData) -> Result<(), String> {
+    helper::validate_only_author_can_do(
+        &entry.agent_address,
+        validation_data.sources(),
+        "profile",
+        "create",
+    )
+}
+
+// NOTE: we don't accept any parameters here because we don't need them to always return an error
+// because this anchor can never be modified
+pub fn anchor_modify() -> Result<(), String> {
+    Err(ZomeError::immutable_entry("profile_anchor").into())
+}
+
+// NOTE: ProfileAnchor can't be deleted because it's address is bound to the agent
+// and we don't want someone else's username to point to nothing
+pub fn anchor_delete() -> Result<(), String> {
+    Err(ZomeError::forbidden("Can't delete the ProfileAnchor entry: it can only be created").into())
+}
+
+// =========================== UsernameAnchor validation
+pub fn username_anchor_modify() -> Result<(), String> {
+    Err(ZomeError::immutable_entry("username_anchor").into())
+}
+
+// NOTE: usernames are never released, otherwise someone else could impersonate the agent who used it before
+pub fn username_anchor_delete() -> Result<(), String> {
+    Err(
+        ZomeError::forbidden("Can't delete the UsernameAnchor entry: it can only be created")
+            .into(),
+    )
+}
+
+//  =========================== ProfileAnchor and UsernameAnchor links validation
+pub fn anchor_to_profile_link(validation_data: LinkValidationData) -> Result<(), String> {
+    match validation_data {
+        hdk::LinkValidationData::LinkAdd {
+            link,
+            validation_data,
+        } => {
+            // get author of this entry
+            let author = validation_data.package.chain_header.provenances()[0].source();
+            // get link base: entry from which the link goes
+            let base: ProfileAnchor = hdk::utils::get_as_type(link.link.base().clone())?;
+            // get link target: entry to which the link goes
+            let target: Profile = hdk::utils::get_as_type(link.link.target().clone())?;
+            if base.agent_address != target.agent_address {
+                return Err(ZomeError::invalid_link(
+                    "profile_anchor->profile",
+                    "Can't link ProfileAnchor to Profile because they belong to different agents",
+                )
+                .into());
+            } else if author != base.agent_address {
+                return Err(ZomeError::invalid_link(
+                    "profile_anchor->profile",
+                    "Can't link ProfileAnchor to Profile because it's not your profile",
+                )
+                .into());
+            }
+            Ok(())
+        }
+        hdk::LinkValidationData::LinkRemove {
+            link,
+            validation_data,
+        } => {
+            let author = validation_data.package.chain_header.provenances()[0].source();
+            let base: ProfileAnchor = hdk::utils::get_as_type(link.link.base().clone())?;
+            if author != base.agent_address {
+                return Err(ZomeError::invalid_link(
+                    "profile_anchor->profile",
+                    "Can't remove link from ProfileAnchor to Profile because it's not your profile",
+                )
+                .into());
+            }
+            Ok(())
+        }
+    }
+}
+
+pub fn username_anchor_to_profile_anchor_link(
+    validation_data: LinkValidationData,
+) -> Result<(), String> {
+    match validation_data {
+        hdk::LinkValidationData::LinkAdd {
+            link,
+            validation_data,
+        } => {
+            let author = validation_data.package.chain_header.provenances()[0].source();
+            let base: UsernameAnchor = hdk::utils::get_as_type(link.link.base().clone())?;
+            let target: ProfileAnchor = hdk::utils::get_as_type(link.link.target().clone())?;
+            if author != target.agent_address {
+                return Err(ZomeError::invalid_link(
+                    "username_anchor->profile_anchor",
+                    "Can't claim a username for someone else's profile",
+                )
+                .into());
+            }
+            // NOTE: username of the profile can't be changed, so any version of it will do
+            let profile_username = handlers::get_latest_profile(&target.agent_address)?
+                .map(|(profile, _profile_address)| profile.username);
+            if profile_username.as_ref() != Some(&base.username) {
+                return Err(ZomeError::invalid_link(
+                    "username_anchor->profile_anchor",
+                    "Can't claim a username that isn't the username of your profile",
+                )
+                .into());
+            }
+            validate_username_is_free(&base.username, &target.agent_address)
+        }
+        // NOTE: usernames are never released, see username_anchor_delete
+        hdk::LinkValidationData::LinkRemove { .. } => Err(ZomeError::invalid_link(
+            "username_anchor->profile_anchor",
+            "Can't remove link from UsernameAnchor to ProfileAnchor: usernames can't be released",
+        )
+        .into()),
+    }
+}
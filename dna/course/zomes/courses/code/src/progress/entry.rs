@@ -0,0 +1,70 @@
+use hdk::prelude::*;
+use holochain_entry_utils::HolochainEntry;
+
+use super::validation;
+
+// Marks that a student has completed a particular content.
+// NOTE: this entry is private so it's only stored on the student's own source chain:
+// nobody except the student can see their progress through the course
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct ContentCompletion {
+    // NOTE: this is the address of the ContentAnchor. Completions recorded before ContentAnchor was introduced
+    // hold the address of the Content entry instead, so we've kept the field name to still be able to read them
+    pub content_address: Address,
+    pub section_anchor_address: Address,
+    pub course_address: Address,
+    pub student_address: Address,
+    pub timestamp: u64,
+}
+
+impl ContentCompletion {
+    pub fn new(
+        content_address: Address,
+        section_anchor_address: Address,
+        course_address: Address,
+        student_address: Address,
+        timestamp: u64,
+    ) -> Self {
+        ContentCompletion {
+            content_address,
+            section_anchor_address,
+            course_address,
+            student_address,
+            timestamp,
+        }
+    }
+}
+
+impl HolochainEntry for ContentCompletion {
+    fn entry_type() -> String {
+        String::from("content_completion")
+    }
+}
+
+pub fn content_completion_entry_def() -> ValidatingEntryType {
+    entry!(
+        name: ContentCompletion::entry_type(),
+        description: "this is a private record that a student has completed the content",
+        sharing: Sharing::Private,
+        validation_package: || {
+            hdk::ValidationPackageDefinition::Entry
+        },
+        validation: | validation_data: hdk::EntryValidationData<ContentCompletion>| {
+            match validation_data {
+                EntryValidationData::Create { entry, validation_data } => {
+                    validation::create(entry, validation_data)
+                },
+                // NOTE: the symbol .. means that we're skipping unpacking parameters that we receive here
+                // because we won't need them
+                EntryValidationData::Modify { .. } => {
+                    validation::modify()
+                },
+                EntryValidationData::Delete { old_entry, old_entry_header, validation_data } => {
+                    validation::delete(old_entry, old_entry_header, validation_data)
+                }
+            }
+        },
+        // ContentCompletion is private so nothing on the DHT can link to it
+        links: []
+    )
+}
@@ -0,0 +1,136 @@
+use hdk::prelude::*;
+use hdk::AGENT_ADDRESS;
+use holochain_entry_utils::HolochainEntry;
+use std::collections::HashSet;
+
+use super::entry::ContentCompletion;
+use crate::content;
+use crate::content::anchor::ContentAnchor;
+use crate::course;
+use crate::error::ZomeError;
+use crate::helper;
+use crate::section;
+use crate::section::anchor::SectionAnchor;
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct SectionProgress {
+    pub section_anchor_address: Address,
+    pub completed: usize,
+    pub total: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct CourseProgress {
+    pub course_anchor_address: Address,
+    // sections are in the same order as they're displayed: each section is followed by it's subsections
+    pub sections: Vec<SectionProgress>,
+    pub completed: usize,
+    pub total: usize,
+    // percentage of completed content in the whole course, from 0 to 100
+    pub percentage: f64,
+}
+
+pub fn mark_content_done(
+    content_anchor_address: Address,
+    timestamp: u64,
+) -> ZomeApiResult<Address> {
+    // retrieve content anchor. If it doesn't exist, we'll fail with error here so we're also validating input
+    let content_anchor: ContentAnchor = hdk::utils::get_as_type(content_anchor_address.clone())?;
+
+    // marking content as done twice shouldn't create duplicate records, so we return the existing one
+    let existing_completion = helper::get_my_entries::<ContentCompletion>()?
+        .into_iter()
+        .find(|(completion, _address)| completion.content_address == content_anchor_address);
+    if let Some((_completion, completion_address)) = existing_completion {
+        return Ok(completion_address);
+    }
+
+    let section_anchor: SectionAnchor =
+        hdk::utils::get_as_type(content_anchor.section_anchor_address.clone())?;
+    let completion = ContentCompletion::new(
+        content_anchor_address,
+        content_anchor.section_anchor_address,
+        section_anchor.course_address,
+        AGENT_ADDRESS.clone(),
+        timestamp,
+    );
+    hdk::commit_entry(&completion.entry())
+}
+
+// returns addresses of contents of the course that were completed by the current agent
+pub fn get_completed_contents(course_anchor_address: &Address) -> ZomeApiResult<HashSet<Address>> {
+    let mut completed_contents = HashSet::new();
+    for (completion, _address) in helper::get_my_entries::<ContentCompletion>()? {
+        if &completion.course_address != course_anchor_address {
+            continue;
+        }
+        // completions recorded before ContentAnchor was introduced hold the address of the Content entry,
+        // so they count for the anchor this content was migrated to
+        if let Some(content_anchor_address) =
+            content::handlers::get_migrated_anchor(&completion.content_address)?
+        {
+            completed_contents.insert(content_anchor_address);
+        }
+        completed_contents.insert(completion.content_address);
+    }
+    Ok(completed_contents)
+}
+
+// checks if all contents of the section and it's subsections are in completed_contents of the current agent
+pub fn is_section_completed(
+    section_anchor_address: &Address,
+    completed_contents: &HashSet<Address>,
+) -> ZomeApiResult<bool> {
+    for address in section::handlers::with_subsections(&[section_anchor_address.clone()])? {
+        for content_anchor_address in content::handlers::get_contents(&address)? {
+            if !completed_contents.contains(&content_anchor_address) {
+                return Ok(false);
+            }
+        }
+    }
+    Ok(true)
+}
+
+pub fn get_my_progress(course_anchor_address: Address) -> ZomeApiResult<CourseProgress> {
+    let latest_course_result = course::handlers::get_latest_course(&course_anchor_address)?;
+    match latest_course_result {
+        Some((course, _course_address)) => {
+            let completed_contents = get_completed_contents(&course_anchor_address)?;
+
+            // we're counting against the current state of the course: content that was completed
+            // but later removed from the section doesn't count anymore
+            let mut sections = Vec::new();
+            for section_anchor_address in section::handlers::with_subsections(&course.sections)? {
+                let contents = content::handlers::get_contents(&section_anchor_address)?;
+                let mut completed = 0;
+                for content_anchor_address in contents.iter() {
+                    if completed_contents.contains(content_anchor_address) {
+                        completed += 1;
+                    }
+                }
+                sections.push(SectionProgress {
+                    section_anchor_address,
+                    completed,
+                    total: contents.len(),
+                });
+            }
+
+            let completed: usize = sections.iter().map(|section| section.completed).sum();
+            let total: usize = sections.iter().map(|section| section.total).sum();
+            let percentage = if total == 0 {
+                0.0
+            } else {
+                completed as f64 * 100.0 / total as f64
+            };
+
+            Ok(CourseProgress {
+                course_anchor_address,
+                sections,
+                completed,
+                total,
+                percentage,
+            })
+        }
+        None => Err(ZomeError::deleted("course", "get progress for").into()),
+    }
+}
@@ -0,0 +1,37 @@
+use hdk::holochain_core_types::chain_header::ChainHeader;
+use hdk::ValidationData;
+
+use super::entry::ContentCompletion;
+use crate::content::anchor::ContentAnchor;
+use crate::error::ZomeError;
+
+pub fn create(entry: ContentCompletion, validation_data: ValidationData) -> Result<(), String> {
+    if !validation_data.sources().contains(&entry.student_address) {
+        return Err(ZomeError::forbidden("Students can only record their own progress").into());
+    }
+    let content_anchor: ContentAnchor = hdk::utils::get_as_type(entry.content_address.clone())?;
+    if content_anchor.section_anchor_address != entry.section_anchor_address {
+        return Err(ZomeError::invalid_input(
+            "Completed content has to belong to the section it's recorded for",
+        )
+        .into());
+    }
+    Ok(())
+}
+
+// NOTE: we don't accept any parameters here because we don't need them to always return an error
+// because completed content stays completed
+pub fn modify() -> Result<(), String> {
+    Err(ZomeError::immutable_entry("content_completion").into())
+}
+
+pub fn delete(
+    entry: ContentCompletion,
+    _entry_header: ChainHeader,
+    validation_data: ValidationData,
+) -> Result<(), String> {
+    if !validation_data.sources().contains(&entry.student_address) {
+        return Err(ZomeError::forbidden("Students can only delete their own progress").into());
+    }
+    Ok(())
+}
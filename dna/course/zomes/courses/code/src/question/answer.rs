@@ -0,0 +1,87 @@
+use hdk::prelude::*;
+use holochain_entry_utils::HolochainEntry;
+
+use super::entry::Question;
+use super::validation;
+
+pub const QUESTION_TO_ANSWER_LINK: &str = "question->answer";
+// there's at most one such link per question: accepting another answer replaces it
+pub const QUESTION_TO_ACCEPTED_ANSWER_LINK: &str = "question->accepted_answer";
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct Answer {
+    pub text: String,
+    pub question_address: Address,
+    pub author_address: Address,
+    pub timestamp: u64,
+}
+
+impl Answer {
+    pub fn new(
+        text: String,
+        question_address: Address,
+        author_address: Address,
+        timestamp: u64,
+    ) -> Self {
+        Answer {
+            text,
+            question_address,
+            author_address,
+            timestamp,
+        }
+    }
+}
+
+impl HolochainEntry for Answer {
+    fn entry_type() -> String {
+        String::from("answer")
+    }
+}
+
+pub fn answer_entry_def() -> ValidatingEntryType {
+    entry!(
+        name: Answer::entry_type(),
+        description: "this is an answer to the question on the course's Q&A board",
+        sharing: Sharing::Public,
+        validation_package: || {
+            hdk::ValidationPackageDefinition::Entry
+        },
+        validation: | validation_data: hdk::EntryValidationData<Answer>| {
+            match validation_data {
+                EntryValidationData::Create { entry, validation_data } => {
+                    validation::answer_create(entry, validation_data)
+                },
+                EntryValidationData::Modify { new_entry, old_entry, old_entry_header, validation_data } => {
+                    validation::answer_modify(new_entry, old_entry, old_entry_header, validation_data)
+                },
+                EntryValidationData::Delete { old_entry, old_entry_header, validation_data } => {
+                    validation::answer_delete(old_entry, old_entry_header, validation_data)
+                }
+            }
+        },
+        links: [
+            // link from the question this answer was given to
+            from!(
+                Question::entry_type(),
+                link_type: QUESTION_TO_ANSWER_LINK,
+                validation_package: || {
+                    hdk::ValidationPackageDefinition::Entry
+                },
+                validation: | validation_data: hdk::LinkValidationData | {
+                    validation::question_to_answer_link(validation_data)
+                }
+            ),
+            // link from the question which author or the teacher has accepted this answer
+            from!(
+                Question::entry_type(),
+                link_type: QUESTION_TO_ACCEPTED_ANSWER_LINK,
+                validation_package: || {
+                    hdk::ValidationPackageDefinition::Entry
+                },
+                validation: | validation_data: hdk::LinkValidationData | {
+                    validation::question_to_accepted_answer_link(validation_data)
+                }
+            )
+        ]
+    )
+}
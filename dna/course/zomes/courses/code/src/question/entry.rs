@@ -0,0 +1,85 @@
+use hdk::prelude::*;
+use holochain_entry_utils::HolochainEntry;
+
+use super::validation;
+use crate::anchor_trait::AnchorTrait;
+use crate::course::anchor::CourseAnchor;
+
+pub const MAX_TITLE_LEN: usize = 200;
+pub const MAX_TEXT_LEN: usize = 5000;
+pub const COURSE_ANCHOR_TO_QUESTION_LINK: &str = "course_anchor->question";
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct Question {
+    pub title: String,
+    pub text: String,
+    pub course_anchor_address: Address,
+    pub author_address: Address,
+    // NOTE: teacher_address is copied from the CourseAnchor so that validation of accepting an answer
+    // doesn't have to retrieve the course
+    pub teacher_address: Address,
+    pub timestamp: u64,
+}
+
+impl Question {
+    pub fn new(
+        title: String,
+        text: String,
+        course_anchor_address: Address,
+        author_address: Address,
+        teacher_address: Address,
+        timestamp: u64,
+    ) -> Self {
+        Question {
+            title,
+            text,
+            course_anchor_address,
+            author_address,
+            teacher_address,
+            timestamp,
+        }
+    }
+}
+
+impl HolochainEntry for Question {
+    fn entry_type() -> String {
+        String::from("question")
+    }
+}
+
+pub fn question_entry_def() -> ValidatingEntryType {
+    entry!(
+        name: Question::entry_type(),
+        description: "this is a question that a student asked on the course's Q&A board",
+        sharing: Sharing::Public,
+        validation_package: || {
+            hdk::ValidationPackageDefinition::Entry
+        },
+        validation: | validation_data: hdk::EntryValidationData<Question>| {
+            match validation_data {
+                EntryValidationData::Create { entry, validation_data } => {
+                    validation::create(entry, validation_data)
+                },
+                EntryValidationData::Modify { new_entry, old_entry, old_entry_header, validation_data } => {
+                    validation::modify(new_entry, old_entry, old_entry_header, validation_data)
+                },
+                EntryValidationData::Delete { old_entry, old_entry_header, validation_data } => {
+                    validation::delete(old_entry, old_entry_header, validation_data)
+                }
+            }
+        },
+        links: [
+            // link from the course on which Q&A board this question was asked
+            from!(
+                CourseAnchor::entry_type(),
+                link_type: COURSE_ANCHOR_TO_QUESTION_LINK,
+                validation_package: || {
+                    hdk::ValidationPackageDefinition::Entry
+                },
+                validation: | validation_data: hdk::LinkValidationData | {
+                    validation::course_anchor_to_question_link(validation_data)
+                }
+            )
+        ]
+    )
+}
@@ -0,0 +1,246 @@
+use hdk::prelude::*;
+use hdk::AGENT_ADDRESS;
+use holochain_entry_utils::HolochainEntry;
+use std::cmp::Reverse;
+
+use super::answer::{Answer, QUESTION_TO_ACCEPTED_ANSWER_LINK, QUESTION_TO_ANSWER_LINK};
+use super::entry::{Question, COURSE_ANCHOR_TO_QUESTION_LINK};
+use super::vote::{Vote, ANSWER_TO_VOTE_LINK};
+use crate::course;
+use crate::error::ZomeError;
+
+// orders in which questions of the course's Q&A board can be listed
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum QuestionSort {
+    // questions without an accepted answer go first, starting with the ones that have the least answers
+    Unanswered,
+    // questions which answers have the most votes go first
+    MostVoted,
+}
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct QuestionSummary {
+    pub question_address: Address,
+    pub answers: usize,
+    // sum of votes for all answers to this question
+    pub votes: usize,
+    pub accepted_answer_address: Option<Address>,
+    pub timestamp: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct AnswerSummary {
+    pub answer_address: Address,
+    pub votes: usize,
+    pub accepted: bool,
+}
+
+pub fn ask(
+    course_anchor_address: Address,
+    title: String,
+    text: String,
+    timestamp: u64,
+) -> ZomeApiResult<Address> {
+    let latest_course_result = course::handlers::get_latest_course(&course_anchor_address)?;
+    match latest_course_result {
+        Some((course, _course_address)) => {
+            let new_question = Question::new(
+                title,
+                text,
+                course_anchor_address.clone(),
+                AGENT_ADDRESS.clone(),
+                course.teacher_address,
+                timestamp,
+            );
+            let new_question_address = hdk::commit_entry(&new_question.entry())?;
+            hdk::link_entries(
+                &course_anchor_address,
+                &new_question_address,
+                COURSE_ANCHOR_TO_QUESTION_LINK,
+                "",
+            )?;
+
+            Ok(new_question_address)
+        }
+        None => Err(ZomeError::deleted("course", "ask a question in").into()),
+    }
+}
+
+pub fn answer(question_address: Address, text: String, timestamp: u64) -> ZomeApiResult<Address> {
+    // retrieve question entry. If it doesn't exist, we'll fail with error here so we're also validating input
+    let _question: Question = hdk::utils::get_as_type(question_address.clone())?;
+
+    let new_answer = Answer::new(
+        text,
+        question_address.clone(),
+        AGENT_ADDRESS.clone(),
+        timestamp,
+    );
+    let new_answer_address = hdk::commit_entry(&new_answer.entry())?;
+    hdk::link_entries(
+        &question_address,
+        &new_answer_address,
+        QUESTION_TO_ANSWER_LINK,
+        "",
+    )?;
+
+    Ok(new_answer_address)
+}
+
+pub fn get_answer_addresses(question_address: &Address) -> ZomeApiResult<Vec<Address>> {
+    let links = hdk::get_links(
+        question_address,
+        LinkMatch::Exactly(QUESTION_TO_ANSWER_LINK),
+        LinkMatch::Any,
+    )?;
+
+    Ok(links.addresses())
+}
+
+pub fn get_accepted_answer(question_address: &Address) -> ZomeApiResult<Option<Address>> {
+    let accepted_answers = hdk::get_links(
+        question_address,
+        LinkMatch::Exactly(QUESTION_TO_ACCEPTED_ANSWER_LINK),
+        LinkMatch::Any,
+    )?
+    .addresses();
+
+    match accepted_answers.len() {
+        0 => Ok(None),
+        1 => Ok(Some(accepted_answers[0].clone())),
+        _ => Err(ZomeError::inconsistent_links("from Question to accepted Answer").into()),
+    }
+}
+
+fn count_votes(answer_address: &Address) -> ZomeApiResult<usize> {
+    Ok(hdk::get_links(
+        answer_address,
+        LinkMatch::Exactly(ANSWER_TO_VOTE_LINK),
+        LinkMatch::Any,
+    )?
+    .addresses()
+    .len())
+}
+
+// lists answers to the question: accepted answer goes first and the rest are sorted by votes
+pub fn get_answers(question_address: &Address) -> ZomeApiResult<Vec<AnswerSummary>> {
+    let accepted_answer_address = get_accepted_answer(question_address)?;
+    let mut answers = Vec::new();
+    for answer_address in get_answer_addresses(question_address)? {
+        answers.push(AnswerSummary {
+            votes: count_votes(&answer_address)?,
+            accepted: accepted_answer_address.as_ref() == Some(&answer_address),
+            answer_address,
+        });
+    }
+    answers.sort_by_key(|answer| (!answer.accepted, Reverse(answer.votes)));
+
+    Ok(answers)
+}
+
+pub fn get_questions(
+    course_anchor_address: &Address,
+    sort_by: QuestionSort,
+) -> ZomeApiResult<Vec<QuestionSummary>> {
+    let question_addresses = hdk::get_links(
+        course_anchor_address,
+        LinkMatch::Exactly(COURSE_ANCHOR_TO_QUESTION_LINK),
+        LinkMatch::Any,
+    )?
+    .addresses();
+
+    let mut questions = Vec::new();
+    for question_address in question_addresses {
+        // deleted questions stay linked from the course, so we're skipping them here
+        let question = match hdk::get_entry(&question_address)?
+            .and_then(|entry| Question::from_entry(&entry))
+        {
+            Some(question) => question,
+            None => continue,
+        };
+        let answers = get_answers(&question_address)?;
+        questions.push(QuestionSummary {
+            answers: answers.len(),
+            votes: answers.iter().map(|answer| answer.votes).sum(),
+            accepted_answer_address: get_accepted_answer(&question_address)?,
+            timestamp: question.timestamp,
+            question_address,
+        });
+    }
+
+    // newer questions go first when everything else is equal
+    match sort_by {
+        QuestionSort::Unanswered => questions.sort_by_key(|question| {
+            (
+                question.accepted_answer_address.is_some(),
+                question.answers,
+                Reverse(question.timestamp),
+            )
+        }),
+        QuestionSort::MostVoted => {
+            questions.sort_by_key(|question| (Reverse(question.votes), Reverse(question.timestamp)))
+        }
+    }
+
+    Ok(questions)
+}
+
+pub fn accept_answer(question_address: Address, answer_address: Address) -> ZomeApiResult<Address> {
+    let answer: Answer = hdk::utils::get_as_type(answer_address.clone())?;
+    if answer.question_address != question_address {
+        return Err(
+            ZomeError::invalid_input("Can't accept an answer to a different question").into(),
+        );
+    }
+
+    // question can only have one accepted answer so we're removing the link to the previous one
+    if let Some(previous_answer_address) = get_accepted_answer(&question_address)? {
+        hdk::remove_link(
+            &question_address,
+            &previous_answer_address,
+            QUESTION_TO_ACCEPTED_ANSWER_LINK,
+            "",
+        )?;
+    }
+
+    hdk::link_entries(
+        &question_address,
+        &answer_address,
+        QUESTION_TO_ACCEPTED_ANSWER_LINK,
+        "",
+    )
+}
+
+pub fn vote(answer_address: Address) -> ZomeApiResult<Address> {
+    let vote = Vote::new(answer_address.clone(), AGENT_ADDRESS.clone());
+    let vote_address = vote.address()?;
+
+    // Vote address is the same every time this agent votes for this answer
+    // so we only need to check if the answer already links to it
+    let votes = hdk::get_links(
+        &answer_address,
+        LinkMatch::Exactly(ANSWER_TO_VOTE_LINK),
+        LinkMatch::Any,
+    )?
+    .addresses();
+    if votes.contains(&vote_address) {
+        return Err(
+            ZomeError::already_exists("vote", "You've already voted for this answer").into(),
+        );
+    }
+
+    hdk::commit_entry(&vote.entry())?;
+    hdk::link_entries(&answer_address, &vote_address, ANSWER_TO_VOTE_LINK, "")?;
+
+    Ok(vote_address)
+}
+
+pub fn remove_vote(answer_address: Address) -> ZomeApiResult<Address> {
+    let vote_address = Vote::new(answer_address.clone(), AGENT_ADDRESS.clone()).address()?;
+
+    // NOTE: we're only removing the link and leave the Vote entry as is: if this agent votes
+    // for this answer again, the same Vote entry would be linked from the answer once more
+    hdk::remove_link(&answer_address, &vote_address, ANSWER_TO_VOTE_LINK, "")?;
+
+    Ok(vote_address)
+}
@@ -0,0 +1,5 @@
+pub mod answer;
+pub mod entry;
+pub mod handlers;
+mod validation;
+pub mod vote;
@@ -0,0 +1,289 @@
+use hdk::holochain_core_types::chain_header::ChainHeader;
+use hdk::{LinkValidationData, ValidationData};
+use holochain_entry_utils::HolochainEntry;
+
+use super::{
+    answer::Answer,
+    entry::{Question, MAX_TEXT_LEN, MAX_TITLE_LEN},
+    vote::Vote,
+};
+use crate::course;
+use crate::course::anchor::CourseAnchor;
+use crate::error::ZomeError;
+use crate::helper;
+
+fn validate_text(text: &str, entity_name: &str) -> Result<(), String> {
+    if text.len() > MAX_TEXT_LEN {
+        return Err(ZomeError::text_too_long(entity_name, "text", MAX_TEXT_LEN).into());
+    }
+    Ok(())
+}
+
+pub fn create(entry: Question, validation_data: ValidationData) -> Result<(), String> {
+    helper::validate_only_author_can_do(
+        &entry.author_address,
+        validation_data.sources(),
+        &Question::entry_type(),
+        "create",
+    )?;
+    helper::validate_entity_title(&entry.title, &Question::entry_type(), MAX_TITLE_LEN)?;
+    validate_text(&entry.text, &Question::entry_type())?;
+    let course_anchor: CourseAnchor = hdk::utils::get_as_type(entry.course_anchor_address.clone())?;
+    if course_anchor.teacher_address != entry.teacher_address {
+        return Err(ZomeError::invalid_input(
+            "Question has to have the same teacher address as the course it's asked in",
+        )
+        .into());
+    }
+    let students = course::handlers::get_students(entry.course_anchor_address.clone())?;
+    if !students.contains(&entry.author_address) {
+        return Err(
+            ZomeError::forbidden("Only students enrolled in the course can ask questions").into(),
+        );
+    }
+    Ok(())
+}
+
+pub fn modify(
+    new_entry: Question,
+    old_entry: Question,
+    _old_entry_header: ChainHeader,
+    validation_data: ValidationData,
+) -> Result<(), String> {
+    helper::validate_only_author_can_do(
+        &old_entry.author_address,
+        validation_data.sources(),
+        &Question::entry_type(),
+        "modify",
+    )?;
+    if new_entry.course_anchor_address != old_entry.course_anchor_address {
+        return Err(ZomeError::immutable_field("question", "course").into());
+    }
+    if new_entry.author_address != old_entry.author_address {
+        return Err(ZomeError::immutable_field("question", "author").into());
+    }
+    helper::validate_entity_title(&new_entry.title, &Question::entry_type(), MAX_TITLE_LEN)?;
+    validate_text(&new_entry.text, &Question::entry_type())?;
+    helper::validate_no_teacher_change(
+        old_entry.teacher_address,
+        new_entry.teacher_address,
+        &Question::entry_type(),
+    )
+}
+
+pub fn delete(
+    entry: Question,
+    _entry_header: ChainHeader,
+    validation_data: ValidationData,
+) -> Result<(), String> {
+    helper::validate_only_author_can_do(
+        &entry.author_address,
+        validation_data.sources(),
+        &Question::entry_type(),
+        "delete",
+    )
+}
+
+// =========================== Answer validation
+pub fn answer_create(entry: Answer, validation_data: ValidationData) -> Result<(), String> {
+    helper::validate_only_author_can_do(
+        &entry.author_address,
+        validation_data.sources(),
+        &Answer::entry_type(),
+        "create",
+    )?;
+    validate_text(&entry.text, &Answer::entry_type())?;
+    let question: Question = hdk::utils::get_as_type(entry.question_address.clone())?;
+    helper::validate_only_course_member_can_do(
+        &question.course_anchor_address,
+        &question.teacher_address,
+        &entry.author_address,
+        "answer questions",
+    )
+}
+
+pub fn answer_modify(
+    new_entry: Answer,
+    old_entry: Answer,
+    _old_entry_header: ChainHeader,
+    validation_data: ValidationData,
+) -> Result<(), String> {
+    helper::validate_only_author_can_do(
+        &old_entry.author_address,
+        validation_data.sources(),
+        &Answer::entry_type(),
+        "modify",
+    )?;
+    if new_entry.question_address != old_entry.question_address {
+        return Err(ZomeError::immutable_field("answer", "question").into());
+    }
+    if new_entry.author_address != old_entry.author_address {
+        return Err(ZomeError::immutable_field("answer", "author").into());
+    }
+    validate_text(&new_entry.text, &Answer::entry_type())
+}
+
+pub fn answer_delete(
+    entry: Answer,
+    _entry_header: ChainHeader,
+    validation_data: ValidationData,
+) -> Result<(), String> {
+    helper::validate_only_author_can_do(
+        &entry.author_address,
+        validation_data.sources(),
+        &Answer::entry_type(),
+        "delete",
+    )
+}
+
+// =========================== Vote validation
+pub fn vote_create(entry: Vote, validation_data: ValidationData) -> Result<(), String> {
+    helper::validate_only_author_can_do(
+        &entry.voter_address,
+        validation_data.sources(),
+        &Vote::entry_type(),
+        "create",
+    )?;
+    let answer: Answer = hdk::utils::get_as_type(entry.answer_address.clone())?;
+    if answer.author_address == entry.voter_address {
+        return Err(ZomeError::forbidden("Can't vote for your own answer").into());
+    }
+    let question: Question = hdk::utils::get_as_type(answer.question_address)?;
+    helper::validate_only_course_member_can_do(
+        &question.course_anchor_address,
+        &question.teacher_address,
+        &entry.voter_address,
+        "vote for answers",
+    )
+}
+
+// NOTE: we don't accept any parameters here because we don't need them to always return an error
+// because there's nothing in the vote that could be changed
+pub fn vote_modify() -> Result<(), String> {
+    Err(ZomeError::immutable_entry("vote").into())
+}
+
+pub fn vote_delete(
+    entry: Vote,
+    _entry_header: ChainHeader,
+    validation_data: ValidationData,
+) -> Result<(), String> {
+    helper::validate_only_author_can_do(
+        &entry.voter_address,
+        validation_data.sources(),
+        &Vote::entry_type(),
+        "delete",
+    )
+}
+
+//  =========================== Q&A links validation
+pub fn course_anchor_to_question_link(validation_data: LinkValidationData) -> Result<(), String> {
+    let (link, validation_data) = match validation_data {
+        hdk::LinkValidationData::LinkAdd {
+            link,
+            validation_data,
+        } => (link, validation_data),
+        hdk::LinkValidationData::LinkRemove {
+            link,
+            validation_data,
+        } => (link, validation_data),
+    };
+    // get author of this entry
+    let author = validation_data.package.chain_header.provenances()[0].source();
+    // get link target: entry to which the link goes
+    let target: Question = hdk::utils::get_as_type(link.link.target().clone())?;
+    if author != target.author_address {
+        return Err(ZomeError::invalid_link("course_anchor->question", "Can't link CourseAnchor to Question because your address isn't specified as author address for this question").into());
+    }
+    if link.link.base() != &target.course_anchor_address {
+        return Err(ZomeError::invalid_link(
+            "course_anchor->question",
+            "Can't link CourseAnchor to Question that was asked in a different course",
+        )
+        .into());
+    }
+    Ok(())
+}
+
+pub fn question_to_answer_link(validation_data: LinkValidationData) -> Result<(), String> {
+    let (link, validation_data) = match validation_data {
+        hdk::LinkValidationData::LinkAdd {
+            link,
+            validation_data,
+        } => (link, validation_data),
+        hdk::LinkValidationData::LinkRemove {
+            link,
+            validation_data,
+        } => (link, validation_data),
+    };
+    let author = validation_data.package.chain_header.provenances()[0].source();
+    let target: Answer = hdk::utils::get_as_type(link.link.target().clone())?;
+    if author != target.author_address {
+        return Err(ZomeError::invalid_link("question->answer", "Can't link Question to Answer because your address isn't specified as author address for this answer").into());
+    }
+    if link.link.base() != &target.question_address {
+        return Err(ZomeError::invalid_link(
+            "question->answer",
+            "Can't link Question to Answer that was given to a different question",
+        )
+        .into());
+    }
+    Ok(())
+}
+
+pub fn question_to_accepted_answer_link(validation_data: LinkValidationData) -> Result<(), String> {
+    let (link, validation_data) = match validation_data {
+        hdk::LinkValidationData::LinkAdd {
+            link,
+            validation_data,
+        } => (link, validation_data),
+        hdk::LinkValidationData::LinkRemove {
+            link,
+            validation_data,
+        } => (link, validation_data),
+    };
+    let author = validation_data.package.chain_header.provenances()[0].source();
+    // get link base: entry from which the link goes
+    let base: Question = hdk::utils::get_as_type(link.link.base().clone())?;
+    if author != base.author_address && author != base.teacher_address {
+        return Err(ZomeError::forbidden(
+            "Only the author of the question or the teacher of the course can accept answers",
+        )
+        .into());
+    }
+    let target: Answer = hdk::utils::get_as_type(link.link.target().clone())?;
+    if link.link.base() != &target.question_address {
+        return Err(ZomeError::invalid_link(
+            "question->accepted_answer",
+            "Can't accept Answer that was given to a different question",
+        )
+        .into());
+    }
+    Ok(())
+}
+
+pub fn answer_to_vote_link(validation_data: LinkValidationData) -> Result<(), String> {
+    let (link, validation_data) = match validation_data {
+        hdk::LinkValidationData::LinkAdd {
+            link,
+            validation_data,
+        } => (link, validation_data),
+        hdk::LinkValidationData::LinkRemove {
+            link,
+            validation_data,
+        } => (link, validation_data),
+    };
+    let author = validation_data.package.chain_header.provenances()[0].source();
+    let target: Vote = hdk::utils::get_as_type(link.link.target().clone())?;
+    if author != target.voter_address {
+        return Err(ZomeError::invalid_link("answer->vote", "Can't link Answer to Vote because your address isn't specified as voter address for this vote").into());
+    }
+    if link.link.base() != &target.answer_address {
+        return Err(ZomeError::invalid_link(
+            "answer->vote",
+            "Can't link Answer to Vote that was given for a different answer",
+        )
+        .into());
+    }
+    Ok(())
+}
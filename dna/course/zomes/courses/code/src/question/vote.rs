@@ -0,0 +1,69 @@
+use hdk::prelude::*;
+use holochain_entry_utils::HolochainEntry;
+
+use super::answer::Answer;
+use super::validation;
+
+pub const ANSWER_TO_VOTE_LINK: &str = "answer->vote";
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct Vote {
+    // NOTE: there are no other fields here on purpose (not even a timestamp): this makes the Vote address
+    // the same every time an agent votes for an answer, so there can only be one vote per agent per answer
+    pub answer_address: Address,
+    pub voter_address: Address,
+}
+
+impl Vote {
+    pub fn new(answer_address: Address, voter_address: Address) -> Self {
+        Vote {
+            answer_address,
+            voter_address,
+        }
+    }
+}
+
+impl HolochainEntry for Vote {
+    fn entry_type() -> String {
+        String::from("vote")
+    }
+}
+
+pub fn vote_entry_def() -> ValidatingEntryType {
+    entry!(
+        name: Vote::entry_type(),
+        description: "this is a vote of a course member for the answer on the course's Q&A board",
+        sharing: Sharing::Public,
+        validation_package: || {
+            hdk::ValidationPackageDefinition::Entry
+        },
+        validation: | validation_data: hdk::EntryValidationData<Vote>| {
+            match validation_data {
+                EntryValidationData::Create { entry, validation_data } => {
+                    validation::vote_create(entry, validation_data)
+                },
+                // NOTE: the symbol .. means that we're skipping unpacking parameters that we receive here
+                // because we won't need them
+                EntryValidationData::Modify { .. } => {
+                    validation::vote_modify()
+                },
+                EntryValidationData::Delete { old_entry, old_entry_header, validation_data } => {
+                    validation::vote_delete(old_entry, old_entry_header, validation_data)
+                }
+            }
+        },
+        links: [
+            // link from the answer that was voted for
+            from!(
+                Answer::entry_type(),
+                link_type: ANSWER_TO_VOTE_LINK,
+                validation_package: || {
+                    hdk::ValidationPackageDefinition::Entry
+                },
+                validation: | validation_data: hdk::LinkValidationData | {
+                    validation::answer_to_vote_link(validation_data)
+                }
+            )
+        ]
+    )
+}
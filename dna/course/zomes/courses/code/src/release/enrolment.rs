@@ -0,0 +1,56 @@
+use hdk::prelude::*;
+use holochain_entry_utils::HolochainEntry;
+
+use super::validation;
+
+// Records when a student enrolled in the course so sections can be released days after that.
+// NOTE: this entry is private for the same reason as ContentCompletion: release of sections is calculated
+// on the student's own node, so nobody else needs to know when the student enrolled
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct Enrolment {
+    pub course_address: Address,
+    pub student_address: Address,
+    pub timestamp: u64,
+}
+
+impl Enrolment {
+    pub fn new(course_address: Address, student_address: Address, timestamp: u64) -> Self {
+        Enrolment {
+            course_address,
+            student_address,
+            timestamp,
+        }
+    }
+}
+
+impl HolochainEntry for Enrolment {
+    fn entry_type() -> String {
+        String::from("enrolment")
+    }
+}
+
+pub fn enrolment_entry_def() -> ValidatingEntryType {
+    entry!(
+        name: Enrolment::entry_type(),
+        description: "this is a private record of the time a student has enrolled in the course",
+        sharing: Sharing::Private,
+        validation_package: || {
+            hdk::ValidationPackageDefinition::Entry
+        },
+        validation: | validation_data: hdk::EntryValidationData<Enrolment>| {
+            match validation_data {
+                EntryValidationData::Create { entry, validation_data } => {
+                    validation::create(entry, validation_data)
+                },
+                EntryValidationData::Modify { .. } => {
+                    validation::modify()
+                },
+                EntryValidationData::Delete { old_entry, old_entry_header, validation_data } => {
+                    validation::delete(old_entry, old_entry_header, validation_data)
+                }
+            }
+        },
+        // Enrolment is private so nothing on the DHT can link to it
+        links: []
+    )
+}
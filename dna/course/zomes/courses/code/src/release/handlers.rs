@@ -0,0 +1,256 @@
+use hdk::prelude::*;
+use hdk::AGENT_ADDRESS;
+use holochain_entry_utils::HolochainEntry;
+use std::cell::Cell;
+use std::collections::HashSet;
+
+use super::enrolment::Enrolment;
+use crate::clock;
+use crate::course;
+use crate::course::anchor::STUDENT_TO_COURSE_ANCHOR_LINK;
+use crate::course::entry::{Course, DripRule};
+use crate::error::ZomeError;
+use crate::helper;
+use crate::progress;
+use crate::section;
+use crate::section::entry::Section;
+use courses_core::release::{self, Drip, Lock};
+
+// Reason why the section is locked for the current agent
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "reason")]
+pub enum SectionLock {
+    UntilTime { unlocks_at: u64 },
+    UntilPreviousSectionCompleted,
+    UntilEnrolled,
+}
+
+impl SectionLock {
+    fn from_lock(lock: Lock) -> Option<Self> {
+        match lock {
+            Lock::Unlocked => None,
+            Lock::UntilTime(unlocks_at) => Some(SectionLock::UntilTime { unlocks_at }),
+            Lock::UntilPreviousSectionCompleted => Some(SectionLock::UntilPreviousSectionCompleted),
+            Lock::UntilEnrolled => Some(SectionLock::UntilEnrolled),
+        }
+    }
+
+    pub fn to_error(&self) -> ZomeError {
+        match self {
+            SectionLock::UntilTime { unlocks_at } => {
+                ZomeError::locked("section", "it isn't released yet", Some(*unlocks_at))
+            }
+            SectionLock::UntilPreviousSectionCompleted => ZomeError::locked(
+                "section",
+                "previous section has to be completed first",
+                None,
+            ),
+            SectionLock::UntilEnrolled => {
+                ZomeError::locked("section", "you have to enrol in the course first", None)
+            }
+        }
+    }
+}
+
+// records time of enrolment. It has to be called after the agent has committed the link that enrolled them:
+// header of this link already has the current time, so we don't need clock::now to commit another entry for it
+pub fn record_enrolment(course_anchor_address: &Address) -> ZomeApiResult<Address> {
+    let enrolled_at = get_enrolment_link_time(course_anchor_address)?
+        .ok_or_else(|| ZomeApiError::from(ZomeError::not_found("link that enrolled the agent")))?;
+    let enrolment = Enrolment::new(
+        course_anchor_address.clone(),
+        AGENT_ADDRESS.clone(),
+        enrolled_at,
+    );
+    hdk::commit_entry(&enrolment.entry())
+}
+
+// returns time at which the current agent enrolled in the course or None if they aren't enrolled
+fn get_enrolment_time(course_anchor_address: &Address) -> ZomeApiResult<Option<u64>> {
+    let enrolment = helper::get_my_entries::<Enrolment>()?
+        .into_iter()
+        .find(|(enrolment, _address)| &enrolment.course_address == course_anchor_address);
+    if let Some((enrolment, _address)) = enrolment {
+        return Ok(Some(enrolment.timestamp));
+    }
+    // NOTE: students that enrolled before enrolment time was recorded don't have the Enrolment entry,
+    // but the link that enrolled them is on their source chain and it's header has the time of enrolment
+    get_enrolment_link_time(course_anchor_address)
+}
+
+// returns time of the header of the student->course_anchor link that the current agent committed to enrol in the course
+fn get_enrolment_link_time(course_anchor_address: &Address) -> ZomeApiResult<Option<u64>> {
+    let query_result = hdk::query_result(
+        String::from(EntryType::LinkAdd).into(),
+        QueryArgsOptions {
+            start: 0,
+            limit: 0,
+            headers: true,
+            entries: true,
+        },
+    )?;
+    match query_result {
+        QueryResult::HeadersWithEntries(items) => {
+            Ok(items.iter().find_map(|(header, entry)| match entry {
+                Entry::LinkAdd(link_data)
+                    if link_data.link().base() == *AGENT_ADDRESS
+                        && link_data.link().target() == course_anchor_address
+                        && link_data.link().link_type() == STUDENT_TO_COURSE_ANCHOR_LINK =>
+                {
+                    Some(clock::header_time(header))
+                }
+                _ => None,
+            }))
+        }
+        _ => Err(
+            ZomeError::inconsistent_links("unexpected result of querying the source chain").into(),
+        ),
+    }
+}
+
+// everything that section locks of one course depend on. It's loaded once per zome call and shared by all sections
+// that are checked in it, so checking every section of the course tree doesn't retrieve the same entries again.
+// NOTE: the current time is only read when a lock depends on it, because reading it commits a ClockTick.
+// Locks only keep sections out of the UI: their content is still on the DHT
+pub struct SectionLocks {
+    // current time, read at most once per zome call
+    now: Cell<Option<u64>>,
+    // None if nothing is locked for the current agent: they teach the course or it doesn't exist anymore
+    course: Option<Course>,
+    enrolled_at: Option<u64>,
+    // only loaded when sections are released one after another
+    completed_contents: HashSet<Address>,
+}
+
+impl SectionLocks {
+    pub fn load(course_anchor_address: &Address) -> ZomeApiResult<Self> {
+        let course = match course::handlers::get_latest_course(course_anchor_address)? {
+            Some((course, _course_address)) if &course.teacher_address != *AGENT_ADDRESS => {
+                Some(course)
+            }
+            _ => None,
+        };
+        let drip_rule = course.as_ref().and_then(|course| course.drip_rule.as_ref());
+        let enrolled_at = match drip_rule {
+            Some(DripRule::DaysAfterEnrolment { .. }) => get_enrolment_time(course_anchor_address)?,
+            _ => None,
+        };
+        let completed_contents = match drip_rule {
+            Some(DripRule::AfterPreviousSection) => {
+                progress::handlers::get_completed_contents(course_anchor_address)?
+            }
+            _ => HashSet::new(),
+        };
+        Ok(SectionLocks {
+            now: Cell::new(None),
+            course,
+            enrolled_at,
+            completed_contents,
+        })
+    }
+
+    fn now(&self) -> ZomeApiResult<u64> {
+        match self.now.get() {
+            Some(now) => Ok(now),
+            None => {
+                let now = clock::now()?;
+                self.now.set(Some(now));
+                Ok(now)
+            }
+        }
+    }
+
+    // returns the closest section before this one on the same level of the course tree that wasn't deleted
+    fn get_previous_section(
+        &self,
+        course: &Course,
+        section: &Section,
+    ) -> ZomeApiResult<Option<Address>> {
+        let siblings = match &section.parent_address {
+            Some(parent_address) => match section::handlers::get_latest_section(parent_address)? {
+                Some((parent_section, _parent_section_address)) => parent_section.subsections,
+                None => return Ok(None),
+            },
+            None => course.sections.clone(),
+        };
+        let position = siblings
+            .iter()
+            .position(|address| address == &section.anchor_address);
+        if let Some(position) = position {
+            for address in siblings[..position].iter().rev() {
+                if section::handlers::get_latest_section(address)?.is_some() {
+                    return Ok(Some(address.clone()));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    // returns why the section isn't available to the current agent yet or None if it is.
+    // Teacher can always see all sections. Subsections are locked as long as their parent section is locked
+    pub fn get(&self, section: &Section) -> ZomeApiResult<Option<SectionLock>> {
+        let course = match &self.course {
+            Some(course) => course,
+            None => return Ok(None),
+        };
+        if let Some(parent_address) = &section.parent_address {
+            if let Some((parent_section, _parent_section_address)) =
+                section::handlers::get_latest_section(parent_address)?
+            {
+                if let Some(parent_lock) = self.get(&parent_section)? {
+                    return Ok(Some(parent_lock));
+                }
+            }
+        }
+
+        let drip = match course.drip_rule {
+            None => Drip::None,
+            // subsections are only checked after their parent section is unlocked, so it's drip time has passed
+            Some(DripRule::DaysAfterEnrolment { .. }) if section.parent_address.is_some() => {
+                Drip::None
+            }
+            Some(DripRule::DaysAfterEnrolment { days }) => Drip::DaysAfterEnrolment {
+                days,
+                // deleted sections are removed from the course, so they don't delay the next ones
+                position: course
+                    .sections
+                    .iter()
+                    .position(|address| address == &section.anchor_address)
+                    .unwrap_or_else(|| course.sections.len()) as u64,
+                enrolled_at: self.enrolled_at,
+            },
+            Some(DripRule::AfterPreviousSection) => Drip::AfterPreviousSection {
+                previous_completed: match self.get_previous_section(course, section)? {
+                    Some(previous_address) => progress::handlers::is_section_completed(
+                        &previous_address,
+                        &self.completed_contents,
+                    )?,
+                    None => true,
+                },
+            },
+        };
+        let now = match release::section_unlocks_at(section.release_at, &drip) {
+            Some(_unlocks_at) => self.now()?,
+            // lock doesn't depend on time
+            None => 0,
+        };
+        Ok(SectionLock::from_lock(release::section_lock(
+            now,
+            section.release_at,
+            &drip,
+        )))
+    }
+}
+
+// returns an error if the section at section_anchor_address is locked for the current agent
+pub fn validate_section_unlocked(section_anchor_address: &Address) -> ZomeApiResult<()> {
+    if let Some((section, _section_address)) =
+        section::handlers::get_latest_section(section_anchor_address)?
+    {
+        let locks = SectionLocks::load(&section.course_address)?;
+        if let Some(lock) = locks.get(&section)? {
+            return Err(lock.to_error().into());
+        }
+    }
+    Ok(())
+}
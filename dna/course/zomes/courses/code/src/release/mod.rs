@@ -0,0 +1,3 @@
+pub mod enrolment;
+pub mod handlers;
+mod validation;
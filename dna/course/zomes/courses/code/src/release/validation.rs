@@ -0,0 +1,32 @@
+use hdk::holochain_core_types::chain_header::ChainHeader;
+use hdk::ValidationData;
+use holochain_entry_utils::HolochainEntry;
+
+use super::enrolment::Enrolment;
+use crate::error::ZomeError;
+use crate::helper;
+
+pub fn create(entry: Enrolment, validation_data: ValidationData) -> Result<(), String> {
+    if !validation_data.sources().contains(&entry.student_address) {
+        return Err(ZomeError::forbidden("Students can only record their own enrolment").into());
+    }
+    // enrolment time has to be the real one, otherwise students could unlock sections earlier
+    helper::validate_timestamp(entry.timestamp, &validation_data, &Enrolment::entry_type())
+}
+
+// NOTE: we don't accept any parameters here because we don't need them to always return an error
+// because enrolment time can't change
+pub fn modify() -> Result<(), String> {
+    Err(ZomeError::immutable_entry("enrolment").into())
+}
+
+pub fn delete(
+    entry: Enrolment,
+    _entry_header: ChainHeader,
+    validation_data: ValidationData,
+) -> Result<(), String> {
+    if !validation_data.sources().contains(&entry.student_address) {
+        return Err(ZomeError::forbidden("Students can only delete their own enrolment").into());
+    }
+    Ok(())
+}
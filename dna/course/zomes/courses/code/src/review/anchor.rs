@@ -0,0 +1,89 @@
+use hdk::prelude::*;
+use holochain_entry_utils::HolochainEntry;
+
+use super::entry::Review;
+use super::validation;
+use crate::anchor_trait::AnchorTrait;
+use crate::course::anchor::CourseAnchor;
+
+pub const COURSE_ANCHOR_TO_REVIEW_ANCHOR_LINK: &str = "course_anchor->review_anchor";
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct ReviewAnchor {
+    // NOTE: there are no other fields here on purpose: this makes the anchor address the same for
+    // every review that a student writes for a course, so each student can only have one review per course
+    pub course_anchor_address: Address,
+    pub student_address: Address,
+}
+
+impl AnchorTrait for ReviewAnchor {
+    fn entry_type() -> String {
+        String::from("review_anchor")
+    }
+    fn link_to() -> String {
+        Review::entry_type()
+    }
+    fn link_type() -> String {
+        "review_anchor->review".to_owned()
+    }
+}
+
+impl ReviewAnchor {
+    pub fn new(course_anchor_address: Address, student_address: Address) -> Self {
+        ReviewAnchor {
+            course_anchor_address,
+            student_address,
+        }
+    }
+}
+
+pub fn review_anchor_def() -> ValidatingEntryType {
+    entry!(
+        name: ReviewAnchor::entry_type(),
+        description: "Anchor to the valid course review",
+        sharing: Sharing::Public,
+        validation_package: || {
+            hdk::ValidationPackageDefinition::Entry
+        },
+        validation: | validation_data: hdk::EntryValidationData<ReviewAnchor>| {
+            match validation_data{
+                EntryValidationData::Create { entry, validation_data } => {
+                    validation::anchor_create(entry, validation_data)
+                 },
+                 // NOTE: the symbol .. means that we're skipping unpacking parameters that we receive here
+                 // because we won't need them
+                 EntryValidationData::Modify { .. } => {
+                    validation::anchor_modify()
+                 },
+                 EntryValidationData::Delete { old_entry, old_entry_header, validation_data } => {
+                    validation::anchor_delete(old_entry, old_entry_header, validation_data)
+                 }
+            }
+        },
+        links:[
+            // link that connects ReviewAnchor to the latest Review entry
+            to!(
+                ReviewAnchor::link_to(),
+                link_type: ReviewAnchor::link_type(),
+                validation_package:||{
+                    hdk::ValidationPackageDefinition::Entry
+                },
+                validation:|validation_data: hdk::LinkValidationData|{
+                    validation::anchor_to_review_link(validation_data)
+                }
+            ),
+            // link from the course that was reviewed
+            // This is to list all reviews of the course
+            from!(
+                CourseAnchor::entry_type(),
+                link_type: COURSE_ANCHOR_TO_REVIEW_ANCHOR_LINK,
+                validation_package:||{
+                    hdk::ValidationPackageDefinition::Entry
+                },
+                validation:|validation_data: hdk::LinkValidationData|{
+                    validation::course_anchor_to_anchor_link(validation_data)
+                }
+            )
+        ]
+    )
+}
@@ -0,0 +1,71 @@
+use hdk::prelude::*;
+use holochain_entry_utils::HolochainEntry;
+
+use super::validation;
+
+pub const MIN_RATING: u8 = 1;
+pub const MAX_RATING: u8 = 5;
+pub const MAX_TEXT_LEN: usize = 2000;
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct Review {
+    // amount of stars from MIN_RATING to MAX_RATING
+    pub rating: u8,
+    pub text: String,
+    pub course_anchor_address: Address,
+    pub student_address: Address,
+    pub timestamp: u64,
+    pub anchor_address: Address,
+}
+
+impl Review {
+    pub fn new(
+        rating: u8,
+        text: String,
+        course_anchor_address: Address,
+        student_address: Address,
+        timestamp: u64,
+        anchor_address: Address,
+    ) -> Self {
+        Review {
+            rating,
+            text,
+            course_anchor_address,
+            student_address,
+            timestamp,
+            anchor_address,
+        }
+    }
+}
+
+impl HolochainEntry for Review {
+    fn entry_type() -> String {
+        String::from("review")
+    }
+}
+
+pub fn review_entry_def() -> ValidatingEntryType {
+    entry!(
+        name: Review::entry_type(),
+        description: "this is a rating and a review of the course written by it's student",
+        sharing: Sharing::Public,
+        validation_package: || {
+            hdk::ValidationPackageDefinition::Entry
+        },
+        validation: | validation_data: hdk::EntryValidationData<Review>| {
+            match validation_data {
+                EntryValidationData::Create { entry, validation_data } => {
+                    validation::create(entry, validation_data)
+                },
+                EntryValidationData::Modify { new_entry, old_entry, old_entry_header, validation_data } => {
+                    validation::modify(new_entry, old_entry, old_entry_header, validation_data)
+                },
+                EntryValidationData::Delete { old_entry, old_entry_header, validation_data } => {
+                    validation::delete(old_entry, old_entry_header, validation_data)
+                }
+            }
+        },
+        // Review is a data entry that is hidden behind the ReviewAnchor so it doesn't have any links
+        links: []
+    )
+}
@@ -0,0 +1,159 @@
+use hdk::prelude::*;
+use hdk::AGENT_ADDRESS;
+use holochain_entry_utils::HolochainEntry;
+
+use super::anchor::{ReviewAnchor, COURSE_ANCHOR_TO_REVIEW_ANCHOR_LINK};
+use super::entry::{Review, MAX_RATING};
+use crate::anchor_trait::AnchorTrait;
+use crate::error::ZomeError;
+use crate::helper;
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct RatingSummary {
+    pub average: f64,
+    pub count: usize,
+    // histogram[i] is the amount of reviews with i + 1 stars
+    pub histogram: Vec<usize>,
+}
+
+pub fn create(
+    course_anchor_address: Address,
+    rating: u8,
+    text: String,
+    timestamp: u64,
+) -> ZomeApiResult<Address> {
+    let review_anchor = ReviewAnchor::new(course_anchor_address.clone(), AGENT_ADDRESS.clone());
+    // ReviewAnchor address is the same for every review of this agent for this course, so if it
+    // exists, this agent has already reviewed this course
+    if hdk::get_entry(&review_anchor.address()?)?.is_some() {
+        return Err(ZomeError::already_exists(
+            "review",
+            "You've already reviewed this course, update your review instead",
+        )
+        .into());
+    }
+    let review_anchor_address = hdk::commit_entry(&review_anchor.entry())?;
+
+    let new_review = Review::new(
+        rating,
+        text,
+        course_anchor_address.clone(),
+        AGENT_ADDRESS.clone(),
+        timestamp,
+        review_anchor_address.clone(),
+    );
+    let new_review_address = hdk::commit_entry(&new_review.entry())?;
+
+    hdk::link_entries(
+        &review_anchor_address,
+        &new_review_address,
+        ReviewAnchor::link_type(),
+        "".to_owned(),
+    )?;
+    // link CourseAnchor to ReviewAnchor for this review to be findable
+    hdk::link_entries(
+        &course_anchor_address,
+        &review_anchor_address,
+        COURSE_ANCHOR_TO_REVIEW_ANCHOR_LINK,
+        "",
+    )?;
+
+    // ReviewAnchor serves as this review's ID so we return it
+    Ok(review_anchor_address)
+}
+
+pub fn get_latest_review(
+    review_anchor_address: &Address,
+) -> ZomeApiResult<Option<(Review, Address)>> {
+    helper::get_latest_data_entry::<Review>(review_anchor_address, &ReviewAnchor::link_type())
+}
+
+pub fn get_latest_review_entry(review_anchor_address: Address) -> ZomeApiResult<Option<Review>> {
+    match get_latest_review(&review_anchor_address)? {
+        Some((review_entry, _review_entry_address)) => Ok(Some(review_entry)),
+        None => Ok(None),
+    }
+}
+
+pub fn update(
+    review_anchor_address: &Address,
+    rating: u8,
+    text: String,
+    timestamp: u64,
+) -> ZomeApiResult<Address> {
+    let latest_review_result = get_latest_review(review_anchor_address)?;
+    match latest_review_result {
+        Some((mut previous_review, previous_review_address)) => {
+            // update the review
+            previous_review.rating = rating;
+            previous_review.text = text;
+            previous_review.timestamp = timestamp;
+            // commit this update to the DHT.
+            let new_review_address =
+                hdk::update_entry(previous_review.entry(), &previous_review_address)?;
+
+            // remove link to previous version of review
+            hdk::remove_link(
+                review_anchor_address,
+                &previous_review_address,
+                ReviewAnchor::link_type(),
+                "".to_owned(),
+            )?;
+
+            // create link to new version of review
+            hdk::link_entries(
+                review_anchor_address,
+                &new_review_address,
+                ReviewAnchor::link_type(),
+                "".to_owned(),
+            )?;
+
+            Ok(review_anchor_address.clone())
+        }
+        None => Err(ZomeError::deleted("review", "update").into()),
+    }
+}
+
+pub fn get_reviews(course_anchor_address: &Address) -> ZomeApiResult<Vec<Address>> {
+    let links = hdk::get_links(
+        course_anchor_address,
+        LinkMatch::Exactly(COURSE_ANCHOR_TO_REVIEW_ANCHOR_LINK),
+        LinkMatch::Any,
+    )?;
+
+    Ok(links.addresses())
+}
+
+pub fn get_rating_summary(course_anchor_address: &Address) -> ZomeApiResult<RatingSummary> {
+    let mut histogram = vec![0; MAX_RATING as usize];
+    for review_anchor_address in get_reviews(course_anchor_address)? {
+        // NOTE: one broken review shouldn't break the summary of the whole course,
+        // so we're skipping reviews that can't be read
+        if let Ok(Some((review, _review_address))) = get_latest_review(&review_anchor_address) {
+            let amount = (review.rating as usize)
+                .checked_sub(1)
+                .and_then(|index| histogram.get_mut(index));
+            if let Some(amount) = amount {
+                *amount += 1;
+            }
+        }
+    }
+
+    let count: usize = histogram.iter().sum();
+    let total_stars: usize = histogram
+        .iter()
+        .enumerate()
+        .map(|(index, amount)| (index + 1) * amount)
+        .sum();
+    let average = if count == 0 {
+        0.0
+    } else {
+        total_stars as f64 / count as f64
+    };
+
+    Ok(RatingSummary {
+        average,
+        count,
+        histogram,
+    })
+}
@@ -0,0 +1,187 @@
+use hdk::holochain_core_types::chain_header::ChainHeader;
+use hdk::prelude::*;
+use hdk::{LinkValidationData, ValidationData};
+
+use super::{
+    anchor::ReviewAnchor,
+    entry::{Review, MAX_RATING, MAX_TEXT_LEN, MIN_RATING},
+};
+use crate::course;
+use crate::course::anchor::CourseAnchor;
+use crate::error::ZomeError;
+use crate::helper;
+
+// validates that student_address belongs to a student enrolled in the course and not to it's teacher
+fn validate_reviewer(
+    course_anchor_address: &Address,
+    student_address: &Address,
+) -> Result<(), String> {
+    let course_anchor: CourseAnchor = hdk::utils::get_as_type(course_anchor_address.clone())?;
+    if &course_anchor.teacher_address == student_address {
+        return Err(ZomeError::forbidden("Teacher can't review their own course").into());
+    }
+    let students = course::handlers::get_students(course_anchor_address.clone())?;
+    if !students.contains(student_address) {
+        return Err(
+            ZomeError::forbidden("Only students enrolled in the course can review it").into(),
+        );
+    }
+    Ok(())
+}
+
+fn validate_review_content(review: &Review) -> Result<(), String> {
+    if review.rating < MIN_RATING || review.rating > MAX_RATING {
+        return Err(ZomeError::invalid_value(
+            "review",
+            "rating",
+            &format!("has to be from {} to {} stars", MIN_RATING, MAX_RATING),
+        )
+        .into());
+    }
+    if review.text.len() > MAX_TEXT_LEN {
+        return Err(ZomeError::text_too_long("review", "text", MAX_TEXT_LEN).into());
+    }
+    Ok(())
+}
+
+pub fn create(entry: Review, validation_data: ValidationData) -> Result<(), String> {
+    helper::validate_only_author_can_do(
+        &entry.student_address,
+        validation_data.sources(),
+        "review",
+        "create",
+    )?;
+    validate_review_content(&entry)?;
+    validate_reviewer(&entry.course_anchor_address, &entry.student_address)
+}
+
+pub fn modify(
+    new_entry: Review,
+    old_entry: Review,
+    _old_entry_header: ChainHeader,
+    validation_data: ValidationData,
+) -> Result<(), String> {
+    helper::validate_only_author_can_do(
+        &old_entry.student_address,
+        validation_data.sources(),
+        "review",
+        "modify",
+    )?;
+    if new_entry.student_address != old_entry.student_address {
+        return Err(ZomeError::immutable_field("review", "author").into());
+    }
+    if new_entry.course_anchor_address != old_entry.course_anchor_address {
+        return Err(ZomeError::immutable_field("review", "course").into());
+    }
+    validate_review_content(&new_entry)
+}
+
+pub fn delete(
+    entry: Review,
+    _entry_header: ChainHeader,
+    validation_data: ValidationData,
+) -> Result<(), String> {
+    helper::validate_only_author_can_do(
+        &entry.student_address,
+        validation_data.sources(),
+        "review",
+        "delete",
+    )
+}
+
+// =========================== ReviewAnchor validation
+pub fn anchor_create(entry: ReviewAnchor, validation_data: ValidationData) -> Result<(), String> {
+    helper::validate_only_author_can_do(
+        &entry.student_address,
+        validation_data.sources(),
+        "review",
+        "create",
+    )?;
+    validate_reviewer(&entry.course_anchor_address, &entry.student_address)
+}
+
+// NOTE: we don't accept any parameters here because we don't need them to always return an error
+// because this anchor can never be modified
+pub fn anchor_modify() -> Result<(), String> {
+    Err(ZomeError::immutable_entry("review_anchor").into())
+}
+
+pub fn anchor_delete(
+    entry: ReviewAnchor,
+    _entry_header: ChainHeader,
+    validation_data: ValidationData,
+) -> Result<(), String> {
+    helper::validate_only_author_can_do(
+        &entry.student_address,
+        validation_data.sources(),
+        "review",
+        "delete",
+    )
+}
+
+//  =========================== ReviewAnchor links validation
+pub fn anchor_to_review_link(validation_data: LinkValidationData) -> Result<(), String> {
+    match validation_data {
+        hdk::LinkValidationData::LinkAdd {
+            link,
+            validation_data,
+        } => {
+            // get author of this entry
+            let author = validation_data.package.chain_header.provenances()[0].source();
+            // get link base: entry from which the link goes
+            let base: ReviewAnchor = hdk::utils::get_as_type(link.link.base().clone())?;
+            // get link target: entry to which the link goes
+            let target: Review = hdk::utils::get_as_type(link.link.target().clone())?;
+            if base.student_address != target.student_address {
+                return Err(ZomeError::invalid_link("review_anchor->review", "Can't link ReviewAnchor to Review because their student addresses are different").into());
+            } else if author != base.student_address {
+                return Err(ZomeError::invalid_link("review_anchor->review", "Can't link ReviewAnchor to Review because your address isn't specified as student address for this review").into());
+            }
+            if base.course_anchor_address != target.course_anchor_address {
+                return Err(ZomeError::invalid_link(
+                    "review_anchor->review",
+                    "Can't link ReviewAnchor to Review because they belong to different courses",
+                )
+                .into());
+            }
+            Ok(())
+        }
+        hdk::LinkValidationData::LinkRemove {
+            link,
+            validation_data,
+        } => {
+            let author = validation_data.package.chain_header.provenances()[0].source();
+            let base: ReviewAnchor = hdk::utils::get_as_type(link.link.base().clone())?;
+            if author != base.student_address {
+                return Err(ZomeError::invalid_link("review_anchor->review", "Can't remove link from ReviewAnchor to Review because your address isn't specified as student address for this review").into());
+            }
+            Ok(())
+        }
+    }
+}
+
+pub fn course_anchor_to_anchor_link(validation_data: LinkValidationData) -> Result<(), String> {
+    let (link, validation_data) = match validation_data {
+        hdk::LinkValidationData::LinkAdd {
+            link,
+            validation_data,
+        } => (link, validation_data),
+        hdk::LinkValidationData::LinkRemove {
+            link,
+            validation_data,
+        } => (link, validation_data),
+    };
+    let author = validation_data.package.chain_header.provenances()[0].source();
+    let target: ReviewAnchor = hdk::utils::get_as_type(link.link.target().clone())?;
+    if author != target.student_address {
+        return Err(ZomeError::invalid_link("course_anchor->review_anchor", "Can't link CourseAnchor to ReviewAnchor because your address isn't specified as student address for this review").into());
+    }
+    if link.link.base() != &target.course_anchor_address {
+        return Err(ZomeError::invalid_link(
+            "course_anchor->review_anchor",
+            "Can't link CourseAnchor to ReviewAnchor of a different course",
+        )
+        .into());
+    }
+    Ok(())
+}
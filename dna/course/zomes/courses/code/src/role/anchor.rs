@@ -0,0 +1,70 @@
+use hdk::prelude::*;
+use holochain_entry_utils::HolochainEntry;
+
+use super::entry::{Role, RoleGrant};
+use super::validation;
+use crate::anchor_trait::AnchorTrait;
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct RoleAnchor {
+    // NOTE: there are no other fields here on purpose: there is a single anchor for every role
+    // that links to all grants of this role, so we can list agents who have it
+    pub role: Role,
+}
+
+impl AnchorTrait for RoleAnchor {
+    fn entry_type() -> String {
+        String::from("role_anchor")
+    }
+    fn link_to() -> String {
+        RoleGrant::entry_type()
+    }
+    fn link_type() -> String {
+        "role_anchor->role_grant".to_owned()
+    }
+}
+
+impl RoleAnchor {
+    pub fn new(role: Role) -> Self {
+        RoleAnchor { role }
+    }
+}
+
+pub fn role_anchor_def() -> ValidatingEntryType {
+    entry!(
+        name: RoleAnchor::entry_type(),
+        description: "Anchor to all grants of the role",
+        sharing: Sharing::Public,
+        validation_package: || {
+            hdk::ValidationPackageDefinition::Entry
+        },
+        validation: | validation_data: hdk::EntryValidationData<RoleAnchor>| {
+            match validation_data{
+                EntryValidationData::Create { validation_data, .. } => {
+                    validation::anchor_create(validation_data)
+                 },
+                 // NOTE: the symbol .. means that we're skipping unpacking parameters that we receive here
+                 // because we won't need them
+                 EntryValidationData::Modify { .. } => {
+                    validation::anchor_modify()
+                 },
+                 EntryValidationData::Delete { .. } => {
+                    validation::anchor_delete()
+                 }
+            }
+        },
+        links:[
+            // link that connects RoleAnchor to every grant of this role
+            to!(
+                RoleAnchor::link_to(),
+                link_type: RoleAnchor::link_type(),
+                validation_package:||{
+                    hdk::ValidationPackageDefinition::Entry
+                },
+                validation:|validation_data: hdk::LinkValidationData|{
+                    validation::anchor_to_role_grant_link(validation_data)
+                }
+            )
+        ]
+    )
+}
@@ -0,0 +1,92 @@
+use hdk::prelude::*;
+use holochain_entry_utils::HolochainEntry;
+
+use super::validation;
+
+// name of the DNA property that lists addresses of agents who can grant and revoke roles.
+// If the DNA doesn't have this property, roles aren't enforced and every agent can teach
+pub const ADMINS_PROPERTY: &str = "admins";
+pub const AGENT_TO_ROLE_GRANT_LINK: &str = "agent->role_grant";
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    Teacher,
+}
+
+impl Role {
+    // used to tag links to role grants so we can only get grants for a particular role
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Teacher => "teacher",
+        }
+    }
+}
+
+// Grant of the role to the agent by one of the admins.
+// Grant is revoked by deleting this entry
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct RoleGrant {
+    pub role: Role,
+    pub agent_address: Address,
+    pub admin_address: Address,
+    // NOTE: timestamp makes every grant unique, so the role can be granted again after it was revoked
+    pub timestamp: u64,
+}
+
+impl RoleGrant {
+    pub fn new(role: Role, agent_address: Address, admin_address: Address, timestamp: u64) -> Self {
+        RoleGrant {
+            role,
+            agent_address,
+            admin_address,
+            timestamp,
+        }
+    }
+}
+
+impl HolochainEntry for RoleGrant {
+    fn entry_type() -> String {
+        String::from("role_grant")
+    }
+}
+
+pub fn role_grant_entry_def() -> ValidatingEntryType {
+    entry!(
+        name: RoleGrant::entry_type(),
+        description: "this is a role that admin has granted to the agent",
+        sharing: Sharing::Public,
+        validation_package: || {
+            hdk::ValidationPackageDefinition::Entry
+        },
+        validation: | validation_data: hdk::EntryValidationData<RoleGrant>| {
+            match validation_data {
+                EntryValidationData::Create { entry, validation_data } => {
+                    validation::create(entry, validation_data)
+                },
+                // NOTE: the symbol .. means that we're skipping unpacking parameters that we receive here
+                // because we won't need them
+                EntryValidationData::Modify { .. } => {
+                    validation::modify()
+                },
+                EntryValidationData::Delete { old_entry, old_entry_header, validation_data } => {
+                    validation::delete(old_entry, old_entry_header, validation_data)
+                }
+            }
+        },
+        links: [
+            // link from the agent who was granted the role
+            // This is for anyone to check which roles the agent has
+            from!(
+                "%agent_id", // this is a special string that would automatically expand to the hdk::AGENT_ADDRESS
+                link_type: AGENT_TO_ROLE_GRANT_LINK,
+                validation_package: || {
+                    hdk::ValidationPackageDefinition::Entry
+                },
+                validation: | validation_data: hdk::LinkValidationData | {
+                    validation::agent_to_role_grant_link(validation_data)
+                }
+            )
+        ]
+    )
+}
@@ -0,0 +1,132 @@
+use hdk::prelude::*;
+use hdk::AGENT_ADDRESS;
+use holochain_entry_utils::HolochainEntry;
+
+use super::anchor::RoleAnchor;
+use super::entry::{Role, RoleGrant, AGENT_TO_ROLE_GRANT_LINK};
+use crate::anchor_trait::AnchorTrait;
+use crate::error::ZomeError;
+
+pub fn grant(agent_address: Address, role: Role, timestamp: u64) -> ZomeApiResult<Address> {
+    if get_roles(&agent_address)?.contains(&role) {
+        return Err(ZomeError::already_exists(
+            "role_grant",
+            &format!("Agent already has the {} role", role.as_str()),
+        )
+        .into());
+    }
+    // if role anchor already exists, this would just return it's address without actually writing anything
+    let role_anchor_address = hdk::commit_entry(&RoleAnchor::new(role.clone()).entry())?;
+    let role_tag = role.as_str();
+    let role_grant = RoleGrant::new(
+        role,
+        agent_address.clone(),
+        AGENT_ADDRESS.clone(),
+        timestamp,
+    );
+    let role_grant_address = hdk::commit_entry(&role_grant.entry())?;
+    // link from the agent so we can check their roles
+    hdk::link_entries(
+        &agent_address,
+        &role_grant_address,
+        AGENT_TO_ROLE_GRANT_LINK,
+        role_tag,
+    )?;
+    // link from the role anchor so we can list everyone who has this role
+    hdk::link_entries(
+        &role_anchor_address,
+        &role_grant_address,
+        RoleAnchor::link_type(),
+        "".to_owned(),
+    )?;
+    Ok(role_grant_address)
+}
+
+// returns addresses of all valid grants of the role to the agent
+fn get_role_grant_addresses(agent_address: &Address, role: &Role) -> ZomeApiResult<Vec<Address>> {
+    let links = hdk::get_links(
+        agent_address,
+        LinkMatch::Exactly(AGENT_TO_ROLE_GRANT_LINK),
+        LinkMatch::Exactly(role.as_str()),
+    )?;
+    let mut role_grant_addresses = vec![];
+    for role_grant_address in links.addresses() {
+        // revoked grants are deleted so get_entry won't return them
+        if hdk::get_entry(&role_grant_address)?.is_some() {
+            role_grant_addresses.push(role_grant_address);
+        }
+    }
+    Ok(role_grant_addresses)
+}
+
+pub fn revoke(agent_address: Address, role: Role) -> ZomeApiResult<Vec<Address>> {
+    let role_grant_addresses = get_role_grant_addresses(&agent_address, &role)?;
+    if role_grant_addresses.is_empty() {
+        return Err(ZomeError::not_found("role_grant").into());
+    }
+    let role_anchor_address = RoleAnchor::new(role.clone()).address()?;
+    for role_grant_address in role_grant_addresses.iter() {
+        hdk::remove_link(
+            &agent_address,
+            role_grant_address,
+            AGENT_TO_ROLE_GRANT_LINK,
+            role.as_str(),
+        )?;
+        hdk::remove_link(
+            &role_anchor_address,
+            role_grant_address,
+            RoleAnchor::link_type(),
+            "".to_owned(),
+        )?;
+        hdk::remove_entry(role_grant_address)?;
+    }
+    Ok(role_grant_addresses)
+}
+
+pub fn get_roles(agent_address: &Address) -> ZomeApiResult<Vec<Role>> {
+    let mut roles = vec![];
+    for role in vec![Role::Teacher] {
+        if !get_role_grant_addresses(agent_address, &role)?.is_empty() {
+            roles.push(role);
+        }
+    }
+    Ok(roles)
+}
+
+// returns address of a valid grant of the role to the current agent. Entries that can only be authored with
+// the role refer to this grant, so they can be validated against the time it was revoked
+pub fn get_my_role_grant(role: &Role) -> ZomeApiResult<Option<Address>> {
+    Ok(get_role_grant_addresses(&AGENT_ADDRESS, role)?
+        .into_iter()
+        .next())
+}
+
+pub fn get_my_roles() -> ZomeApiResult<Vec<Role>> {
+    get_roles(&AGENT_ADDRESS)
+}
+
+// lists agents who currently have the teacher role
+pub fn list_teachers() -> ZomeApiResult<Vec<Address>> {
+    let role_grant_addresses = hdk::get_links(
+        &RoleAnchor::new(Role::Teacher).address()?,
+        LinkMatch::Exactly(&RoleAnchor::link_type()),
+        LinkMatch::Any,
+    )?
+    .addresses();
+    let mut teachers: Vec<Address> = vec![];
+    for role_grant_address in role_grant_addresses {
+        let role_grant = match hdk::get_entry(&role_grant_address)? {
+            Some(entry) => RoleGrant::from_entry(&entry).ok_or_else(|| {
+                ZomeApiError::from(ZomeError::inconsistent_links(
+                    "role anchor is linked to an entry that isn't a role grant",
+                ))
+            })?,
+            // revoked grants are deleted so get_entry won't return them
+            None => continue,
+        };
+        if !teachers.contains(&role_grant.agent_address) {
+            teachers.push(role_grant.agent_address);
+        }
+    }
+    Ok(teachers)
+}
@@ -0,0 +1,4 @@
+pub mod anchor;
+pub mod entry;
+pub mod handlers;
+pub mod validation;
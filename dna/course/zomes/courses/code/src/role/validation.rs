@@ -0,0 +1,213 @@
+use hdk::holochain_core_types::chain_header::ChainHeader;
+use hdk::holochain_core_types::crud_status::CrudStatus;
+use hdk::prelude::*;
+use hdk::{LinkValidationData, ValidationData};
+use holochain_entry_utils::HolochainEntry;
+
+use super::anchor::RoleAnchor;
+use super::entry::{Role, RoleGrant, ADMINS_PROPERTY};
+use crate::anchor_trait::AnchorTrait;
+use crate::clock;
+use crate::error::ZomeError;
+use crate::helper;
+
+// validates that one of validation_data_sources is listed as admin in the DNA properties
+fn validate_only_admin_can_do(
+    validation_data_sources: Vec<Address>,
+    action_name: &str,
+) -> Result<(), String> {
+    match helper::get_address_list_property(ADMINS_PROPERTY)? {
+        Some(admins) => {
+            if !validation_data_sources
+                .iter()
+                .any(|source| admins.contains(source))
+            {
+                return Err(ZomeError::not_admin(action_name).into());
+            }
+            Ok(())
+        }
+        None => Err(ZomeError::forbidden(
+            "Roles can't be managed because this DNA doesn't have any admins",
+        )
+        .into()),
+    }
+}
+
+// returns time at which the grant was revoked or None if it's still valid.
+// Grants are revoked by deleting them, so this is the time of the header of the deletion entry
+fn get_revocation_time(role_grant_address: &Address) -> Result<Option<u64>, String> {
+    let history = match hdk::get_entry_result(
+        role_grant_address,
+        GetEntryOptions {
+            status_request: StatusRequestKind::All,
+            ..GetEntryOptions::default()
+        },
+    )?
+    .result
+    {
+        GetEntryResultType::All(history) => history,
+        GetEntryResultType::Single(_item) => return Ok(None),
+    };
+    let deleted = history.items.iter().any(|item| match &item.meta {
+        Some(meta) => meta.crud_status == CrudStatus::Deleted,
+        None => false,
+    });
+    if !deleted {
+        return Ok(None);
+    }
+    let deletion_address = history
+        .crud_links
+        .get(role_grant_address)
+        .ok_or_else(|| String::from(ZomeError::not_found("revocation of the role grant")))?;
+    let deletion = hdk::get_entry_result(
+        deletion_address,
+        GetEntryOptions {
+            headers: true,
+            ..GetEntryOptions::default()
+        },
+    )?;
+    match deletion.result {
+        GetEntryResultType::Single(item) => match item.headers.first() {
+            Some(header) => Ok(Some(clock::header_time(header))),
+            None => Err(ZomeError::not_found("header of the role grant revocation").into()),
+        },
+        GetEntryResultType::All(_history) => {
+            Err(ZomeError::not_found("revocation of the role grant").into())
+        }
+    }
+}
+
+// validates that the grant at role_grant_address gave the role to agent_address and wasn't revoked before
+// the entry was authored. It only depends on entries and headers that don't change once they're on the DHT,
+// so entries that were valid when they were authored stay valid after the role is revoked.
+// NOTE: if DNA doesn't define any admins, roles aren't enforced
+pub fn validate_has_role(
+    agent_address: &Address,
+    role: Role,
+    role_grant_address: &Option<Address>,
+    validation_data: &ValidationData,
+) -> Result<(), String> {
+    if helper::get_address_list_property(ADMINS_PROPERTY)?.is_none() {
+        return Ok(());
+    }
+    let missing_role = || {
+        String::from(ZomeError::forbidden(&format!(
+            "Only agents with the {} role can do this",
+            role.as_str()
+        )))
+    };
+    let role_grant_address = role_grant_address.as_ref().ok_or_else(missing_role)?;
+    // NOTE: we're asking for the initial version of the grant because hdk::get_entry doesn't return revoked ones
+    let role_grant = match hdk::get_entry_result(
+        role_grant_address,
+        GetEntryOptions {
+            status_request: StatusRequestKind::Initial,
+            ..GetEntryOptions::default()
+        },
+    )?
+    .result
+    {
+        GetEntryResultType::Single(item) => {
+            item.entry.and_then(|entry| RoleGrant::from_entry(&entry))
+        }
+        GetEntryResultType::All(_history) => None,
+    }
+    .ok_or_else(missing_role)?;
+    if &role_grant.agent_address != agent_address || role_grant.role != role {
+        return Err(missing_role());
+    }
+    if let Some(revoked_at) = get_revocation_time(role_grant_address)? {
+        if clock::header_time(&validation_data.package.chain_header) >= revoked_at {
+            return Err(missing_role());
+        }
+    }
+    Ok(())
+}
+
+pub fn create(entry: RoleGrant, validation_data: ValidationData) -> Result<(), String> {
+    validate_only_admin_can_do(validation_data.sources(), "grant roles")?;
+    if !validation_data.sources().contains(&entry.admin_address) {
+        return Err(ZomeError::forbidden(
+            "Role grant has to be signed by the admin who is specified in it",
+        )
+        .into());
+    }
+    Ok(())
+}
+
+// NOTE: we don't accept any parameters here because we don't need them to always return an error
+// because role grant can only be revoked, not changed
+pub fn modify() -> Result<(), String> {
+    Err(ZomeError::immutable_entry("role_grant").into())
+}
+
+pub fn delete(
+    _entry: RoleGrant,
+    _entry_header: ChainHeader,
+    validation_data: ValidationData,
+) -> Result<(), String> {
+    // any admin can revoke role, not only the one who granted it
+    validate_only_admin_can_do(validation_data.sources(), "revoke roles")
+}
+
+// =========================== RoleAnchor validation
+pub fn anchor_create(validation_data: ValidationData) -> Result<(), String> {
+    validate_only_admin_can_do(validation_data.sources(), "grant roles")
+}
+
+// NOTE: we don't accept any parameters here because we don't need them to always return an error
+// because this anchor can never be modified
+pub fn anchor_modify() -> Result<(), String> {
+    Err(ZomeError::immutable_entry("role_anchor").into())
+}
+
+pub fn anchor_delete() -> Result<(), String> {
+    Err(ZomeError::forbidden("Can't delete the RoleAnchor entry: it can only be created").into())
+}
+
+//  =========================== RoleGrant links validation
+// returns author and target of the link if link author is one of the admins
+fn validate_link_to_role_grant(
+    validation_data: LinkValidationData,
+) -> Result<(Address, RoleGrant), String> {
+    let (link, validation_data) = match validation_data {
+        hdk::LinkValidationData::LinkAdd {
+            link,
+            validation_data,
+        } => (link, validation_data),
+        hdk::LinkValidationData::LinkRemove {
+            link,
+            validation_data,
+        } => (link, validation_data),
+    };
+    validate_only_admin_can_do(
+        vec![validation_data.package.chain_header.provenances()[0].source()],
+        "manage roles",
+    )?;
+    let target: RoleGrant = hdk::utils::get_as_type(link.link.target().clone())?;
+    Ok((link.link.base().clone(), target))
+}
+
+pub fn agent_to_role_grant_link(validation_data: LinkValidationData) -> Result<(), String> {
+    let (base, target) = validate_link_to_role_grant(validation_data)?;
+    if base != target.agent_address {
+        return Err(ZomeError::invalid_link(
+            "agent->role_grant",
+            "Can't link agent to the role grant of another agent",
+        )
+        .into());
+    }
+    Ok(())
+}
+
+pub fn anchor_to_role_grant_link(validation_data: LinkValidationData) -> Result<(), String> {
+    let (base, target) = validate_link_to_role_grant(validation_data)?;
+    if base != RoleAnchor::new(target.role).address()? {
+        return Err(ZomeError::invalid_link(
+            "role_anchor->role_grant",
+            "Can't link RoleAnchor to the grant of a different role",
+        )
+        .into());
+    }
+    Ok(())
+}
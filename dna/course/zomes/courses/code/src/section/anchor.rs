@@ -1,11 +1,17 @@
 use super::{entry::Section, validation};
 use crate::anchor_trait::AnchorTrait;
+use crate::content::anchor::ContentAnchor;
 use crate::content::entry::Content;
 
 use hdk::prelude::*;
 use holochain_entry_utils::HolochainEntry;
 
+pub const SECTION_TO_CONTENT_ANCHOR_LINK: &str = "section_anchor->content_anchor";
+// NOTE: before ContentAnchor was introduced, sections linked directly to Content entries with this link.
+// It's only kept so content::handlers::migrate_contents could find these links and replace them with anchors
 pub const SECTION_TO_CONTENT_LINK: &str = "section_anchor->content";
+// links SectionAnchor of the section that was moved to another course to it's new SectionAnchor
+pub const SECTION_ANCHOR_TO_MOVED_SECTION_LINK: &str = "section_anchor->moved_section_anchor";
 
 #[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
 pub struct SectionAnchor {
@@ -13,7 +19,15 @@ pub struct SectionAnchor {
     //  and wouldn't be used to display data about section to a user
     pub title: String,
     pub course_address: Address,
+    // SectionAnchor of the parent section for subsections and None for top-level sections.
+    // NOTE: it's part of the anchor so sections can't be moved around the tree: this is what keeps it free of cycles
+    pub parent_address: Option<Address>,
     pub timestamp: u64,
+    // position of the section in it's course or parent section when it was created.
+    // It keeps anchors of sections with the same title that were created in the same second apart.
+    // NOTE: anchors that were created before it was introduced don't have this field
+    #[serde(default)]
+    pub ordinal: u64,
     // NOTE: storing teacher_address sure requires more memory for each and every SectionAnchor entry but instead
     // it gives us the ability to quickly validate that only the Course's teacher is deleting this SectionAnchor entry.
     // So we're trading smaller memory footprint for a less error-prone and less CPU-intensive validation, because it
@@ -39,13 +53,17 @@ impl SectionAnchor {
     pub fn new(
         title: String,
         course_address: Address,
+        parent_address: Option<Address>,
         timestamp: u64,
+        ordinal: u64,
         teacher_address: Address,
     ) -> Self {
         SectionAnchor {
             title: title,
             course_address: course_address,
+            parent_address,
             timestamp: timestamp,
+            ordinal,
             teacher_address: teacher_address,
         }
     }
@@ -85,6 +103,26 @@ pub fn section_anchor_def() -> ValidatingEntryType {
                    validation::anchor_to_section_link(validation_data)
                 }
             ),
+            to!(
+                ContentAnchor::entry_type(),
+                link_type: SECTION_TO_CONTENT_ANCHOR_LINK,
+                validation_package:||{
+                    hdk::ValidationPackageDefinition::Entry
+                },
+                validation:|validation_data: hdk::LinkValidationData|{
+                    validation::section_to_content_anchor_link(validation_data)
+                }
+            ),
+            to!(
+                SectionAnchor::entry_type(),
+                link_type: SECTION_ANCHOR_TO_MOVED_SECTION_LINK,
+                validation_package:||{
+                    hdk::ValidationPackageDefinition::Entry
+                },
+                validation:|validation_data: hdk::LinkValidationData|{
+                    validation::anchor_to_moved_section_link(validation_data)
+                }
+            ),
             to!(
                 Content::entry_type(),
                 link_type: SECTION_TO_CONTENT_LINK,
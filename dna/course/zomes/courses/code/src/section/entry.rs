@@ -8,12 +8,22 @@ use holochain_entry_utils::HolochainEntry;
 
 use super::validation;
 
-pub const MAX_TITLE_LEN: usize = 200;
+pub use courses_core::section::{MAX_DEPTH, MAX_TITLE_LEN};
 
 #[derive(Serialize, Deserialize, Debug, DefaultJson, Clone)]
 pub struct Section {
     pub title: String,
     pub course_address: Address,
+    // same as SectionAnchor::parent_address. Sections that were created before subsections were introduced
+    // don't have this field and are top-level ones
+    #[serde(default)]
+    pub parent_address: Option<Address>,
+    // SectionAnchor addresses of subsections in the order they should be displayed
+    #[serde(default)]
+    pub subsections: Vec<Address>,
+    // time before which students can't see the content of this section. None means it's released right away
+    #[serde(default)]
+    pub release_at: Option<u64>,
     pub timestamp: u64,
     pub anchor_address: Address,
     // NOTE: storing teacher_address sure requires more memory for each and every Section entry but instead
@@ -29,6 +39,7 @@ impl Section {
     pub fn new(
         title: String,
         course_address: Address,
+        parent_address: Option<Address>,
         timestamp: u64,
         anchor_address: Address,
         teacher_address: Address,
@@ -36,6 +47,9 @@ impl Section {
         Section {
             title: title,
             course_address: course_address,
+            parent_address,
+            subsections: Vec::new(),
+            release_at: None,
             timestamp: timestamp,
             anchor_address: anchor_address,
             teacher_address: teacher_address,
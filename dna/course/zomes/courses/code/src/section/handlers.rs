@@ -1,11 +1,71 @@
 use hdk::prelude::*;
+use hdk::AGENT_ADDRESS;
 use holochain_entry_utils::HolochainEntry;
 
-use super::anchor::SectionAnchor;
+use super::anchor::{SectionAnchor, SECTION_ANCHOR_TO_MOVED_SECTION_LINK};
 use super::entry::Section;
 use crate::anchor_trait::AnchorTrait;
+use crate::assignment;
+use crate::content;
+use crate::content::entry::Content;
 use crate::course;
+use crate::error::ZomeError;
 use crate::helper;
+use crate::release::handlers::{SectionLock, SectionLocks};
+use crate::signal::{self, ChangeKind, ChangeNotice};
+use courses_core::section as rules;
+
+// commits SectionAnchor and Section entries and links them together without adding section to the course.
+// This is for callers that update the course themselves, like batch::handlers::apply
+pub fn commit_new_section(
+    title: String,
+    course_anchor_address: &Address,
+    parent_address: Option<Address>,
+    timestamp: u64,
+    ordinal: u64,
+    teacher_address: Address,
+) -> ZomeApiResult<Address> {
+    // initialize SectionAnchor instance
+    let section_anchor = SectionAnchor::new(
+        title.clone(),
+        course_anchor_address.clone(),
+        parent_address.clone(),
+        timestamp,
+        ordinal,
+        teacher_address.clone(),
+    );
+    // SectionAnchor address depends only on it's fields, so the same anchor could already be committed
+    if hdk::get_entry(&section_anchor.address()?)?.is_some() {
+        return Err(ZomeError::already_exists(
+            "section",
+            "Section with this title was already created here at the same time",
+        )
+        .into());
+    }
+    // commit SectionAnchor to DHT
+    let section_anchor_address = hdk::commit_entry(&section_anchor.entry())?;
+
+    // initialize Section instance without commiting it to DHT: we'll need it to commit anchor
+    let new_section = Section::new(
+        title,
+        course_anchor_address.clone(),
+        parent_address,
+        timestamp,
+        section_anchor_address.clone(),
+        teacher_address,
+    );
+    // commit Section to DHT
+    let new_section_address = hdk::commit_entry(&new_section.entry())?;
+
+    hdk::link_entries(
+        &section_anchor_address,
+        &new_section_address,
+        SectionAnchor::link_type(),
+        "".to_owned(),
+    )?;
+
+    Ok(section_anchor_address)
+}
 
 pub fn create(
     title: String,
@@ -18,32 +78,13 @@ pub fn create(
 
     match latest_course_result {
         Some((previous_course, _previous_course_address)) => {
-            // initialize SectionAnchor instance
-            let section_anchor = SectionAnchor::new(
-                title.clone(),
-                course_anchor_address.clone(),
-                timestamp,
-                previous_course.teacher_address.clone(),
-            );
-            // commit SectionAnchor to DHT
-            let section_anchor_address = hdk::commit_entry(&section_anchor.entry())?;
-
-            // initialize Section instance without commiting it to DHT: we'll need it to commit anchor
-            let new_section = Section::new(
+            let section_anchor_address = commit_new_section(
                 title,
-                course_anchor_address.clone(),
+                course_anchor_address,
+                None,
                 timestamp,
-                section_anchor_address.clone(),
+                previous_course.sections.len() as u64,
                 previous_course.teacher_address,
-            );
-            // commit Section to DHT
-            let new_section_address = hdk::commit_entry(&new_section.entry())?;
-
-            hdk::link_entries(
-                &section_anchor_address,
-                &new_section_address,
-                SectionAnchor::link_type(),
-                "".to_owned(),
             )?;
 
             course::handlers::add_section(
@@ -51,33 +92,132 @@ pub fn create(
                 &section_anchor_address,
                 timestamp,
             )?;
+
+            signal::notify(ChangeNotice::section(
+                ChangeKind::Created,
+                course_anchor_address.clone(),
+                section_anchor_address.clone(),
+            ))?;
             // SectionAnchor serves as this section's ID so we return it
             Ok(section_anchor_address)
         }
         None => {
-            return Err(ZomeApiError::from(
-                "Can't create a section in deleted course".to_owned(),
-            ));
+            return Err(ZomeError::deleted("course", "create a section in").into());
+        }
+    }
+}
+
+// creates section inside of the section at parent_address. Unlike top-level sections, subsections aren't listed
+// in the Course entry but in the Section entry of their parent
+pub fn create_subsection(
+    title: String,
+    parent_address: &Address,
+    timestamp: u64,
+) -> ZomeApiResult<Address> {
+    match get_latest_section(parent_address)? {
+        Some((mut parent_section, parent_section_address)) => {
+            let section_anchor_address = commit_new_section(
+                title,
+                &parent_section.course_address,
+                Some(parent_address.clone()),
+                timestamp,
+                parent_section.subsections.len() as u64,
+                parent_section.teacher_address.clone(),
+            )?;
+
+            parent_section
+                .subsections
+                .push(section_anchor_address.clone());
+            parent_section.timestamp = timestamp;
+            commit_update(
+                parent_section.clone(),
+                &parent_section_address,
+                parent_address,
+            )?;
+
+            signal::notify(ChangeNotice::section(
+                ChangeKind::Created,
+                parent_section.course_address,
+                section_anchor_address.clone(),
+            ))?;
+            Ok(section_anchor_address)
         }
+        None => Err(ZomeError::deleted("section", "create a subsection in").into()),
     }
 }
 
+// returns section_anchor_addresses together with addresses of all their subsections, each section followed by
+// it's subsections, in the order they're displayed. Deleted sections are skipped together with their subsections
+pub fn with_subsections(section_anchor_addresses: &[Address]) -> ZomeApiResult<Vec<Address>> {
+    let mut addresses = vec![];
+    for section_anchor_address in section_anchor_addresses {
+        if let Some((section, _section_address)) = get_latest_section(section_anchor_address)? {
+            addresses.push(section_anchor_address.clone());
+            addresses.extend(with_subsections(&section.subsections)?);
+        }
+    }
+    Ok(addresses)
+}
+
 pub fn get_latest_section(
     section_anchor_address: &Address,
 ) -> ZomeApiResult<Option<(Section, Address)>> {
     helper::get_latest_data_entry::<Section>(section_anchor_address, &SectionAnchor::link_type())
 }
 
-pub fn get_latest_section_entry(section_anchor_address: Address) -> ZomeApiResult<Option<Section>> {
+// Section together with the reason why it isn't available to the current agent yet
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct SectionWithLock {
+    #[serde(flatten)]
+    pub section: Section,
+    // None for the teacher and for sections that are already released to the student
+    pub lock: Option<SectionLock>,
+}
+
+pub fn get_latest_section_entry(
+    section_anchor_address: Address,
+) -> ZomeApiResult<Option<SectionWithLock>> {
     let latest_section_result = get_latest_section(&section_anchor_address)?;
     match latest_section_result {
         Some((section_entry, _section_entry_address)) => {
-            return Ok(Some(section_entry));
+            let lock = SectionLocks::load(&section_entry.course_address)?.get(&section_entry)?;
+            return Ok(Some(SectionWithLock {
+                section: section_entry,
+                lock,
+            }));
         }
         None => return Ok(None),
     }
 }
 
+// commits updated section and moves link from SectionAnchor to it's new version
+pub fn commit_update(
+    section: Section,
+    previous_section_address: &Address,
+    section_anchor_address: &Address,
+) -> ZomeApiResult<Address> {
+    // commit this update to the DHT.
+    let new_section_address = hdk::update_entry(section.entry(), previous_section_address)?;
+
+    // remove link to previous version of section
+    hdk::remove_link(
+        section_anchor_address,
+        previous_section_address,
+        SectionAnchor::link_type(),
+        "".to_owned(),
+    )?;
+
+    // create link to new version of section
+    hdk::link_entries(
+        section_anchor_address,
+        &new_section_address,
+        SectionAnchor::link_type(),
+        "".to_owned(),
+    )?;
+
+    Ok(new_section_address)
+}
+
 pub fn update(
     title: String,
     section_anchor_address: &Address,
@@ -89,53 +229,241 @@ pub fn update(
             // update the section
             previous_section.title = title;
             previous_section.timestamp = timestamp;
-            // commit this update to the DHT.
-            let new_section_address =
-                hdk::update_entry(previous_section.entry(), &previous_section_address)?;
-
-            // remove link to previous version of section
-            hdk::remove_link(
-                section_anchor_address,
+            commit_update(
+                previous_section.clone(),
                 &previous_section_address,
-                SectionAnchor::link_type(),
-                "".to_owned(),
+                section_anchor_address,
             )?;
 
-            // create link to new version of section
-            hdk::link_entries(
+            signal::notify(ChangeNotice::section(
+                ChangeKind::Updated,
+                previous_section.course_address,
+                section_anchor_address.clone(),
+            ))?;
+
+            Ok(section_anchor_address.clone())
+        }
+        None => {
+            return Err(ZomeError::deleted("section", "update").into());
+        }
+    }
+}
+
+// sets time before which students can't see the content of the section. None releases it right away
+pub fn set_release_time(
+    section_anchor_address: &Address,
+    release_at: Option<u64>,
+    timestamp: u64,
+) -> ZomeApiResult<Address> {
+    match get_latest_section(section_anchor_address)? {
+        Some((mut previous_section, previous_section_address)) => {
+            previous_section.release_at = release_at;
+            previous_section.timestamp = timestamp;
+            commit_update(
+                previous_section.clone(),
+                &previous_section_address,
                 section_anchor_address,
-                &new_section_address,
-                SectionAnchor::link_type(),
-                "".to_owned(),
             )?;
 
+            signal::notify(ChangeNotice::section(
+                ChangeKind::Updated,
+                previous_section.course_address,
+                section_anchor_address.clone(),
+            ))?;
+
             Ok(section_anchor_address.clone())
         }
-        None => {
-            return Err(ZomeApiError::from(
-                "Can't update a deleted section".to_owned(),
-            ));
+        None => Err(ZomeError::deleted("section", "set release time of").into()),
+    }
+}
+
+// changes the order of subsections. subsections has to list exactly the same sections as the section already has
+pub fn reorder_subsections(
+    section_anchor_address: &Address,
+    subsections: Vec<Address>,
+    timestamp: u64,
+) -> ZomeApiResult<Address> {
+    match get_latest_section(section_anchor_address)? {
+        Some((mut previous_section, previous_section_address)) => {
+            let mut current_order = previous_section.subsections.clone();
+            let mut new_order = subsections.clone();
+            current_order.sort();
+            new_order.sort();
+            if current_order != new_order {
+                return Err(ZomeError::invalid_input(
+                    "New order has to list exactly the same subsections as the section has",
+                )
+                .into());
+            }
+
+            previous_section.subsections = subsections;
+            previous_section.timestamp = timestamp;
+            commit_update(
+                previous_section.clone(),
+                &previous_section_address,
+                section_anchor_address,
+            )?;
+
+            signal::notify(ChangeNotice::section(
+                ChangeKind::Updated,
+                previous_section.course_address,
+                section_anchor_address.clone(),
+            ))?;
+
+            Ok(section_anchor_address.clone())
         }
+        None => Err(ZomeError::deleted("section", "reorder subsections of").into()),
+    }
+}
+
+// removes subsection from the list of it's parent section
+fn remove_subsection(
+    parent_address: &Address,
+    section_anchor_address: &Address,
+    timestamp: u64,
+) -> ZomeApiResult<()> {
+    // NOTE: if parent section was deleted, there's no list to remove subsection from
+    if let Some((mut parent_section, parent_section_address)) = get_latest_section(parent_address)?
+    {
+        parent_section
+            .subsections
+            .remove_item(section_anchor_address);
+        parent_section.timestamp = timestamp;
+        commit_update(parent_section, &parent_section_address, parent_address)?;
     }
+    Ok(())
 }
 
 pub fn delete(section_anchor_address: Address, timestamp: u64) -> ZomeApiResult<Address> {
     let section_anchor: SectionAnchor = hdk::utils::get_as_type(section_anchor_address.clone())?;
 
-    // NOTE: we're using the fact that anchor contains course_address and that we don't allow
-    //  to change course_address in a section entry.
+    // NOTE: we're using the fact that anchor contains course_address and parent_address and that we don't allow
+    //  to change them in a section entry.
     // By doing so, we avoid necessity to query links of the section_anchor to retrieve the latest section entry
     // which makes this method a little bit faster
-    course::handlers::delete_section(
-        &section_anchor.course_address,
-        &section_anchor_address,
-        timestamp,
-    )?;
+    match &section_anchor.parent_address {
+        Some(parent_address) => {
+            remove_subsection(parent_address, &section_anchor_address, timestamp)?;
+        }
+        None => {
+            course::handlers::delete_section(
+                &section_anchor.course_address,
+                &section_anchor_address,
+                timestamp,
+            )?;
+        }
+    }
 
     // NOTE: let's try only deleting an anchor! (and don't touch links from anchor to section entry and section entry itself)
     // reasons:
     // 1) without it, we won't be able to reach the section because everywhere we link to section we only use anchor address
     // 2) we'll avoid polluting DHT by new deletion metadata
     let result = hdk::remove_entry(&section_anchor_address)?;
+
+    signal::notify(ChangeNotice::section(
+        ChangeKind::Deleted,
+        section_anchor.course_address,
+        section_anchor_address,
+    ))?;
     Ok(result)
 }
+
+// addresses of the anchors that content got when it's section was moved to another course
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct MovedContent {
+    pub previous_content_anchor_address: Address,
+    pub content_anchor_address: Address,
+}
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone)]
+pub struct MovedSection {
+    pub section_anchor_address: Address,
+    pub contents: Vec<MovedContent>,
+}
+
+// moves section with all it's content to another course of the same teacher.
+// Since SectionAnchor and ContentAnchor can't change the course / section they belong to, the section is created anew
+// in the target course and removed from the source course. Returns addresses of the new SectionAnchor and ContentAnchors.
+// NOTE: comments and progress of students aren't moved: comment threads can only be linked by their authors and
+// ContentCompletion entries are private, so both stay with the previous ContentAnchors
+pub fn move_to_course(
+    section_anchor_address: Address,
+    target_course_anchor_address: &Address,
+    timestamp: u64,
+) -> ZomeApiResult<MovedSection> {
+    let section = match get_latest_section(&section_anchor_address)? {
+        Some((section, _section_address)) => section,
+        None => return Err(ZomeError::deleted("section", "move").into()),
+    };
+    let source_course = match course::handlers::get_latest_course(&section.course_address)? {
+        Some((course, _course_address)) => course,
+        None => return Err(ZomeError::deleted("course", "move a section out of").into()),
+    };
+    let target_course = match course::handlers::get_latest_course(target_course_anchor_address)? {
+        Some((course, _course_address)) => course,
+        None => return Err(ZomeError::deleted("course", "move a section into").into()),
+    };
+    rules::validate_move(
+        &source_course.teacher_address,
+        &target_course.teacher_address,
+        &[AGENT_ADDRESS.clone()],
+        &section.course_address,
+        target_course_anchor_address,
+    )
+    .map_err(|error| ZomeApiError::from(ZomeError::from(error)))?;
+    if !section.subsections.is_empty() {
+        return Err(ZomeError::forbidden(
+            "Sections with subsections can't be moved to another course",
+        )
+        .into());
+    }
+    // submissions of students point to the assignments of this section, so they would be left behind
+    if !assignment::handlers::get_assignments(&section_anchor_address)?.is_empty() {
+        return Err(ZomeError::forbidden(
+            "Sections with assignments can't be moved to another course",
+        )
+        .into());
+    }
+
+    let contents = content::handlers::get_ordered_contents(&section_anchor_address)?;
+
+    let new_section_anchor_address =
+        create(section.title, target_course_anchor_address, timestamp)?;
+    if section.release_at.is_some() {
+        set_release_time(&new_section_anchor_address, section.release_at, timestamp)?;
+    }
+    // this link is validated to be made by the teacher of both courses, so it has to go before anything is removed
+    hdk::link_entries(
+        &section_anchor_address,
+        &new_section_anchor_address,
+        SECTION_ANCHOR_TO_MOVED_SECTION_LINK,
+        "",
+    )?;
+    let mut moved_contents = vec![];
+    for (content_index, (content, content_anchor_address)) in contents.into_iter().enumerate() {
+        // NOTE: same as in course::handlers::fork, original timestamps have to be close to the time of the commit,
+        // so every content gets the same timestamp and is kept in order by it's index
+        let new_content_anchor_address = content::handlers::commit_new_content(
+            Content::new(
+                content.name.clone(),
+                new_section_anchor_address.clone(),
+                content.url.clone(),
+                timestamp,
+                content.description.clone(),
+                target_course.teacher_address.clone(),
+            ),
+            content_index as u64,
+        )?;
+        content::handlers::remove(&content, &content_anchor_address)?;
+        moved_contents.push(MovedContent {
+            previous_content_anchor_address: content_anchor_address,
+            content_anchor_address: new_content_anchor_address,
+        });
+    }
+    delete(section_anchor_address, timestamp)?;
+
+    Ok(MovedSection {
+        section_anchor_address: new_section_anchor_address,
+        contents: moved_contents,
+    })
+}
@@ -1,21 +1,34 @@
 use crate::anchor_trait::AnchorTrait;
+use crate::error::ZomeError;
 use hdk::holochain_core_types::chain_header::ChainHeader;
+use hdk::holochain_persistence_api::cas::content::Address;
 use hdk::{LinkValidationData, ValidationData};
 use holochain_entry_utils::HolochainEntry;
 
-use super::{
-    anchor::SectionAnchor,
-    entry::{Section, MAX_TITLE_LEN},
-};
+use super::{anchor::SectionAnchor, entry::Section};
+use crate::content::anchor::ContentAnchor;
 use crate::helper;
+use courses_core::section as rules;
 
 pub fn create(entry: Section, validation_data: ValidationData) -> Result<(), String> {
-    helper::validate_only_teacher_can_do(
+    rules::validate_create(
         &entry.teacher_address,
-        validation_data.sources(),
-        "create sections in this course",
-    )?;
-    helper::validate_entity_title(&entry.title, &Section::entry_type(), MAX_TITLE_LEN)
+        &validation_data.sources(),
+        &entry.title,
+        &Section::entry_type(),
+    )
+    .map_err(helper::rule_error)?;
+    validate_subsections(&entry, &[])?;
+    let section_anchor: SectionAnchor = hdk::utils::get_as_type(entry.anchor_address.clone())?;
+    if section_anchor.parent_address != entry.parent_address {
+        return Err(ZomeError::invalid_value(
+            "section",
+            "parent",
+            "has to be the same as the parent of it's SectionAnchor",
+        )
+        .into());
+    }
+    helper::validate_timestamp(entry.timestamp, &validation_data, &Section::entry_type())
 }
 
 pub fn modify(
@@ -24,17 +37,52 @@ pub fn modify(
     _old_entry_header: ChainHeader,
     validation_data: ValidationData,
 ) -> Result<(), String> {
-    helper::validate_only_teacher_can_do(
+    rules::validate_modify(
         &old_entry.teacher_address,
-        validation_data.sources(),
-        "modify sections in this course",
-    )?;
-    if new_entry.course_address != old_entry.course_address {
-        return Err(String::from(
-            "Cannot change course to which the section belongs",
-        ));
+        &validation_data.sources(),
+        &old_entry.course_address,
+        &new_entry.course_address,
+        &old_entry.parent_address,
+        &new_entry.parent_address,
+        &new_entry.title,
+    )
+    .map_err(helper::rule_error)?;
+    validate_subsections(&new_entry, &old_entry.subsections)?;
+    helper::validate_timestamp_update(
+        old_entry.timestamp,
+        new_entry.timestamp,
+        &validation_data,
+        &Section::entry_type(),
+    )
+}
+
+// checks that every section that was added to subsections is a child of this section. Since depth of the tree
+// is limited when SectionAnchor is created, this is what keeps the tree of subsections bounded and free of cycles.
+// Subsections that were already listed are skipped: they were checked before and could be deleted since then
+fn validate_subsections(entry: &Section, previous_subsections: &[Address]) -> Result<(), String> {
+    for (index, address) in entry.subsections.iter().enumerate() {
+        if entry.subsections[..index].contains(address) {
+            return Err(ZomeError::invalid_value(
+                "section",
+                "subsections",
+                "can't list the same subsection twice",
+            )
+            .into());
+        }
+        if previous_subsections.contains(address) {
+            continue;
+        }
+        let subsection_anchor: SectionAnchor = hdk::utils::get_as_type(address.clone())?;
+        if subsection_anchor.parent_address.as_ref() != Some(&entry.anchor_address) {
+            return Err(ZomeError::invalid_value(
+                "section",
+                "subsections",
+                "has to list only subsections of this section",
+            )
+            .into());
+        }
     }
-    helper::validate_entity_title(&new_entry.title, &Section::entry_type(), MAX_TITLE_LEN)
+    Ok(())
 }
 
 pub fn delete(
@@ -42,29 +90,62 @@ pub fn delete(
     _entry_header: ChainHeader,
     validation_data: ValidationData,
 ) -> Result<(), String> {
-    helper::validate_only_teacher_can_do(
-        &entry.teacher_address,
-        validation_data.sources(),
-        "delete sections in this course",
-    )
+    rules::validate_delete(&entry.teacher_address, &validation_data.sources())
+        .map_err(helper::rule_error)
 }
 
 // =========================== SectionAnchor validation
 pub fn anchor_create(entry: SectionAnchor, validation_data: ValidationData) -> Result<(), String> {
-    helper::validate_only_teacher_can_do(
+    rules::validate_create(
         &entry.teacher_address,
-        validation_data.sources(),
-        "create sections in this course",
-    )?;
-    helper::validate_entity_title(&entry.title, &SectionAnchor::entry_type(), MAX_TITLE_LEN)
+        &validation_data.sources(),
+        &entry.title,
+        &SectionAnchor::entry_type(),
+    )
+    .map_err(helper::rule_error)?;
+    if let Some(parent_address) = &entry.parent_address {
+        let ancestors = get_ancestors(parent_address, &entry.course_address)?;
+        rules::validate_ancestors(&ancestors).map_err(helper::rule_error)?;
+    }
+    helper::validate_timestamp(
+        entry.timestamp,
+        &validation_data,
+        &SectionAnchor::entry_type(),
+    )
+}
+
+// returns addresses of the section at parent_address and all of it's ancestors up to the top-level section.
+// Stops as soon as the chain is too long or has a loop because it's invalid anyway
+fn get_ancestors(
+    parent_address: &Address,
+    course_address: &Address,
+) -> Result<Vec<Address>, String> {
+    let mut ancestors: Vec<Address> = vec![];
+    let mut next_address = Some(parent_address.clone());
+    while let Some(address) = next_address {
+        if ancestors.contains(&address) || ancestors.len() >= rules::MAX_DEPTH {
+            ancestors.push(address);
+            break;
+        }
+        let ancestor: SectionAnchor = hdk::utils::get_as_type(address.clone())?;
+        if &ancestor.course_address != course_address {
+            return Err(ZomeError::invalid_value(
+                "section",
+                "parent",
+                "parent section has to belong to the same course",
+            )
+            .into());
+        }
+        ancestors.push(address);
+        next_address = ancestor.parent_address;
+    }
+    Ok(ancestors)
 }
 
 // NOTE: we don't accept any parameters here because we don't need them to always return an error
 // because this anchor can never be modified
 pub fn anchor_modify() -> Result<(), String> {
-    Err(String::from(
-        "Can't modify the SectionAnchor entry: it can only be created or deleted".to_owned(),
-    ))
+    Err(ZomeError::immutable_entry("section_anchor").into())
 }
 
 pub fn anchor_delete(
@@ -72,11 +153,8 @@ pub fn anchor_delete(
     _entry_header: ChainHeader,
     validation_data: ValidationData,
 ) -> Result<(), String> {
-    helper::validate_only_teacher_can_do(
-        &entry.teacher_address,
-        validation_data.sources(),
-        "delete sections in this course",
-    )
+    rules::validate_delete(&entry.teacher_address, &validation_data.sources())
+        .map_err(helper::rule_error)
 }
 
 //  =========================== SectionAnchor links validation
@@ -95,18 +173,16 @@ pub fn anchor_to_section_link(validation_data: LinkValidationData) -> Result<(),
             if base.teacher_address != target.teacher_address {
                 // notice that we're using return and ending this statement with ; symbol
                 // You can do both: skip ; symbol in the last fn statement or explicitly add return to it and then leave ; as is
-                return Err(String::from(
-                    "Can't link SectionAnchor to Section because their teacher addresses are different",
-                ));
+                return Err(ZomeError::invalid_link("section_anchor->section", "Can't link SectionAnchor to Section because their teacher addresses are different").into());
             } else if author != base.teacher_address {
-                return Err(String::from(
-                    "Can't link SectionAnchor to Section because your address isn't specified as teacher address for this course",
-                ));
+                return Err(ZomeError::invalid_link("section_anchor->section", "Can't link SectionAnchor to Section because your address isn't specified as teacher address for this course").into());
             }
             if base.course_address != target.course_address {
-                return Err(String::from(
+                return Err(ZomeError::invalid_link(
+                    "section_anchor->section",
                     "Can't link SectionAnchor to Section because they belong to different courses",
-                ));
+                )
+                .into());
             }
             Ok(())
         }
@@ -119,11 +195,71 @@ pub fn anchor_to_section_link(validation_data: LinkValidationData) -> Result<(),
             // get link base: entry from which the link goes
             let base: SectionAnchor = hdk::utils::get_as_type(link.link.base().clone())?;
             if author != base.teacher_address {
-                return Err(String::from(
-                    "Can't remove link from SectionAnchor to Section because your address isn't specified as teacher_address for this course",
-                ));
+                return Err(ZomeError::invalid_link("section_anchor->section", "Can't remove link from SectionAnchor to Section because your address isn't specified as teacher_address for this course").into());
             }
             Ok(())
         }
     }
 }
+
+pub fn section_to_content_anchor_link(validation_data: LinkValidationData) -> Result<(), String> {
+    match validation_data {
+        hdk::LinkValidationData::LinkAdd {
+            link,
+            validation_data,
+        } => {
+            let author = validation_data.package.chain_header.provenances()[0].source();
+            let base: SectionAnchor = hdk::utils::get_as_type(link.link.base().clone())?;
+            let target: ContentAnchor = hdk::utils::get_as_type(link.link.target().clone())?;
+            if author != base.teacher_address {
+                return Err(ZomeError::invalid_link("section_anchor->content_anchor", "Can't link SectionAnchor to ContentAnchor because your address isn't specified as teacher address for this course").into());
+            }
+            if &target.section_anchor_address != link.link.base() {
+                return Err(ZomeError::invalid_link(
+                    "section_anchor->content_anchor",
+                    "Can't link SectionAnchor to ContentAnchor of another section",
+                )
+                .into());
+            }
+            Ok(())
+        }
+        hdk::LinkValidationData::LinkRemove {
+            link,
+            validation_data,
+        } => {
+            let author = validation_data.package.chain_header.provenances()[0].source();
+            let base: SectionAnchor = hdk::utils::get_as_type(link.link.base().clone())?;
+            if author != base.teacher_address {
+                return Err(ZomeError::invalid_link("section_anchor->content_anchor", "Can't remove link from SectionAnchor to ContentAnchor because your address isn't specified as teacher_address for this course").into());
+            }
+            Ok(())
+        }
+    }
+}
+
+// this link is what makes moving a section to another course valid: it connects sections of both courses,
+// so it's checked that it's author teaches both of them
+pub fn anchor_to_moved_section_link(validation_data: LinkValidationData) -> Result<(), String> {
+    match validation_data {
+        hdk::LinkValidationData::LinkAdd {
+            link,
+            validation_data,
+        } => {
+            let base: SectionAnchor = hdk::utils::get_as_type(link.link.base().clone())?;
+            let target: SectionAnchor = hdk::utils::get_as_type(link.link.target().clone())?;
+            rules::validate_move(
+                &base.teacher_address,
+                &target.teacher_address,
+                &validation_data.sources(),
+                &base.course_address,
+                &target.course_address,
+            )
+            .map_err(helper::rule_error)
+        }
+        hdk::LinkValidationData::LinkRemove { .. } => Err(ZomeError::invalid_link(
+            "section_anchor->moved_section_anchor",
+            "Can't remove link to the moved section because section can't be moved back to it's old anchor",
+        )
+        .into()),
+    }
+}
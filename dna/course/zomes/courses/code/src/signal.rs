@@ -0,0 +1,161 @@
+use hdk::prelude::*;
+use hdk::AGENT_ADDRESS;
+
+use crate::course;
+use crate::direct_message::{self, DirectMessage};
+use crate::error::ZomeError;
+
+// Names of the signals that are emitted to the UI with hdk::emit_signal.
+// Each signal carries the payload struct with the matching name as it's arguments
+pub const COURSE_CHANGED_SIGNAL: &str = "course_changed";
+pub const SECTION_CHANGED_SIGNAL: &str = "section_changed";
+pub const CONTENT_CHANGED_SIGNAL: &str = "content_changed";
+
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone, PartialEq)]
+pub enum ChangeKind {
+    Created,
+    Updated,
+    Deleted,
+}
+
+// payload of the course_changed signal
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone, PartialEq)]
+pub struct CourseChanged {
+    pub kind: ChangeKind,
+    pub course_anchor_address: Address,
+    // agent who made the change
+    pub author_address: Address,
+}
+
+// payload of the section_changed signal
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone, PartialEq)]
+pub struct SectionChanged {
+    pub kind: ChangeKind,
+    pub course_anchor_address: Address,
+    pub section_anchor_address: Address,
+    // agent who made the change
+    pub author_address: Address,
+}
+
+// payload of the content_changed signal
+#[derive(Serialize, Deserialize, Debug, self::DefaultJson, Clone, PartialEq)]
+pub struct ContentChanged {
+    pub kind: ChangeKind,
+    pub course_anchor_address: Address,
+    pub section_anchor_address: Address,
+    pub content_anchor_address: Address,
+    // agent who made the change
+    pub author_address: Address,
+}
+
+// Change of the course structure that is delivered to other course members so their UIs can refresh
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "entity", content = "change")]
+pub enum ChangeNotice {
+    Course(CourseChanged),
+    Section(SectionChanged),
+    Content(ContentChanged),
+}
+
+impl ChangeNotice {
+    pub fn course(kind: ChangeKind, course_anchor_address: Address) -> Self {
+        ChangeNotice::Course(CourseChanged {
+            kind,
+            course_anchor_address,
+            author_address: AGENT_ADDRESS.clone(),
+        })
+    }
+
+    pub fn section(
+        kind: ChangeKind,
+        course_anchor_address: Address,
+        section_anchor_address: Address,
+    ) -> Self {
+        ChangeNotice::Section(SectionChanged {
+            kind,
+            course_anchor_address,
+            section_anchor_address,
+            author_address: AGENT_ADDRESS.clone(),
+        })
+    }
+
+    pub fn content(
+        kind: ChangeKind,
+        course_anchor_address: Address,
+        section_anchor_address: Address,
+        content_anchor_address: Address,
+    ) -> Self {
+        ChangeNotice::Content(ContentChanged {
+            kind,
+            course_anchor_address,
+            section_anchor_address,
+            content_anchor_address,
+            author_address: AGENT_ADDRESS.clone(),
+        })
+    }
+
+    fn author_address(&self) -> &Address {
+        match self {
+            ChangeNotice::Course(payload) => &payload.author_address,
+            ChangeNotice::Section(payload) => &payload.author_address,
+            ChangeNotice::Content(payload) => &payload.author_address,
+        }
+    }
+
+    fn course_anchor_address(&self) -> &Address {
+        match self {
+            ChangeNotice::Course(payload) => &payload.course_anchor_address,
+            ChangeNotice::Section(payload) => &payload.course_anchor_address,
+            ChangeNotice::Content(payload) => &payload.course_anchor_address,
+        }
+    }
+
+    // emits signal with this change to the UI of the current agent
+    fn emit(self) -> ZomeApiResult<()> {
+        match self {
+            ChangeNotice::Course(payload) => hdk::emit_signal(COURSE_CHANGED_SIGNAL, payload),
+            ChangeNotice::Section(payload) => hdk::emit_signal(SECTION_CHANGED_SIGNAL, payload),
+            ChangeNotice::Content(payload) => hdk::emit_signal(CONTENT_CHANGED_SIGNAL, payload),
+        }
+    }
+}
+
+// emits signal with the change to the UI of the current agent.
+// NOTE: other course members aren't notified here: waiting for each of them to respond would make every change slow,
+// so UI calls notify_course_members for the changes it wants to share
+pub fn notify(notice: ChangeNotice) -> ZomeApiResult<()> {
+    notice.emit()
+}
+
+// sends the change notice made by the current agent to the teacher and all students of the course
+// and returns addresses of agents that received it
+pub fn notify_course_members(notice: ChangeNotice) -> ZomeApiResult<Vec<Address>> {
+    if notice.author_address() != *AGENT_ADDRESS {
+        return Err(ZomeError::forbidden(
+            "Change notice has to be sent by the agent who made the change",
+        )
+        .into());
+    }
+    let course_anchor_address = notice.course_anchor_address().clone();
+    let mut recipients = course::handlers::get_students(course_anchor_address.clone())?;
+    if let Some((course, _course_address)) =
+        course::handlers::get_latest_course(&course_anchor_address)?
+    {
+        recipients.push(course.teacher_address);
+    }
+    Ok(direct_message::broadcast(
+        recipients,
+        DirectMessage::ChangeNotice(notice),
+    ))
+}
+
+// called from the zome's receive callback when another agent notifies us about the change they've made
+pub fn receive(from_address: Address, notice: ChangeNotice) -> ZomeApiResult<()> {
+    if &from_address != notice.author_address() {
+        return Err(ZomeError::forbidden(
+            "Change notice has to be sent by the agent who made the change",
+        )
+        .into());
+    }
+    notice.emit()
+}
@@ -0,0 +1,80 @@
+use crate::error::RuleResult;
+use crate::rules;
+
+pub fn validate_create<A: PartialEq>(teacher_address: &A, sources: &[A]) -> RuleResult {
+    rules::validate_only_teacher_can_do(
+        teacher_address,
+        sources,
+        "create content in the section of this course",
+    )
+}
+
+pub fn validate_modify<A: PartialEq>(
+    old_teacher_address: &A,
+    new_teacher_address: &A,
+    sources: &[A],
+    old_section_anchor_address: &A,
+    new_section_anchor_address: &A,
+) -> RuleResult {
+    rules::validate_only_teacher_can_do(
+        old_teacher_address,
+        sources,
+        "modify content in the section of this course",
+    )?;
+    // content can't be moved to another section: links from the old section would point to the content
+    // that doesn't belong to it anymore
+    rules::validate_field_unchanged(
+        old_section_anchor_address,
+        new_section_anchor_address,
+        "content",
+        "section",
+    )?;
+    rules::validate_no_teacher_change(old_teacher_address, new_teacher_address, "content")
+}
+
+pub fn validate_delete<A: PartialEq>(teacher_address: &A, sources: &[A]) -> RuleResult {
+    rules::validate_only_teacher_can_do(
+        teacher_address,
+        sources,
+        "delete content in the section of this course",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::RuleError;
+    use proptest::prelude::*;
+
+    #[test]
+    fn only_teacher_can_manage_content() {
+        assert_eq!(validate_create(&"alice", &["alice"]), Ok(()));
+        assert!(validate_create(&"alice", &["bob"]).is_err());
+        assert_eq!(
+            validate_modify(&"alice", &"alice", &["alice"], &"section", &"section"),
+            Ok(())
+        );
+        assert!(validate_modify(&"alice", &"alice", &["bob"], &"section", &"section").is_err());
+        assert_eq!(validate_delete(&"alice", &["alice"]), Ok(()));
+        assert!(validate_delete(&"alice", &["bob"]).is_err());
+    }
+
+    #[test]
+    fn content_cant_be_moved_to_another_section() {
+        assert_eq!(
+            validate_modify(&"alice", &"alice", &["alice"], &"section 1", &"section 2"),
+            Err(RuleError::ImmutableField {
+                entity: "content".to_owned(),
+                field: "section".to_owned(),
+            })
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn content_teacher_cant_be_changed(old in 0u8..5, new in 0u8..5) {
+            let result = validate_modify(&old, &new, &[old], &10, &10);
+            prop_assert_eq!(result.is_ok(), old == new);
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use crate::error::RuleResult;
+use crate::rules;
+
+pub const MAX_TITLE_LEN: usize = 50;
+
+// rules for creating both Course and CourseAnchor: entity_name tells which one of them is validated
+pub fn validate_create<A: PartialEq>(
+    teacher_address: &A,
+    sources: &[A],
+    title: &str,
+    entity_name: &str,
+) -> RuleResult {
+    rules::validate_only_teacher_can_do(teacher_address, sources, "create their courses")?;
+    rules::validate_entity_title(title, entity_name, MAX_TITLE_LEN)
+}
+
+pub fn validate_modify<A: PartialEq>(
+    old_teacher_address: &A,
+    new_teacher_address: &A,
+    sources: &[A],
+    new_title: &str,
+    entity_name: &str,
+) -> RuleResult {
+    rules::validate_only_teacher_can_do(old_teacher_address, sources, "modify their courses")?;
+    rules::validate_entity_title(new_title, entity_name, MAX_TITLE_LEN)?;
+    rules::validate_no_teacher_change(old_teacher_address, new_teacher_address, entity_name)
+}
+
+pub fn validate_delete<A: PartialEq>(teacher_address: &A, sources: &[A]) -> RuleResult {
+    rules::validate_only_teacher_can_do(teacher_address, sources, "delete their courses")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::RuleError;
+    use proptest::prelude::*;
+
+    #[test]
+    fn teacher_can_create_course() {
+        assert_eq!(
+            validate_create(&"alice", &["alice"], "Rust 101", "course"),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn course_can_only_be_created_by_it_s_teacher() {
+        assert_eq!(
+            validate_create(&"alice", &["bob"], "Rust 101", "course"),
+            Err(RuleError::NotTeacher {
+                action: "create their courses".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn course_title_is_limited() {
+        let title = "a".repeat(MAX_TITLE_LEN + 1);
+        assert_eq!(
+            validate_create(&"alice", &["alice"], &title, "course_anchor"),
+            Err(RuleError::TitleTooLong {
+                entity: "course_anchor".to_owned(),
+                max_length: MAX_TITLE_LEN,
+            })
+        );
+    }
+
+    #[test]
+    fn teacher_of_the_course_cant_be_changed() {
+        assert_eq!(
+            validate_modify(&"alice", &"bob", &["alice"], "Rust 101", "course"),
+            Err(RuleError::ImmutableField {
+                entity: "course".to_owned(),
+                field: "teacher".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn course_can_only_be_modified_and_deleted_by_it_s_teacher() {
+        assert!(validate_modify(&"alice", &"alice", &["bob"], "Rust 101", "course").is_err());
+        assert!(validate_delete(&"alice", &["bob"]).is_err());
+        assert_eq!(validate_delete(&"alice", &["alice"]), Ok(()));
+    }
+
+    proptest! {
+        #[test]
+        fn teacher_can_always_rename_their_course(title in "[a-zA-Z0-9 ]{0,50}") {
+            prop_assert!(validate_modify(&"alice", &"alice", &["alice"], &title, "course").is_ok());
+        }
+    }
+}
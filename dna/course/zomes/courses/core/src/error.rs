@@ -0,0 +1,61 @@
+use std::fmt;
+
+// Reasons why business rules can reject an action.
+// Zome converts each of them into the ZomeError with the same code
+#[derive(Debug, Clone, PartialEq)]
+pub enum RuleError {
+    TitleTooLong {
+        entity: String,
+        max_length: usize,
+    },
+    NotTeacher {
+        action: String,
+    },
+    NotAuthor {
+        entity: String,
+        action: String,
+    },
+    NotCourseMember {
+        action: String,
+    },
+    ImmutableField {
+        entity: String,
+        field: String,
+    },
+    InvalidValue {
+        entity: String,
+        field: String,
+        reason: String,
+    },
+}
+
+impl fmt::Display for RuleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RuleError::TitleTooLong { entity, max_length } => write!(
+                f,
+                "{} title is too long, has to be no longer than {}",
+                entity, max_length
+            ),
+            RuleError::NotTeacher { action } => write!(f, "Only the teacher can {}", action),
+            RuleError::NotAuthor { entity, action } => {
+                write!(f, "Only the author of the {} can {} it", entity, action)
+            }
+            RuleError::NotCourseMember { action } => write!(
+                f,
+                "Only the teacher and students enrolled in the course can {}",
+                action
+            ),
+            RuleError::ImmutableField { entity, field } => {
+                write!(f, "Cannot change the {} of the {}", field, entity)
+            }
+            RuleError::InvalidValue {
+                entity,
+                field,
+                reason,
+            } => write!(f, "Invalid {} {}: {}", entity, field, reason),
+        }
+    }
+}
+
+pub type RuleResult = Result<(), RuleError>;
@@ -0,0 +1,12 @@
+// Business rules of the courses zome that don't depend on hdk.
+// Zome validation callbacks collect the data they need from the DHT (entries, link authors, students etc.)
+// and call these functions to decide if the action is allowed, so all rules can be tested
+// with plain `cargo test` without starting any conductors.
+// NOTE: rules are generic over the address type so they don't need hdk's Address. Zome uses them with Address,
+// tests use plain strings
+pub mod content;
+pub mod course;
+pub mod error;
+pub mod release;
+pub mod rules;
+pub mod section;
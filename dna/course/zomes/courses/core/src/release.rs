@@ -0,0 +1,206 @@
+use crate::error::{RuleError, RuleResult};
+use std::cmp;
+
+pub const SECS_PER_DAY: u64 = 24 * 60 * 60;
+
+// Drip rule of the course applied to a particular student and section, with the student's data already collected
+#[derive(Debug, Clone, PartialEq)]
+pub enum Drip {
+    // course doesn't drip sections
+    None,
+    // n-th section (position counts from 0) unlocks (n + 1) * days after the student enrolled.
+    // enrolled_at is None if the agent isn't enrolled
+    DaysAfterEnrolment {
+        days: u64,
+        position: u64,
+        enrolled_at: Option<u64>,
+    },
+    // section unlocks when the student completes all content of the previous section
+    AfterPreviousSection {
+        previous_completed: bool,
+    },
+}
+
+// What a student has to wait for before they can see the content of the section
+#[derive(Debug, Clone, PartialEq)]
+pub enum Lock {
+    Unlocked,
+    UntilTime(u64),
+    UntilPreviousSectionCompleted,
+    UntilEnrolled,
+}
+
+// time when the section unlocks, if it depends on time. release_at is the time set for the section itself,
+// drip is the rule of the course. When both of them set a time, section unlocks at the later one.
+// Drip time that doesn't fit into u64 is u64::MAX, so such section never unlocks
+pub fn section_unlocks_at(release_at: Option<u64>, drip: &Drip) -> Option<u64> {
+    let drip_unlocks_at = match drip {
+        Drip::DaysAfterEnrolment {
+            days,
+            position,
+            enrolled_at: Some(enrolled_at),
+        } => Some(
+            position
+                .checked_add(1)
+                .and_then(|sections| sections.checked_mul(*days))
+                .and_then(|days| days.checked_mul(SECS_PER_DAY))
+                .and_then(|delay| enrolled_at.checked_add(delay))
+                .unwrap_or(u64::MAX),
+        ),
+        _ => None,
+    };
+    match (release_at, drip_unlocks_at) {
+        (Some(release_at), Some(drip_unlocks_at)) => Some(cmp::max(release_at, drip_unlocks_at)),
+        (release_at, drip_unlocks_at) => release_at.or(drip_unlocks_at),
+    }
+}
+
+// decides if the section is locked for the student at time now. See section_unlocks_at for release_at and drip
+pub fn section_lock(now: u64, release_at: Option<u64>, drip: &Drip) -> Lock {
+    if let Drip::DaysAfterEnrolment {
+        enrolled_at: None, ..
+    } = drip
+    {
+        return Lock::UntilEnrolled;
+    }
+    let unlocks_at = section_unlocks_at(release_at, drip);
+    if let Some(unlocks_at) = unlocks_at {
+        if now < unlocks_at {
+            return Lock::UntilTime(unlocks_at);
+        }
+    }
+    if let Drip::AfterPreviousSection {
+        previous_completed: false,
+    } = drip
+    {
+        return Lock::UntilPreviousSectionCompleted;
+    }
+    Lock::Unlocked
+}
+
+// validates number of days in the drip rule: sections that unlock right after enrolment don't need a rule
+pub fn validate_drip_days(days: u64) -> RuleResult {
+    if days == 0 {
+        return Err(RuleError::InvalidValue {
+            entity: "course".to_owned(),
+            field: "drip_rule".to_owned(),
+            reason: "days after enrolment have to be greater than 0".to_owned(),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn section_without_release_time_and_drip_is_unlocked() {
+        assert_eq!(section_lock(100, None, &Drip::None), Lock::Unlocked);
+    }
+
+    #[test]
+    fn section_unlocks_at_release_time() {
+        assert_eq!(
+            section_lock(100, Some(200), &Drip::None),
+            Lock::UntilTime(200)
+        );
+        assert_eq!(section_lock(200, Some(200), &Drip::None), Lock::Unlocked);
+    }
+
+    #[test]
+    fn section_unlocks_days_after_enrolment() {
+        let drip = Drip::DaysAfterEnrolment {
+            days: 7,
+            position: 0,
+            enrolled_at: Some(1000),
+        };
+        let unlocks_at = 1000 + 7 * SECS_PER_DAY;
+        assert_eq!(section_lock(1000, None, &drip), Lock::UntilTime(unlocks_at));
+        assert_eq!(section_lock(unlocks_at, None, &drip), Lock::Unlocked);
+    }
+
+    #[test]
+    fn sections_unlock_one_by_one() {
+        let drip = |position| Drip::DaysAfterEnrolment {
+            days: 7,
+            position,
+            enrolled_at: Some(1000),
+        };
+        assert_eq!(
+            section_unlocks_at(None, &drip(0)),
+            Some(1000 + 7 * SECS_PER_DAY)
+        );
+        assert_eq!(
+            section_unlocks_at(None, &drip(2)),
+            Some(1000 + 21 * SECS_PER_DAY)
+        );
+    }
+
+    #[test]
+    fn section_that_unlocks_too_late_never_unlocks() {
+        let drip = Drip::DaysAfterEnrolment {
+            days: u64::MAX / SECS_PER_DAY,
+            position: 1,
+            enrolled_at: Some(1000),
+        };
+        assert_eq!(section_unlocks_at(None, &drip), Some(u64::MAX));
+        assert_eq!(
+            section_lock(u64::MAX - 1, None, &drip),
+            Lock::UntilTime(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn section_is_locked_for_agents_that_arent_enrolled() {
+        let drip = Drip::DaysAfterEnrolment {
+            days: 7,
+            position: 0,
+            enrolled_at: None,
+        };
+        assert_eq!(section_lock(1000, None, &drip), Lock::UntilEnrolled);
+    }
+
+    #[test]
+    fn section_unlocks_after_previous_section_is_completed() {
+        let not_completed = Drip::AfterPreviousSection {
+            previous_completed: false,
+        };
+        let completed = Drip::AfterPreviousSection {
+            previous_completed: true,
+        };
+        assert_eq!(
+            section_lock(100, None, &not_completed),
+            Lock::UntilPreviousSectionCompleted
+        );
+        assert_eq!(section_lock(100, None, &completed), Lock::Unlocked);
+        // release time is reported first because it's known in advance
+        assert_eq!(
+            section_lock(100, Some(200), &not_completed),
+            Lock::UntilTime(200)
+        );
+    }
+
+    #[test]
+    fn drip_needs_at_least_one_day() {
+        assert!(validate_drip_days(0).is_err());
+        assert_eq!(validate_drip_days(1), Ok(()));
+    }
+
+    proptest! {
+        #[test]
+        fn section_unlocks_at_the_later_of_both_times(
+            now in 0u64..100_000_000,
+            release_at in 0u64..100_000_000,
+            enrolled_at in 0u64..100_000_000,
+            days in 1u64..100,
+            position in 0u64..100,
+        ) {
+            let drip = Drip::DaysAfterEnrolment { days, position, enrolled_at: Some(enrolled_at) };
+            let unlocks_at = cmp::max(release_at, enrolled_at + (position + 1) * days * SECS_PER_DAY);
+            let expected = if now < unlocks_at { Lock::UntilTime(unlocks_at) } else { Lock::Unlocked };
+            prop_assert_eq!(section_lock(now, Some(release_at), &drip), expected);
+        }
+    }
+}
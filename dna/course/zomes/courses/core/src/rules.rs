@@ -0,0 +1,288 @@
+use crate::error::{RuleError, RuleResult};
+use std::cmp;
+
+// how far timestamp of the entry can be from the time in it's chain header.
+// Agents' clocks are never perfectly in sync, so we allow some difference
+pub const MAX_TIMESTAMP_SKEW_SECS: u64 = 10 * 60;
+
+// validates title of some entity on not being longer than allowed_length
+pub fn validate_entity_title(title: &str, entity_name: &str, allowed_length: usize) -> RuleResult {
+    if title.len() > allowed_length {
+        return Err(RuleError::TitleTooLong {
+            entity: entity_name.to_owned(),
+            max_length: allowed_length,
+        });
+    }
+    Ok(())
+}
+
+// validates that agent with teacher_address is listed in the sources of the action
+pub fn validate_only_teacher_can_do<A: PartialEq>(
+    teacher_address: &A,
+    sources: &[A],
+    action_name: &str,
+) -> RuleResult {
+    if !sources.contains(teacher_address) {
+        return Err(RuleError::NotTeacher {
+            action: action_name.to_owned(),
+        });
+    }
+    Ok(())
+}
+
+// validates that agent with author_address is listed in the sources of the action
+pub fn validate_only_author_can_do<A: PartialEq>(
+    author_address: &A,
+    sources: &[A],
+    entity_name: &str,
+    action_name: &str,
+) -> RuleResult {
+    if !sources.contains(author_address) {
+        return Err(RuleError::NotAuthor {
+            entity: entity_name.to_owned(),
+            action: action_name.to_owned(),
+        });
+    }
+    Ok(())
+}
+
+// validates that agent with agent_address is either the teacher or one of the students of the course
+pub fn validate_only_course_member_can_do<A: PartialEq>(
+    teacher_address: &A,
+    students: &[A],
+    agent_address: &A,
+    action_name: &str,
+) -> RuleResult {
+    if agent_address != teacher_address && !students.contains(agent_address) {
+        return Err(RuleError::NotCourseMember {
+            action: action_name.to_owned(),
+        });
+    }
+    Ok(())
+}
+
+// validates that field of the entry has the same value in it's old and new versions
+pub fn validate_field_unchanged<T: PartialEq>(
+    old_value: &T,
+    new_value: &T,
+    entity_name: &str,
+    field_name: &str,
+) -> RuleResult {
+    if old_value != new_value {
+        return Err(RuleError::ImmutableField {
+            entity: entity_name.to_owned(),
+            field: field_name.to_owned(),
+        });
+    }
+    Ok(())
+}
+
+pub fn validate_no_teacher_change<A: PartialEq>(
+    old_teacher_address: &A,
+    new_teacher_address: &A,
+    entity_name: &str,
+) -> RuleResult {
+    validate_field_unchanged(
+        old_teacher_address,
+        new_teacher_address,
+        entity_name,
+        "teacher",
+    )
+}
+
+fn invalid_timestamp(entity_name: &str, reason: String) -> RuleError {
+    RuleError::InvalidValue {
+        entity: entity_name.to_owned(),
+        field: "timestamp".to_owned(),
+        reason,
+    }
+}
+
+// validates that timestamp of the entry is within MAX_TIMESTAMP_SKEW_SECS from header_timestamp:
+// the time at which conductor of the author committed this entry. Both are in seconds since Unix epoch
+pub fn validate_timestamp(timestamp: u64, header_timestamp: u64, entity_name: &str) -> RuleResult {
+    // NOTE: not using abs_diff because it isn't available in the toolchain that Holochain shell provides
+    let skew = cmp::max(timestamp, header_timestamp) - cmp::min(timestamp, header_timestamp);
+    if skew > MAX_TIMESTAMP_SKEW_SECS {
+        return Err(invalid_timestamp(
+            entity_name,
+            format!(
+                "{} is too far from the time it was committed at ({}), allowed difference is {} seconds",
+                timestamp, header_timestamp, MAX_TIMESTAMP_SKEW_SECS
+            ),
+        ));
+    }
+    Ok(())
+}
+
+// validates that new version of the entry isn't older than the previous one.
+// NOTE: timestamps are in seconds so two updates made within the same second can have equal timestamps
+pub fn validate_timestamp_not_decreasing(
+    old_timestamp: u64,
+    new_timestamp: u64,
+    entity_name: &str,
+) -> RuleResult {
+    if new_timestamp < old_timestamp {
+        return Err(invalid_timestamp(
+            entity_name,
+            format!(
+                "{} is earlier than the timestamp of the previous version ({})",
+                new_timestamp, old_timestamp
+            ),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn title_of_max_length_is_valid() {
+        assert_eq!(validate_entity_title("abcde", "course", 5), Ok(()));
+    }
+
+    #[test]
+    fn title_longer_than_max_length_is_invalid() {
+        assert_eq!(
+            validate_entity_title("abcdef", "course", 5),
+            Err(RuleError::TitleTooLong {
+                entity: "course".to_owned(),
+                max_length: 5,
+            })
+        );
+    }
+
+    #[test]
+    fn teacher_can_do_when_signed_the_action() {
+        assert_eq!(
+            validate_only_teacher_can_do(&"alice", &["bob", "alice"], "create"),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn others_cant_do_what_only_teacher_can() {
+        assert_eq!(
+            validate_only_teacher_can_do(&"alice", &["bob"], "create"),
+            Err(RuleError::NotTeacher {
+                action: "create".to_owned(),
+            })
+        );
+        assert!(validate_only_teacher_can_do(&"alice", &[], "create").is_err());
+    }
+
+    #[test]
+    fn only_author_can_do() {
+        assert_eq!(
+            validate_only_author_can_do(&"alice", &["alice"], "comment", "update"),
+            Ok(())
+        );
+        assert_eq!(
+            validate_only_author_can_do(&"alice", &["bob"], "comment", "update"),
+            Err(RuleError::NotAuthor {
+                entity: "comment".to_owned(),
+                action: "update".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn teacher_and_students_are_course_members() {
+        let students = ["bob", "carol"];
+        assert_eq!(
+            validate_only_course_member_can_do(&"alice", &students, &"alice", "comment"),
+            Ok(())
+        );
+        assert_eq!(
+            validate_only_course_member_can_do(&"alice", &students, &"carol", "comment"),
+            Ok(())
+        );
+        assert_eq!(
+            validate_only_course_member_can_do(&"alice", &students, &"dave", "comment"),
+            Err(RuleError::NotCourseMember {
+                action: "comment".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn teacher_change_is_rejected() {
+        assert_eq!(
+            validate_no_teacher_change(&"alice", &"alice", "course"),
+            Ok(())
+        );
+        assert_eq!(
+            validate_no_teacher_change(&"alice", &"bob", "course"),
+            Err(RuleError::ImmutableField {
+                entity: "course".to_owned(),
+                field: "teacher".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn timestamps_from_the_past_and_future_are_rejected() {
+        let now = 1_600_000_000;
+        assert_eq!(validate_timestamp(now, now, "course"), Ok(()));
+        assert_eq!(
+            validate_timestamp(now - MAX_TIMESTAMP_SKEW_SECS, now, "course"),
+            Ok(())
+        );
+        assert_eq!(
+            validate_timestamp(now + MAX_TIMESTAMP_SKEW_SECS, now, "course"),
+            Ok(())
+        );
+        // "created" in 1970
+        assert!(validate_timestamp(0, now, "course").is_err());
+        // "created" in 2099
+        assert!(validate_timestamp(4_070_908_800, now, "course").is_err());
+    }
+
+    #[test]
+    fn timestamp_cant_go_back_on_update() {
+        assert_eq!(validate_timestamp_not_decreasing(10, 10, "section"), Ok(()));
+        assert_eq!(validate_timestamp_not_decreasing(10, 11, "section"), Ok(()));
+        match validate_timestamp_not_decreasing(11, 10, "section") {
+            Err(RuleError::InvalidValue { entity, field, .. }) => {
+                assert_eq!(entity, "section");
+                assert_eq!(field, "timestamp");
+            }
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn timestamp_is_valid_iff_within_skew(header_timestamp in 1_000_000u64..2_000_000, offset in -2000i64..2000) {
+            let timestamp = (header_timestamp as i64 + offset) as u64;
+            let result = validate_timestamp(timestamp, header_timestamp, "content");
+            prop_assert_eq!(result.is_ok(), offset.unsigned_abs() <= MAX_TIMESTAMP_SKEW_SECS);
+        }
+
+        #[test]
+        fn title_is_valid_iff_not_longer_than_max(title in ".{0,80}", max_length in 0usize..100) {
+            let result = validate_entity_title(&title, "section", max_length);
+            prop_assert_eq!(result.is_ok(), title.len() <= max_length);
+        }
+
+        #[test]
+        fn teacher_can_do_iff_among_sources(teacher in 0u8..10, sources in prop::collection::vec(0u8..10, 0..5)) {
+            let result = validate_only_teacher_can_do(&teacher, &sources, "modify");
+            prop_assert_eq!(result.is_ok(), sources.contains(&teacher));
+        }
+
+        #[test]
+        fn teacher_is_always_course_member(teacher in 0u8..10, students in prop::collection::vec(0u8..10, 0..5)) {
+            prop_assert!(validate_only_course_member_can_do(&teacher, &students, &teacher, "comment").is_ok());
+        }
+
+        #[test]
+        fn field_is_unchanged_iff_values_are_equal(old in 0u8..5, new in 0u8..5) {
+            let result = validate_field_unchanged(&old, &new, "section", "course");
+            prop_assert_eq!(result.is_ok(), old == new);
+        }
+    }
+}
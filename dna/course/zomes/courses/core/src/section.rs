@@ -0,0 +1,220 @@
+use crate::error::{RuleError, RuleResult};
+use crate::rules;
+
+pub const MAX_TITLE_LEN: usize = 200;
+// how many levels of sections a course can have: top-level sections are on the first level
+pub const MAX_DEPTH: usize = 3;
+
+// rules for creating both Section and SectionAnchor: entity_name tells which one of them is validated
+pub fn validate_create<A: PartialEq>(
+    teacher_address: &A,
+    sources: &[A],
+    title: &str,
+    entity_name: &str,
+) -> RuleResult {
+    rules::validate_only_teacher_can_do(
+        teacher_address,
+        sources,
+        "create sections in this course",
+    )?;
+    rules::validate_entity_title(title, entity_name, MAX_TITLE_LEN)
+}
+
+pub fn validate_modify<A: PartialEq>(
+    old_teacher_address: &A,
+    sources: &[A],
+    old_course_address: &A,
+    new_course_address: &A,
+    old_parent_address: &Option<A>,
+    new_parent_address: &Option<A>,
+    new_title: &str,
+) -> RuleResult {
+    rules::validate_only_teacher_can_do(
+        old_teacher_address,
+        sources,
+        "modify sections in this course",
+    )?;
+    rules::validate_field_unchanged(old_course_address, new_course_address, "section", "course")?;
+    rules::validate_field_unchanged(old_parent_address, new_parent_address, "section", "parent")?;
+    rules::validate_entity_title(new_title, "section", MAX_TITLE_LEN)
+}
+
+// validates position of the new subsection in the tree. ancestors are addresses of it's parent section,
+// parent of the parent and so on up to the top-level section
+pub fn validate_ancestors<A: PartialEq>(ancestors: &[A]) -> RuleResult {
+    if ancestors.len() >= MAX_DEPTH {
+        return Err(RuleError::InvalidValue {
+            entity: "section".to_owned(),
+            field: "parent".to_owned(),
+            reason: format!("sections can't be nested deeper than {} levels", MAX_DEPTH),
+        });
+    }
+    for (index, ancestor) in ancestors.iter().enumerate() {
+        if ancestors[index + 1..].contains(ancestor) {
+            return Err(RuleError::InvalidValue {
+                entity: "section".to_owned(),
+                field: "parent".to_owned(),
+                reason: "section can't be nested inside itself".to_owned(),
+            });
+        }
+    }
+    Ok(())
+}
+
+pub fn validate_delete<A: PartialEq>(teacher_address: &A, sources: &[A]) -> RuleResult {
+    rules::validate_only_teacher_can_do(teacher_address, sources, "delete sections in this course")
+}
+
+// rules for moving a section together with it's content to another course: agent has to teach both courses
+pub fn validate_move<A: PartialEq>(
+    source_teacher_address: &A,
+    target_teacher_address: &A,
+    sources: &[A],
+    source_course_address: &A,
+    target_course_address: &A,
+) -> RuleResult {
+    rules::validate_only_teacher_can_do(
+        source_teacher_address,
+        sources,
+        "move sections out of this course",
+    )?;
+    rules::validate_only_teacher_can_do(
+        target_teacher_address,
+        sources,
+        "move sections into this course",
+    )?;
+    if source_course_address == target_course_address {
+        return Err(RuleError::InvalidValue {
+            entity: "section".to_owned(),
+            field: "course".to_owned(),
+            reason: "section is already in this course".to_owned(),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn teacher_can_create_section() {
+        assert_eq!(
+            validate_create(&"alice", &["alice"], "Intro", "section"),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn section_can_only_be_created_by_the_teacher() {
+        assert!(validate_create(&"alice", &["bob"], "Intro", "section").is_err());
+    }
+
+    #[test]
+    fn section_cant_be_moved_to_another_course() {
+        assert_eq!(
+            validate_modify(
+                &"alice",
+                &["alice"],
+                &"course 1",
+                &"course 2",
+                &None,
+                &None,
+                "Intro"
+            ),
+            Err(RuleError::ImmutableField {
+                entity: "section".to_owned(),
+                field: "course".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn section_cant_be_moved_to_another_parent() {
+        assert_eq!(
+            validate_modify(
+                &"alice",
+                &["alice"],
+                &"course",
+                &"course",
+                &Some("section 1"),
+                &None,
+                "Intro"
+            ),
+            Err(RuleError::ImmutableField {
+                entity: "section".to_owned(),
+                field: "parent".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn subsections_are_limited_in_depth() {
+        assert_eq!(validate_ancestors::<&str>(&[]), Ok(()));
+        assert_eq!(validate_ancestors(&["chapter"]), Ok(()));
+        assert_eq!(validate_ancestors(&["lesson", "chapter"]), Ok(()));
+        assert!(validate_ancestors(&["topic", "lesson", "chapter"]).is_err());
+    }
+
+    #[test]
+    fn section_cant_be_nested_inside_itself() {
+        assert!(validate_ancestors(&["lesson", "lesson"]).is_err());
+    }
+
+    #[test]
+    fn section_can_only_be_deleted_by_the_teacher() {
+        assert_eq!(validate_delete(&"alice", &["alice"]), Ok(()));
+        assert!(validate_delete(&"alice", &["bob"]).is_err());
+    }
+
+    #[test]
+    fn section_can_be_moved_by_the_teacher_of_both_courses() {
+        assert_eq!(
+            validate_move(&"alice", &"alice", &["alice"], &"course 1", &"course 2"),
+            Ok(())
+        );
+        assert_eq!(
+            validate_move(&"alice", &"bob", &["alice"], &"course 1", &"course 2"),
+            Err(RuleError::NotTeacher {
+                action: "move sections into this course".to_owned(),
+            })
+        );
+        assert!(validate_move(&"bob", &"alice", &["alice"], &"course 1", &"course 2").is_err());
+    }
+
+    #[test]
+    fn section_cant_be_moved_to_the_same_course() {
+        assert!(validate_move(&"alice", &"alice", &["alice"], &"course 1", &"course 1").is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn section_can_be_moved_iff_agent_teaches_both_courses(
+            source_teacher in 0u8..3,
+            target_teacher in 0u8..3,
+            agent in 0u8..3,
+        ) {
+            let result = validate_move(&source_teacher, &target_teacher, &[agent], &10, &11);
+            prop_assert_eq!(result.is_ok(), source_teacher == agent && target_teacher == agent);
+        }
+
+        #[test]
+        fn ancestors_are_valid_iff_short_and_unique(ancestors in proptest::collection::vec(0u8..4, 0..5)) {
+            let mut unique = ancestors.clone();
+            unique.sort();
+            unique.dedup();
+            let result = validate_ancestors(&ancestors);
+            prop_assert_eq!(
+                result.is_ok(),
+                ancestors.len() < MAX_DEPTH && unique.len() == ancestors.len()
+            );
+        }
+
+        #[test]
+        fn section_title_is_valid_iff_not_longer_than_max(title in ".{0,300}") {
+            let result = validate_modify(&"alice", &["alice"], &"course", &"course", &None, &None, &title);
+            prop_assert_eq!(result.is_ok(), title.len() <= MAX_TITLE_LEN);
+        }
+    }
+}